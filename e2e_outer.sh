@@ -1,6 +1,6 @@
 #!/bin/bash -e
 
-rm -f challenge* response* new_challenge* processed*
+rm -f challenge* response* new_challenge* processed* receipt.json proving_key verifying_key keys.json
 
 POWER=20
 BATCH=10000
@@ -28,14 +28,18 @@ $phase2 --response-fname response --phase2-fname processed --phase2-size $POWER
 $snark new --phase1 processed --output initial_ceremony --phase1-size $POWER
 
 cp initial_ceremony contribution1
-yes | $snark contribute --data contribution1
+$snark contribute --data contribution1 --non-interactive
 $snark verify --before initial_ceremony --after contribution1
 
 # a new contributor contributes
 cp contribution1 contribution2
-yes | $snark contribute --data contribution2
+$snark contribute --data contribution2 --non-interactive
 $snark verify --before contribution1 --after contribution2
+$snark verify-receipt --receipt receipt.json --transcript contribution2
 $snark verify --before initial_ceremony --after contribution2
 
+# the coordinator verifies the whole transcript and exports the final keys
+$snark export --initial initial_ceremony --transcript contribution2
+
 # done! since `verify` passed, you can be sure that this will work
 # as shown in the `mpc.rs` example
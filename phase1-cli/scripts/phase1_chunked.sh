@@ -1,6 +1,6 @@
 #!/bin/bash
 
-rm -f challenge* response* new_challenge* new_response* new_new_challenge_* processed* initial_ceremony* response_list* combined* seed*
+rm -f challenge* response* new_challenge* new_response* new_new_challenge_* processed* initial_ceremony* response_list* combined* seed* beacon_transcript* *.receipt.json
 
 PROVING_SYSTEM=$1
 POWER=10
@@ -45,6 +45,7 @@ for i in $(seq $(($MAX_CHUNK_INDEX/2 + 1)) $MAX_CHUNK_INDEX); do
 done
 
 $phase1_combine combine --response-list-fname response_list --combined-fname combined
-$phase1_full beacon --challenge-fname combined --response-fname response_beacon --beacon-hash 0000000000000000000a558a61ddc8ee4e488d647a747fe4dcc362fe2026c620
+$phase1_full beacon --challenge-fname combined --response-fname response_beacon --beacon-hash 0000000000000000000a558a61ddc8ee4e488d647a747fe4dcc362fe2026c620 --beacon-iterations-log2 10 --insecure-beacon
+$phase1_full verify-beacon --beacon-hash 0000000000000000000a558a61ddc8ee4e488d647a747fe4dcc362fe2026c620 --beacon-transcript-fname beacon_transcript.json
 $phase1_full verify-and-transform-pok-and-correctness --challenge-fname combined --response-fname response_beacon --new-challenge-fname response_beacon_new_challenge
 $phase1_full verify-and-transform-ratios --response-fname response_beacon_new_challenge
@@ -1,6 +1,7 @@
 #!/bin/bash
 
-rm -f challenge* response* new_challenge* processed* initial_ceremony* response_list* combined* seed*
+rm -f challenge* response* resumed_* new_challenge* processed* initial_ceremony* response_list* combined* seed* beacon_transcript* *.receipt.json
+rm -rf transcript
 
 PROVING_SYSTEM=$1
 POWER=10
@@ -16,6 +17,44 @@ phase1="cargo run --release --bin phase1 --features cli -- --curve-kind $CURVE -
 $phase1 new --challenge-fname challenge
 yes | $phase1 contribute --challenge-fname challenge --response-fname response
 $phase1 verify-and-transform-pok-and-correctness --challenge-fname challenge --response-fname response --new-challenge-fname new_challenge
-$phase1 beacon --challenge-fname new_challenge --response-fname new_response --beacon-hash 0000000000000000000a558a61ddc8ee4e488d647a747fe4dcc362fe2026c620
+$phase1 beacon --challenge-fname new_challenge --response-fname new_response --beacon-hash 0000000000000000000a558a61ddc8ee4e488d647a747fe4dcc362fe2026c620 --beacon-iterations-log2 10 --insecure-beacon
+$phase1 verify-beacon --beacon-hash 0000000000000000000a558a61ddc8ee4e488d647a747fe4dcc362fe2026c620 --beacon-transcript-fname beacon_transcript.json
 $phase1 verify-and-transform-pok-and-correctness --challenge-fname new_challenge --response-fname new_response --new-challenge-fname new_challenge_2
 $phase1 verify-and-transform-ratios --response-fname new_challenge_2
+
+####### Resuming
+
+# A response without a checkpoint was interrupted before its first batch, and starts over.
+touch resumed_response
+yes | $phase1 contribute --challenge-fname challenge --response-fname resumed_response --resumable
+test ! -e resumed_response.checkpoint || exit 1
+$phase1 verify-and-transform-pok-and-correctness --challenge-fname challenge --response-fname resumed_response --new-challenge-fname resumed_challenge
+
+# A checkpoint which can't be decoded is ignored, and the contribution starts over.
+touch resumed_response_2
+echo "not a checkpoint" > resumed_response_2.checkpoint
+yes | $phase1 contribute --challenge-fname challenge --response-fname resumed_response_2 --resumable
+test ! -e resumed_response_2.checkpoint || exit 1
+$phase1 verify-and-transform-pok-and-correctness --challenge-fname challenge --response-fname resumed_response_2 --new-challenge-fname resumed_challenge_2
+
+####### Transcript
+
+# The contribution and the beacon form a transcript of two contributions.
+mkdir transcript
+cp challenge transcript/challenge_0
+cp response transcript/response_0
+cp new_challenge transcript/challenge_1
+cp new_response transcript/response_1
+cp new_challenge_2 transcript/challenge_2
+$phase1 verify-transcript --transcript-dir transcript || exit 1
+
+# A tampered response must be rejected.
+RESPONSE_SIZE=`stat -c %s transcript/response_1`
+printf '\xff' | dd of=transcript/response_1 bs=1 seek=$((RESPONSE_SIZE / 2)) conv=notrunc
+if cmp -s new_response transcript/response_1; then
+  printf '\x00' | dd of=transcript/response_1 bs=1 seek=$((RESPONSE_SIZE / 2)) conv=notrunc
+fi
+if $phase1 verify-transcript --transcript-dir transcript; then
+  echo "verify-transcript accepted a tampered response"
+  exit 1
+fi
@@ -2,18 +2,46 @@ use phase1::{helpers::CurveKind, CurveParameters, Phase1Parameters};
 use phase1_cli::{
     combine,
     contribute,
+    convert_compression,
+    diff,
+    export_ptau,
+    export_universal_params,
+    export_zcash,
+    import_ptau,
+    import_zcash,
+    inspect,
     new_challenge,
+    split,
     transform_pok_and_correctness,
     transform_ratios,
+    verify_receipt,
+    verify_transcript,
+    write_receipt,
     Command,
+    ContributeOpts,
     Phase1Opts,
 };
-use setup_utils::{beacon_randomness, derive_rng_from_seed, from_slice, CheckForCorrectness, UseCompression};
+use setup_utils::{
+    compute_beacon,
+    curves::Bls12_381,
+    derive_rng_from_seed,
+    verify_beacon,
+    CheckForCorrectness,
+    EntropyRecord,
+    NoProgress,
+    ReceiptRandomness,
+    UseCompression,
+    Zeroizing,
+};
 
 use snarkvm_curves::{bls12_377::Bls12_377, bw6_761::BW6_761, PairingEngine as Engine};
 
 use gumdrop::Options;
-use std::{fs::read_to_string, process, time::Instant};
+use std::{
+    fs::read_to_string,
+    process,
+    time::{Instant, SystemTime},
+};
 use tracing_subscriber::{
     filter::EnvFilter,
     fmt::{time, Subscriber},
@@ -23,6 +51,20 @@ const CHALLENGE_IS_COMPRESSED: UseCompression = UseCompression::No;
 const CONTRIBUTION_IS_COMPRESSED: UseCompression = UseCompression::Yes;
 const CHECK_CONTRIBUTION_INPUT_FOR_CORRECTNESS: CheckForCorrectness = CheckForCorrectness::No;
 
+fn file_compression(response: bool) -> UseCompression {
+    if response {
+        CONTRIBUTION_IS_COMPRESSED
+    } else {
+        CHALLENGE_IS_COMPRESSED
+    }
+}
+
+fn receipt_filename(opt: &ContributeOpts) -> String {
+    opt.receipt_fname
+        .clone()
+        .unwrap_or_else(|| format!("{}.receipt.json", opt.response_fname))
+}
+
 fn execute_cmd<E: Engine>(opts: Phase1Opts) {
     let curve = CurveParameters::<E>::new();
     let parameters = Phase1Parameters::<E>::new(
@@ -44,12 +86,19 @@ fn execute_cmd<E: Engine>(opts: Phase1Opts) {
     let now = Instant::now();
     match command {
         Command::New(opt) => {
-            new_challenge(CHALLENGE_IS_COMPRESSED, &opt.challenge_fname, &parameters);
+            new_challenge(CHALLENGE_IS_COMPRESSED, &opt.challenge_fname, opt.header, &parameters);
         }
         Command::Contribute(opt) => {
             // contribute to the randomness
-            let seed = hex::decode(&read_to_string(&opts.seed).expect("should have read seed").trim())
-                .expect("seed should be a hex string");
+            let started_at = SystemTime::now();
+            let seed_hex = Zeroizing::new(read_to_string(&opts.seed).expect("should have read seed"));
+            let seed = Zeroizing::new(hex::decode(seed_hex.trim()).expect("seed should be a hex string"));
+            let randomness = ReceiptRandomness::Entropy {
+                sources: vec![EntropyRecord {
+                    source: format!("file:{}", opts.seed),
+                    bytes: seed.len(),
+                }],
+            };
             let rng = derive_rng_from_seed(&seed);
             contribute(
                 CHALLENGE_IS_COMPRESSED,
@@ -59,13 +108,36 @@ fn execute_cmd<E: Engine>(opts: Phase1Opts) {
                 CHECK_CONTRIBUTION_INPUT_FOR_CORRECTNESS,
                 &parameters,
                 rng,
+                opt.resumable,
+                &NoProgress,
+            );
+            write_receipt(
+                CONTRIBUTION_IS_COMPRESSED,
+                &opt.response_fname,
+                &receipt_filename(&opt),
+                started_at,
+                randomness,
+                &parameters,
             );
         }
         Command::Beacon(opt) => {
             // use the beacon's randomness
             // Place block hash here (block number #564321)
-            let beacon_hash = hex::decode(&opt.beacon_hash).expect("could not hex decode beacon hash");
-            let rng = derive_rng_from_seed(&beacon_randomness(from_slice(&beacon_hash)));
+            let started_at = SystemTime::now();
+            println!(
+                "Will compute the beacon with 2^{} iterations",
+                opt.beacon_iterations_log2
+            );
+            let beacon = compute_beacon(
+                &opt.beacon_hash,
+                opt.beacon_iterations_log2,
+                opt.insecure_beacon,
+                &opt.beacon_transcript_fname,
+            )
+            .expect("unable to compute the beacon");
+            println!("Final result of beacon: {}", hex::encode(beacon.result));
+            println!("Wrote the beacon transcript to {}", opt.beacon_transcript_fname);
+            let rng = derive_rng_from_seed(&beacon.result);
             contribute(
                 CHALLENGE_IS_COMPRESSED,
                 &opt.challenge_fname,
@@ -74,6 +146,16 @@ fn execute_cmd<E: Engine>(opts: Phase1Opts) {
                 CHECK_CONTRIBUTION_INPUT_FOR_CORRECTNESS,
                 &parameters,
                 rng,
+                opt.resumable,
+                &NoProgress,
+            );
+            write_receipt(
+                CONTRIBUTION_IS_COMPRESSED,
+                &opt.response_fname,
+                &receipt_filename(&opt),
+                started_at,
+                ReceiptRandomness::beacon(&beacon),
+                &parameters,
             );
         }
         Command::VerifyAndTransformPokAndCorrectness(opt) => {
@@ -90,11 +172,110 @@ fn execute_cmd<E: Engine>(opts: Phase1Opts) {
         }
         Command::VerifyAndTransformRatios(opt) => {
             // we receive a previous participation, verify it, and generate a new challenge from it
-            transform_ratios(&opt.response_fname, &parameters);
+            transform_ratios(&opt.response_fname, opts.verification_strategy, &parameters);
         }
         Command::Combine(opt) => {
             combine(&opt.response_list_fname, &opt.combined_fname, &parameters);
         }
+        Command::VerifyTranscript(opt) => {
+            verify_transcript(
+                CHALLENGE_IS_COMPRESSED,
+                CONTRIBUTION_IS_COMPRESSED,
+                &opt.transcript_dir,
+                opts.verification_strategy,
+                &parameters,
+            );
+        }
+        Command::ImportPtau(opt) => {
+            import_ptau(
+                &opt.ptau_fname,
+                CHALLENGE_IS_COMPRESSED,
+                &opt.challenge_fname,
+                &parameters,
+            );
+        }
+        Command::ExportPtau(opt) => {
+            export_ptau(
+                CHALLENGE_IS_COMPRESSED,
+                &opt.challenge_fname,
+                &opt.ptau_fname,
+                opt.history_fname.as_deref(),
+                &parameters,
+            );
+        }
+        Command::ImportZcash(opt) => {
+            import_zcash(&opt.zcash_fname, &opt.output_fname, &parameters);
+        }
+        Command::ExportZcash(opt) => {
+            export_zcash(&opt.input_fname, &opt.zcash_fname, &parameters);
+        }
+        Command::ExportUniversalParams(opt) => {
+            export_universal_params(
+                CHALLENGE_IS_COMPRESSED,
+                &opt.challenge_fname,
+                &opt.output_fname,
+                &parameters,
+            );
+        }
+        Command::Inspect(opt) => {
+            inspect(
+                file_compression(opt.response),
+                &opt.input_fname,
+                opt.response,
+                &parameters,
+            );
+        }
+        Command::Diff(opt) => {
+            diff(
+                file_compression(opt.response),
+                &opt.first_fname,
+                &opt.second_fname,
+                opt.response,
+                &parameters,
+            );
+        }
+        Command::ConvertCompression(opt) => {
+            let (compressed_input, compressed_output) = match opt.compress {
+                true => (UseCompression::No, UseCompression::Yes),
+                false => (UseCompression::Yes, UseCompression::No),
+            };
+            convert_compression(
+                compressed_input,
+                &opt.input_fname,
+                compressed_output,
+                &opt.output_fname,
+                opt.response,
+                &parameters,
+            );
+        }
+        Command::Split(opt) => {
+            split(
+                CHALLENGE_IS_COMPRESSED,
+                &opt.challenge_fname,
+                &opt.chunk_prefix,
+                opt.number_of_chunks,
+                &parameters,
+            );
+        }
+        Command::VerifyBeacon(opt) => {
+            let beacon = verify_beacon(&opt.beacon_hash, &opt.beacon_transcript_fname)
+                .unwrap_or_else(|e| panic!("INVALID BEACON TRANSCRIPT: {}", e));
+            println!(
+                "Verified {} checkpoints over 2^{} iterations",
+                beacon.checkpoints.len(),
+                beacon.iterations_log2
+            );
+            println!("Final result of beacon: {}", hex::encode(beacon.result));
+        }
+        Command::VerifyReceipt(opt) => {
+            verify_receipt(
+                CHALLENGE_IS_COMPRESSED,
+                CONTRIBUTION_IS_COMPRESSED,
+                &opt.receipt_fname,
+                &opt.transcript_dir,
+                &parameters,
+            );
+        }
     };
 
     let new_now = Instant::now();
@@ -112,6 +293,7 @@ fn main() {
 
     match opts.curve_kind {
         CurveKind::Bls12_377 => execute_cmd::<Bls12_377>(opts),
+        CurveKind::Bls12_381 => execute_cmd::<Bls12_381>(opts),
         CurveKind::BW6 => execute_cmd::<BW6_761>(opts),
     };
 }
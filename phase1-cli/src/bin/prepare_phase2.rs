@@ -3,13 +3,17 @@ use phase1::{
     parameters::*,
     Phase1,
 };
-use setup_utils::{CheckForCorrectness, Groth16Params, Result, UseCompression};
+use setup_utils::{curves::Bls12_381, CheckForCorrectness, Groth16Params, NoProgress, Result, UseCompression};
 
 use snarkvm_curves::{bls12_377::Bls12_377, bw6_761::BW6_761, PairingEngine as Engine};
 
 use gumdrop::Options;
 use memmap::*;
-use std::{fs::OpenOptions, time::Instant};
+use std::{
+    fs::OpenOptions,
+    io::{BufWriter, Write},
+    time::Instant,
+};
 use tracing_subscriber::{
     filter::EnvFilter,
     fmt::{time, Subscriber},
@@ -59,35 +63,31 @@ fn prepare_phase2<E: Engine + Sync>(opts: &PreparePhase2Opts) -> Result<()> {
     };
 
     // Create the parameter file
-    let mut writer = OpenOptions::new()
-        .read(false)
-        .write(true)
-        .create_new(true)
-        .open(&opts.phase2_fname)
-        .expect("unable to create parameter file in this directory");
+    let mut writer = BufWriter::new(
+        OpenOptions::new()
+            .read(false)
+            .write(true)
+            .create_new(true)
+            .open(&opts.phase2_fname)
+            .expect("unable to create parameter file in this directory"),
+    );
 
-    // Deserialize the accumulator
-    let current_accumulator = Phase1::deserialize(
-        &response_readable_map,
-        UseCompression::Yes,
-        CheckForCorrectness::Full,
-        &parameters,
-    )
-    .expect("unable to read uncompressed accumulator");
+    // Locate the sections of the accumulator, which are deserialized one at a time
+    let powers = Phase1::serialized_powers(&response_readable_map, UseCompression::Yes, &parameters)
+        .expect("unable to read compressed accumulator");
 
-    // Load the elements to the Groth16 utility
-    let groth16_params = Groth16Params::<E>::new(
+    // Transform each section to Lagrange coefficients, and write it before the next one
+    Groth16Params::<E>::write_streaming(
+        &mut writer,
+        UseCompression::No,
         2usize.pow(opts.phase2_size),
-        current_accumulator.tau_powers_g1,
-        current_accumulator.tau_powers_g2,
-        current_accumulator.alpha_tau_powers_g1,
-        current_accumulator.beta_tau_powers_g1,
-        current_accumulator.beta_g2,
+        opts.batch_size,
+        powers,
+        CheckForCorrectness::Full,
+        &NoProgress,
     )
     .expect("could not create Groth16 Lagrange coefficients");
-
-    // Write the parameters
-    groth16_params.write(&mut writer, UseCompression::No)?;
+    writer.flush()?;
 
     Ok(())
 }
@@ -103,6 +103,7 @@ fn main() -> Result<()> {
     let now = Instant::now();
     match opts.curve_kind {
         CurveKind::Bls12_377 => prepare_phase2::<Bls12_377>(&opts)?,
+        CurveKind::Bls12_381 => prepare_phase2::<Bls12_381>(&opts)?,
         CurveKind::BW6 => prepare_phase2::<BW6_761>(&opts)?,
     }
 
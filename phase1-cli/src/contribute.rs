@@ -1,15 +1,18 @@
-use phase1::{Phase1, Phase1Parameters};
-use setup_utils::{calculate_hash, CheckForCorrectness, UseCompression};
+use crate::header::{header_size, split_header, write_header};
+use phase1::{Checkpoint, Phase1, Phase1Parameters};
+use setup_utils::{calculate_hash, CheckForCorrectness, Error, Progress, UseCompression};
 
 use snarkvm_curves::PairingEngine as Engine;
 
 use memmap::*;
 use rand::{CryptoRng, Rng};
 use std::{
-    fs::OpenOptions,
-    io::{Read, Write},
+    fs::{self, OpenOptions},
+    io::{ErrorKind, Read, Write},
+    path::Path,
 };
 
+#[allow(clippy::too_many_arguments)]
 pub fn contribute<T: Engine + Sync>(
     compressed_input: UseCompression,
     challenge_filename: &str,
@@ -18,41 +21,61 @@ pub fn contribute<T: Engine + Sync>(
     check_input_correctness: CheckForCorrectness,
     parameters: &Phase1Parameters<T>,
     mut rng: impl Rng + CryptoRng,
+    resumable: bool,
+    progress: &dyn Progress,
 ) {
     // Try to load challenge file from disk.
     let reader = OpenOptions::new()
         .read(true)
         .open(challenge_filename)
         .expect("unable open challenge file");
-    {
-        let metadata = reader
-            .metadata()
-            .expect("unable to get filesystem metadata for challenge file");
-        let expected_challenge_length = match compressed_input {
-            UseCompression::Yes => parameters.contribution_size - parameters.public_key_size,
-            UseCompression::No => parameters.accumulator_size,
-        };
-
-        if metadata.len() != (expected_challenge_length as u64) {
-            panic!(
-                "The size of challenge file should be {}, but it's {}, so something isn't right.",
-                expected_challenge_length,
-                metadata.len()
-            );
-        }
-    }
 
-    let readable_map = unsafe {
+    let challenge_map = unsafe {
         MmapOptions::new()
             .map(&reader)
             .expect("unable to create a memory map for input")
     };
 
-    // Create response file in this directory
+    // The challenge may start with a header, in which case the response gets one too.
+    let expected_challenge_length = match compressed_input {
+        UseCompression::Yes => parameters.contribution_size - parameters.public_key_size,
+        UseCompression::No => parameters.accumulator_size,
+    };
+    let (challenge_header, readable_map) = split_header(
+        &challenge_map,
+        "challenge",
+        expected_challenge_length,
+        compressed_input,
+        parameters,
+    );
+    let response_offset = header_size(challenge_header.is_some());
+
+    // A resumable contribution keeps a checkpoint next to the response file,
+    // and continues from it if the response file already exists. If the contribution
+    // was interrupted before its first checkpoint, or the checkpoint can't be decoded,
+    // it starts over from the first batch.
+    let checkpoint_filename = format!("{}.checkpoint", response_filename);
+    let resuming = resumable && Path::new(response_filename).exists();
+    let checkpoint = match resuming {
+        true => match fs::read(&checkpoint_filename) {
+            Ok(bytes) => match Checkpoint::from_bytes(&bytes) {
+                Ok(checkpoint) => Some(checkpoint),
+                Err(e) => {
+                    tracing::warn!("Unable to read the checkpoint ({}), starting over", e);
+                    None
+                }
+            },
+            Err(e) if e.kind() == ErrorKind::NotFound => None,
+            Err(e) => panic!("unable to read the checkpoint of the response file: {}", e),
+        },
+        false => None,
+    };
+
+    // Create response file in this directory, or reopen it to resume the contribution
     let writer = OpenOptions::new()
         .read(true)
         .write(true)
-        .create_new(true)
+        .create_new(!resuming)
         .open(response_filename)
         .expect("unable to create response file");
 
@@ -62,7 +85,7 @@ pub fn contribute<T: Engine + Sync>(
     };
 
     writer
-        .set_len(required_output_length as u64)
+        .set_len((response_offset + required_output_length) as u64)
         .expect("must make output file large enough");
 
     let mut writable_map = unsafe {
@@ -70,6 +93,9 @@ pub fn contribute<T: Engine + Sync>(
             .map_mut(&writer)
             .expect("unable to create a memory map for output")
     };
+    if challenge_header.is_some() {
+        write_header(&mut writable_map, compressed_output, parameters);
+    }
 
     tracing::info!("Calculating previous contribution hash...");
 
@@ -77,13 +103,13 @@ pub fn contribute<T: Engine + Sync>(
         UseCompression::No == compressed_input,
         "Hashing the compressed file in not yet defined"
     );
-    let current_accumulator_hash = calculate_hash(&readable_map);
+    let current_accumulator_hash = calculate_hash(readable_map);
 
     {
         tracing::info!("`challenge` file contains decompressed points and has a hash:");
         log_hash(&current_accumulator_hash);
 
-        (&mut writable_map[0..])
+        (&mut writable_map[response_offset..])
             .write_all(current_accumulator_hash.as_slice())
             .expect("unable to write a challenge hash to mmap");
 
@@ -111,29 +137,84 @@ pub fn contribute<T: Engine + Sync>(
     tracing::info!("Computing and writing your contribution, this could take a while...");
 
     // this computes a transformation and writes it
-    Phase1::computation(
-        &readable_map,
-        &mut writable_map,
-        compressed_input,
-        compressed_output,
-        check_input_correctness,
-        &private_key,
-        &parameters,
-    )
-    .expect("must contribute with the key");
+    if resumable {
+        // The response must be on disk before the checkpoint which refers to it. The output
+        // map is borrowed by the computation, so it is flushed through a second map of the file.
+        let checkpoint_map = unsafe {
+            MmapOptions::new()
+                .map_mut(&writer)
+                .expect("unable to create a memory map for output")
+        };
+        let mut save_checkpoint = |checkpoint: &Checkpoint| -> Result<(), Error> {
+            checkpoint_map.flush()?;
+            let temporary_filename = format!("{}.tmp", checkpoint_filename);
+            fs::write(&temporary_filename, checkpoint.to_bytes())?;
+            fs::rename(&temporary_filename, &checkpoint_filename)?;
+            Ok(())
+        };
+        if let Some(checkpoint) = &checkpoint {
+            tracing::info!("Resuming after {} completed batches...", checkpoint.completed_batches);
+        }
+
+        let result = match Phase1::resumable_computation(
+            readable_map,
+            &mut writable_map[response_offset..],
+            compressed_input,
+            compressed_output,
+            check_input_correctness,
+            &private_key,
+            &parameters,
+            progress,
+            checkpoint.as_ref(),
+            &mut save_checkpoint,
+        ) {
+            Err(Error::InvalidCheckpoint(e)) => {
+                tracing::warn!("Unable to resume the contribution ({}), starting over", e);
+                Phase1::resumable_computation(
+                    readable_map,
+                    &mut writable_map[response_offset..],
+                    compressed_input,
+                    compressed_output,
+                    check_input_correctness,
+                    &private_key,
+                    &parameters,
+                    progress,
+                    None,
+                    &mut save_checkpoint,
+                )
+            }
+            result => result,
+        };
+        result.expect("must contribute with the key");
+    } else {
+        Phase1::computation_with_progress(
+            readable_map,
+            &mut writable_map[response_offset..],
+            compressed_input,
+            compressed_output,
+            check_input_correctness,
+            &private_key,
+            &parameters,
+            progress,
+        )
+        .expect("must contribute with the key");
+    }
 
     tracing::info!("Finishing writing your contribution to response file...");
 
     // Write the public key
     public_key
-        .write(&mut writable_map, compressed_output, &parameters)
+        .write(&mut writable_map[response_offset..], compressed_output, &parameters)
         .expect("unable to write public key");
 
     writable_map.flush().expect("must flush a memory map");
 
     // Get the hash of the contribution, so the user can compare later
     let output_readonly = writable_map.make_read_only().expect("must make a map readonly");
-    let contribution_hash = calculate_hash(&output_readonly);
+    let contribution_hash = calculate_hash(&output_readonly[response_offset..]);
+    if resumable {
+        fs::remove_file(&checkpoint_filename).expect("unable to remove the checkpoint of the response file");
+    }
 
     tracing::info!(
         "Done!\n\n\
@@ -0,0 +1,83 @@
+use crate::header::{header_size, split_header, write_header};
+use phase1::{Phase1, Phase1Parameters};
+use setup_utils::{calculate_hash, print_hash, CheckForCorrectness, UseCompression};
+
+use snarkvm_curves::PairingEngine as Engine;
+
+use fs_err::OpenOptions;
+use memmap::*;
+
+/// Reads a challenge, or a response if `with_public_key` is set, and writes it with the output
+/// compression. The hash and the public key are kept, and so is the header if the input has one.
+pub fn convert_compression<T: Engine + Sync>(
+    compressed_input: UseCompression,
+    input_filename: &str,
+    compressed_output: UseCompression,
+    output_filename: &str,
+    with_public_key: bool,
+    parameters: &Phase1Parameters<T>,
+) {
+    println!(
+        "Will convert {} from compression {} to compression {}",
+        input_filename, compressed_input, compressed_output
+    );
+
+    let public_key_size = match with_public_key {
+        true => parameters.public_key_size,
+        false => 0,
+    };
+
+    let reader = OpenOptions::new()
+        .read(true)
+        .open(input_filename)
+        .expect("unable open input file in this directory");
+    let input_map = unsafe {
+        MmapOptions::new()
+            .map(reader.file())
+            .expect("unable to create a memory map for input")
+    };
+    let (input_header, input) = split_header(
+        &input_map,
+        "input",
+        parameters.get_length(compressed_input) + public_key_size,
+        compressed_input,
+        parameters,
+    );
+    println!("Input file hash:");
+    print_hash(&calculate_hash(input));
+
+    let file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create_new(true)
+        .open(output_filename)
+        .expect("unable to create output file");
+    let offset = header_size(input_header.is_some());
+    file.set_len((offset + parameters.get_length(compressed_output) + public_key_size) as u64)
+        .expect("unable to allocate large enough file");
+
+    let mut writable_map = unsafe {
+        MmapOptions::new()
+            .map_mut(file.file())
+            .expect("unable to create a memory map")
+    };
+    if input_header.is_some() {
+        write_header(&mut writable_map, compressed_output, parameters);
+    }
+
+    Phase1::convert_compression(
+        input,
+        &mut writable_map[offset..],
+        compressed_input,
+        compressed_output,
+        with_public_key,
+        CheckForCorrectness::Full,
+        parameters,
+    )
+    .expect("unable to convert the input file");
+    writable_map.flush().expect("unable to flush memmap to disk");
+
+    let output_readonly = writable_map.make_read_only().expect("must make a map readonly");
+    println!("Wrote the converted file with a hash:");
+    print_hash(&calculate_hash(&output_readonly[offset..]));
+}
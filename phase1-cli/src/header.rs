@@ -0,0 +1,40 @@
+use phase1::{check_file_header, FileHeader, Phase1Parameters, HEADER_SIZE};
+use setup_utils::UseCompression;
+
+use snarkvm_curves::PairingEngine as Engine;
+
+/// Splits the optional header from a file, and checks that it describes the given
+/// parameters and that the rest of the file has the expected length.
+pub(crate) fn split_header<'a, T: Engine>(
+    file: &'a [u8],
+    name: &str,
+    expected_length: usize,
+    compression: UseCompression,
+    parameters: &Phase1Parameters<T>,
+) -> (Option<FileHeader>, &'a [u8]) {
+    let (header, body) = check_file_header(file, compression, parameters)
+        .unwrap_or_else(|e| panic!("The header of the {} file is invalid: {}", name, e));
+    if body.len() != expected_length {
+        panic!(
+            "The size of {} file should be {}, but it's {}, so something isn't right.",
+            name,
+            expected_length,
+            body.len()
+        );
+    }
+    (header, body)
+}
+
+/// Writes a header describing the given parameters at the start of a file.
+pub(crate) fn write_header<T: Engine>(file: &mut [u8], compression: UseCompression, parameters: &Phase1Parameters<T>) {
+    let header = FileHeader::new(parameters, compression).expect("unable to describe the file in a header");
+    file[..HEADER_SIZE].copy_from_slice(&header.to_bytes());
+}
+
+/// Returns the size of the header of a file.
+pub(crate) fn header_size(with_header: bool) -> usize {
+    match with_header {
+        true => HEADER_SIZE,
+        false => 0,
+    }
+}
@@ -0,0 +1,160 @@
+use phase1::{FileHeader, Phase1, Phase1Parameters, PublicKey, HEADER_SIZE};
+use setup_utils::{calculate_hash, print_hash, UseCompression};
+
+use snarkvm_curves::PairingEngine as Engine;
+
+use fs_err::OpenOptions;
+use memmap::*;
+
+/// Prints the header, the sizes, the hashes, the public key and the sections of an
+/// accumulator file, and whether its elements are valid points.
+pub fn inspect<T: Engine + Sync>(
+    compressed: UseCompression,
+    filename: &str,
+    with_public_key: bool,
+    parameters: &Phase1Parameters<T>,
+) {
+    let map = read_file(filename);
+    let body = inspect_header(&map, compressed, parameters);
+
+    let expected_length = expected_length(compressed, with_public_key, parameters);
+    println!(
+        "Expected size: {} bytes, actual size: {} bytes",
+        expected_length,
+        body.len()
+    );
+    if body.len() < expected_length {
+        panic!("The file {} is too short to be inspected", filename);
+    }
+
+    println!("Hash of the previous file:");
+    print_hash(&body[..parameters.hash_size]);
+    println!("Hash of this file:");
+    print_hash(&calculate_hash(body));
+
+    if with_public_key {
+        match PublicKey::<T>::read(body, compressed, parameters) {
+            Ok(public_key) => println!("Public key: {:?}", public_key),
+            Err(e) => println!("Public key: unable to decode it: {}", e),
+        }
+    }
+
+    let reports =
+        Phase1::inspect_sections(body, compressed, parameters).expect("unable to inspect the accumulator sections");
+    for report in reports {
+        println!("{}: {} elements", report.element_type, report.len);
+        println!("\tfirst: {}", report.first.as_deref().unwrap_or("undecodable"));
+        println!("\tlast: {}", report.last.as_deref().unwrap_or("undecodable"));
+        match report.first_invalid {
+            None => println!("\tall elements are on the curve and in the subgroup"),
+            Some(index) => {
+                println!("\tinvalid elements, starting at index {}:", index);
+                println!(
+                    "\t{} undecodable, {} at infinity, {} not on the curve, {} not in the subgroup",
+                    report.undecodable, report.at_infinity, report.not_on_curve, report.not_in_subgroup
+                );
+            }
+        }
+    }
+}
+
+/// Compares two accumulator files with the same parameters section by section, and
+/// prints the index of the first element which differs in each section.
+pub fn diff<T: Engine + Sync>(
+    compressed: UseCompression,
+    first_filename: &str,
+    second_filename: &str,
+    with_public_key: bool,
+    parameters: &Phase1Parameters<T>,
+) {
+    let first_map = read_file(first_filename);
+    let second_map = read_file(second_filename);
+    println!("Header of {}:", first_filename);
+    let first = inspect_header(&first_map, compressed, parameters);
+    println!("Header of {}:", second_filename);
+    let second = inspect_header(&second_map, compressed, parameters);
+
+    let expected_length = expected_length(compressed, with_public_key, parameters);
+    for (filename, body) in &[(first_filename, first), (second_filename, second)] {
+        if body.len() != expected_length {
+            panic!(
+                "The size of {} should be {}, but it's {}, so something isn't right.",
+                filename,
+                expected_length,
+                body.len()
+            );
+        }
+    }
+
+    let hash_size = parameters.hash_size;
+    match first[..hash_size] == second[..hash_size] {
+        true => println!("Hash of the previous file: identical"),
+        false => println!("Hash of the previous file: different"),
+    }
+
+    let diffs =
+        Phase1::diff_sections(first, second, compressed, parameters).expect("unable to compare the accumulators");
+    for diff in diffs {
+        match diff.first_difference {
+            None => println!("{}: identical", diff.element_type),
+            Some(index) => println!("{}: first difference at index {}", diff.element_type, index),
+        }
+    }
+
+    if with_public_key {
+        let public_key_start = expected_length - parameters.public_key_size;
+        match first[public_key_start..] == second[public_key_start..] {
+            true => println!("Public key: identical"),
+            false => println!("Public key: different"),
+        }
+    }
+}
+
+fn read_file(filename: &str) -> Mmap {
+    let reader = OpenOptions::new()
+        .read(true)
+        .open(filename)
+        .expect("unable open the file in this directory");
+    unsafe {
+        MmapOptions::new()
+            .map(reader.file())
+            .expect("unable to create a memory map for input")
+    }
+}
+
+/// Prints the header of a file, if it has one, and returns the rest of the file.
+fn inspect_header<'a, T: Engine>(
+    file: &'a [u8],
+    compressed: UseCompression,
+    parameters: &Phase1Parameters<T>,
+) -> &'a [u8] {
+    match FileHeader::read(file) {
+        Ok(None) => {
+            println!("No header");
+            file
+        }
+        Ok(Some(header)) => {
+            println!("Header: {:?}", header);
+            if let Err(e) = header.check(parameters, compressed) {
+                println!("The header does not match the provided parameters: {}", e);
+            }
+            &file[HEADER_SIZE..]
+        }
+        Err(e) => {
+            println!("Invalid header: {}", e);
+            &file[HEADER_SIZE.min(file.len())..]
+        }
+    }
+}
+
+/// Returns the expected length of a challenge, or of a response if it has a public key.
+fn expected_length<T: Engine>(
+    compressed: UseCompression,
+    with_public_key: bool,
+    parameters: &Phase1Parameters<T>,
+) -> usize {
+    match with_public_key {
+        true => parameters.get_length(compressed) + parameters.public_key_size,
+        false => parameters.get_length(compressed),
+    }
+}
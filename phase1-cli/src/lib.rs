@@ -7,20 +7,53 @@ pub use combine::combine;
 mod contribute;
 pub use contribute::contribute;
 
+mod convert_compression;
+pub use convert_compression::convert_compression;
+
+mod header;
+
+mod inspect;
+pub use inspect::{diff, inspect};
+
 mod new_challenge;
 pub use new_challenge::new_challenge;
 
+mod ptau;
+pub use ptau::{export_ptau, import_ptau};
+
+mod receipt;
+pub use receipt::{verify_receipt, write_receipt};
+
+mod split;
+pub use split::split;
+
 mod transform_pok_and_correctness;
 pub use transform_pok_and_correctness::transform_pok_and_correctness;
 
 mod transform_ratios;
 pub use transform_ratios::transform_ratios;
 
+mod universal_params;
+pub use universal_params::export_universal_params;
+
+mod verify_transcript;
+pub use verify_transcript::verify_transcript;
+
+mod zcash;
+pub use zcash::{export_zcash, import_zcash};
+
 use phase1::{
-    helpers::{contribution_mode_from_str, curve_from_str, proving_system_from_str, CurveKind},
+    helpers::{
+        contribution_mode_from_str,
+        curve_from_str,
+        proving_system_from_str,
+        verification_strategy_from_str,
+        CurveKind,
+    },
     ContributionMode,
     ProvingSystem,
 };
+use setup_utils::VerificationStrategy;
 
 use gumdrop::Options;
 use std::default::Default;
@@ -52,6 +85,12 @@ pub struct Phase1Opts {
         parse(try_from_str = "proving_system_from_str")
     )]
     pub proving_system: ProvingSystem,
+    #[options(
+        help = "the strategy used to verify the ratios of an accumulator",
+        default = "per_batch",
+        parse(try_from_str = "verification_strategy_from_str")
+    )]
+    pub verification_strategy: VerificationStrategy,
     #[options(help = "the size of batches to process", default = "256")]
     pub batch_size: usize,
     #[options(help = "the circuit power (circuit size will be 2^{power})", default = "21")]
@@ -81,6 +120,42 @@ pub enum Command {
     // this receives a list of chunked responses and combines them into a single response.
     #[options(help = "receive a list of chunked responses and combines them into a single response")]
     Combine(CombineOpts),
+    // this receives a directory of challenges and responses, and verifies every contribution from the initial challenge.
+    #[options(help = "verify every contribution of a ceremony, starting from the initial challenge")]
+    VerifyTranscript(VerifyTranscriptOpts),
+    // this reads a snarkjs ptau file and writes its accumulator as a new challenge.
+    #[options(help = "import a snarkjs .ptau file as a new challenge, without its contributions")]
+    ImportPtau(ImportPtauOpts),
+    // this reads a challenge and writes its accumulator as a snarkjs ptau file.
+    #[options(help = "export a challenge as a snarkjs .ptau file")]
+    ExportPtau(ExportPtauOpts),
+    // this reads a powersoftau challenge or response and writes it in the format of this crate.
+    #[options(help = "import a challenge or response from the original powersoftau ceremony")]
+    ImportZcash(ImportZcashOpts),
+    // this reads a challenge or response and writes it in the powersoftau format.
+    #[options(help = "export a challenge or response to the format of the original powersoftau ceremony")]
+    ExportZcash(ExportZcashOpts),
+    // this reads the challenge of a full Marlin ceremony and writes the universal parameters for snarkVM.
+    #[options(help = "export the KZG10 universal parameters of a full Marlin challenge")]
+    ExportUniversalParams(ExportUniversalParamsOpts),
+    // this reads a challenge or response and prints its sizes, hashes, public key and sections.
+    #[options(help = "print the contents of a challenge or response, and check that its elements are valid")]
+    Inspect(InspectOpts),
+    // this reads two challenges or responses and prints the first differing element of each section.
+    #[options(help = "compare two challenges or responses section by section")]
+    Diff(DiffOpts),
+    // this reads a challenge or response and writes it with compressed or decompressed elements.
+    #[options(help = "decompress a challenge or response, or compress it with --compress")]
+    ConvertCompression(ConvertCompressionOpts),
+    // this reads a full challenge and writes a challenge for each chunk.
+    #[options(help = "split a full challenge into the challenges of its chunks")]
+    Split(SplitOpts),
+    // this reads the transcript of a beacon contribution and verifies its checkpoints.
+    #[options(help = "verify the transcript written by a beacon contribution")]
+    VerifyBeacon(VerifyBeaconOpts),
+    // this reads the receipt of a contribution and checks it against the response it describes in a transcript.
+    #[options(help = "check the receipt of a contribution against a transcript")]
+    VerifyReceipt(VerifyReceiptOpts),
 }
 
 // Options for the Contribute command
@@ -89,6 +164,8 @@ pub struct NewOpts {
     help: bool,
     #[options(help = "the challenge file name to be created", default = "challenge")]
     pub challenge_fname: String,
+    #[options(help = "start the challenge with a header describing its parameters")]
+    pub header: bool,
 }
 
 // Options for the Contribute command
@@ -104,6 +181,24 @@ pub struct ContributeOpts {
         default = "0000000000000000000a558a61ddc8ee4e488d647a747fe4dcc362fe2026c620"
     )]
     pub beacon_hash: String,
+    #[options(help = "keep a checkpoint next to the response file, and resume from it after an interruption")]
+    pub resumable: bool,
+    #[options(
+        help = "the base 2 logarithm of the number of hash iterations of the beacon",
+        default = "42"
+    )]
+    pub beacon_iterations_log2: u32,
+    #[options(help = "allow a beacon with fewer than 2^32 hash iterations, which is only secure for testing")]
+    pub insecure_beacon: bool,
+    #[options(
+        help = "the file the beacon transcript will be written to",
+        default = "beacon_transcript.json"
+    )]
+    pub beacon_transcript_fname: String,
+    #[options(
+        help = "the JSON receipt of the contribution which will be generated (default: <response>.receipt.json)"
+    )]
+    pub receipt_fname: Option<String>,
 }
 
 #[derive(Debug, Options, Clone)]
@@ -135,3 +230,131 @@ pub struct CombineOpts {
     #[options(help = "the combined response file", default = "combined")]
     pub combined_fname: String,
 }
+
+#[derive(Debug, Options, Clone)]
+pub struct VerifyTranscriptOpts {
+    help: bool,
+    #[options(
+        help = "the directory containing challenge_0, response_0, challenge_1, response_1, ...",
+        default = "transcript"
+    )]
+    pub transcript_dir: String,
+}
+
+#[derive(Debug, Options, Clone)]
+pub struct ImportPtauOpts {
+    help: bool,
+    #[options(help = "the snarkjs .ptau file to import", default = "powersOfTau.ptau")]
+    pub ptau_fname: String,
+    #[options(help = "the challenge file name to be created", default = "challenge")]
+    pub challenge_fname: String,
+}
+
+#[derive(Debug, Options, Clone)]
+pub struct ExportPtauOpts {
+    help: bool,
+    #[options(help = "the provided challenge file", default = "challenge")]
+    pub challenge_fname: String,
+    #[options(help = "the snarkjs .ptau file to be created", default = "powersOfTau.ptau")]
+    pub ptau_fname: String,
+    #[options(help = "a .ptau file whose contributions are copied to the created file (default: no contributions)")]
+    pub history_fname: Option<String>,
+}
+
+#[derive(Debug, Options, Clone)]
+pub struct ImportZcashOpts {
+    help: bool,
+    #[options(help = "the powersoftau file to import", default = "challenge.zcash")]
+    pub zcash_fname: String,
+    #[options(help = "the file name to be created", default = "challenge")]
+    pub output_fname: String,
+}
+
+#[derive(Debug, Options, Clone)]
+pub struct ExportZcashOpts {
+    help: bool,
+    #[options(help = "the provided challenge file", default = "challenge")]
+    pub input_fname: String,
+    #[options(help = "the powersoftau file to be created", default = "challenge.zcash")]
+    pub zcash_fname: String,
+}
+
+#[derive(Debug, Options, Clone)]
+pub struct ExportUniversalParamsOpts {
+    help: bool,
+    #[options(help = "the provided challenge file", default = "challenge")]
+    pub challenge_fname: String,
+    #[options(help = "the universal parameters file to be created", default = "universal_params")]
+    pub output_fname: String,
+}
+
+#[derive(Debug, Options, Clone)]
+pub struct InspectOpts {
+    help: bool,
+    #[options(help = "inspect a response file instead of a challenge file")]
+    pub response: bool,
+    #[options(help = "the provided challenge or response file", default = "challenge")]
+    pub input_fname: String,
+}
+
+#[derive(Debug, Options, Clone)]
+pub struct DiffOpts {
+    help: bool,
+    #[options(help = "compare response files instead of challenge files")]
+    pub response: bool,
+    #[options(help = "the first challenge or response file", default = "challenge")]
+    pub first_fname: String,
+    #[options(help = "the second challenge or response file", default = "new_challenge")]
+    pub second_fname: String,
+}
+
+#[derive(Debug, Options, Clone)]
+pub struct ConvertCompressionOpts {
+    help: bool,
+    #[options(help = "convert a response file instead of a challenge file, keeping its public key")]
+    pub response: bool,
+    #[options(help = "compress the elements of the file instead of decompressing them")]
+    pub compress: bool,
+    #[options(help = "the provided challenge or response file", default = "challenge")]
+    pub input_fname: String,
+    #[options(help = "the converted file to be created", default = "challenge.converted")]
+    pub output_fname: String,
+}
+
+#[derive(Debug, Options, Clone)]
+pub struct SplitOpts {
+    help: bool,
+    #[options(help = "the provided full challenge file", default = "challenge")]
+    pub challenge_fname: String,
+    #[options(
+        help = "the prefix of the chunk challenge files, which are suffixed with _{chunk index}",
+        default = "challenge_chunk"
+    )]
+    pub chunk_prefix: String,
+    #[options(help = "the number of chunks to split the challenge into", default = "1")]
+    pub number_of_chunks: usize,
+}
+
+#[derive(Debug, Options, Clone)]
+pub struct VerifyBeaconOpts {
+    help: bool,
+    #[options(
+        help = "the beacon hash the transcript should start from",
+        default = "0000000000000000000a558a61ddc8ee4e488d647a747fe4dcc362fe2026c620"
+    )]
+    pub beacon_hash: String,
+    #[options(help = "the provided beacon transcript file", default = "beacon_transcript.json")]
+    pub beacon_transcript_fname: String,
+}
+
+#[derive(Debug, Options, Clone)]
+pub struct VerifyReceiptOpts {
+    help: bool,
+    #[options(help = "the provided receipt file", default = "response.receipt.json")]
+    pub receipt_fname: String,
+    #[options(
+        help = "the directory containing challenge_0, response_0, challenge_1, response_1, ...",
+        default = "transcript"
+    )]
+    pub transcript_dir: String,
+}
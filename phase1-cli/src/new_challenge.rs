@@ -1,3 +1,4 @@
+use crate::header::{header_size, write_header};
 use phase1::{Phase1, Phase1Parameters};
 use setup_utils::{blank_hash, calculate_hash, print_hash, UseCompression};
 
@@ -9,6 +10,7 @@ use std::{fs::OpenOptions, io::Write};
 pub fn new_challenge<T: Engine + Sync>(
     compress_new_challenge: UseCompression,
     challenge_filename: &str,
+    with_header: bool,
     parameters: &Phase1Parameters<T>,
 ) {
     println!(
@@ -29,7 +31,8 @@ pub fn new_challenge<T: Engine + Sync>(
         UseCompression::No => parameters.accumulator_size,
     };
 
-    file.set_len(expected_challenge_length as u64)
+    let offset = header_size(with_header);
+    file.set_len((offset + expected_challenge_length) as u64)
         .expect("unable to allocate large enough file");
 
     let mut writable_map = unsafe {
@@ -37,10 +40,13 @@ pub fn new_challenge<T: Engine + Sync>(
             .map_mut(&file)
             .expect("unable to create a memory map")
     };
+    if with_header {
+        write_header(&mut writable_map, compress_new_challenge, parameters);
+    }
 
     // Write a blank BLAKE2b hash:
     let hash = blank_hash();
-    (&mut writable_map[0..])
+    (&mut writable_map[offset..])
         .write_all(hash.as_slice())
         .expect("unable to write a default hash to mmap");
     writable_map
@@ -50,13 +56,13 @@ pub fn new_challenge<T: Engine + Sync>(
     println!("Blank hash for an empty challenge:");
     print_hash(&hash);
 
-    Phase1::initialization(&mut writable_map, compress_new_challenge, &parameters)
+    Phase1::initialization(&mut writable_map[offset..], compress_new_challenge, &parameters)
         .expect("generation of initial accumulator is successful");
     writable_map.flush().expect("unable to flush memmap to disk");
 
     // Get the hash of the contribution, so the user can compare later
     let output_readonly = writable_map.make_read_only().expect("must make a map readonly");
-    let contribution_hash = calculate_hash(&output_readonly);
+    let contribution_hash = calculate_hash(&output_readonly[offset..]);
 
     println!("Empty contribution is formed with a hash:");
     print_hash(&contribution_hash);
@@ -0,0 +1,143 @@
+use phase1::{Phase1, Phase1Parameters};
+use setup_utils::{blank_hash, calculate_hash, print_hash, CheckForCorrectness, UseCompression};
+
+use snarkvm_curves::PairingEngine as Engine;
+
+use fs_err::{File, OpenOptions};
+use memmap::*;
+use std::io::{BufWriter, Write};
+
+/// Reads a snarkjs `.ptau` file and writes its accumulator as a challenge file.
+pub fn import_ptau<T: Engine + Sync>(
+    ptau_filename: &str,
+    compress_challenge: UseCompression,
+    challenge_filename: &str,
+    parameters: &Phase1Parameters<T>,
+) {
+    println!(
+        "Will import an accumulator for 2^{} powers of tau from {}",
+        parameters.total_size_in_log2, ptau_filename
+    );
+
+    let ptau_reader = File::open(ptau_filename).expect("unable to open ptau file in this directory");
+    let ptau_map = unsafe {
+        MmapOptions::new()
+            .map(ptau_reader.file())
+            .expect("unable to create a memory map for input")
+    };
+
+    let (accumulator, contributions) = Phase1::read_ptau(&ptau_map, CheckForCorrectness::Full, parameters)
+        .expect("unable to read the accumulator from the ptau file");
+    println!(
+        "The ptau file contains {} contributions, which are not kept in the challenge. Pass the ptau file to \
+         export-ptau with --history-fname to copy them to an exported file.",
+        contributions.len()
+    );
+
+    let file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create_new(true)
+        .open(challenge_filename)
+        .expect("unable to create challenge file");
+    file.set_len(parameters.get_length(compress_challenge) as u64)
+        .expect("unable to allocate large enough file");
+
+    let mut writable_map = unsafe {
+        MmapOptions::new()
+            .map_mut(file.file())
+            .expect("unable to create a memory map")
+    };
+
+    // Write a blank BLAKE2b hash, as the imported accumulator starts a new hash chain.
+    (&mut writable_map[0..])
+        .write_all(blank_hash().as_slice())
+        .expect("unable to write a default hash to mmap");
+    accumulator
+        .serialize(&mut writable_map, compress_challenge, parameters)
+        .expect("unable to write the accumulator to the challenge file");
+    writable_map.flush().expect("unable to flush memmap to disk");
+
+    let output_readonly = writable_map.make_read_only().expect("must make a map readonly");
+    println!("Wrote the imported accumulator to the challenge file with a hash:");
+    print_hash(&calculate_hash(&output_readonly));
+}
+
+/// Reads a challenge file and writes its accumulator as a snarkjs `.ptau` file.
+///
+/// The challenge file does not carry the history of the ceremony, so the contributions
+/// are copied from the `.ptau` file at `history_filename`, if one is given. Otherwise,
+/// the resulting file has an empty contributions section. The history is refused if its
+/// last contribution did not produce the accumulator of the challenge.
+pub fn export_ptau<T: Engine + Sync>(
+    challenge_is_compressed: UseCompression,
+    challenge_filename: &str,
+    ptau_filename: &str,
+    history_filename: Option<&str>,
+    parameters: &Phase1Parameters<T>,
+) {
+    println!(
+        "Will export an accumulator for 2^{} powers of tau to {}",
+        parameters.total_size_in_log2, ptau_filename
+    );
+
+    let challenge_reader = OpenOptions::new()
+        .read(true)
+        .open(challenge_filename)
+        .expect("unable open challenge file in this directory");
+    {
+        let metadata = challenge_reader
+            .metadata()
+            .expect("unable to get filesystem metadata for challenge file");
+        let expected_challenge_length = parameters.get_length(challenge_is_compressed);
+        if metadata.len() != (expected_challenge_length as u64) {
+            panic!(
+                "The size of challenge file should be {}, but it's {}, so something isn't right.",
+                expected_challenge_length,
+                metadata.len()
+            );
+        }
+    }
+
+    let challenge_map = unsafe {
+        MmapOptions::new()
+            .map(challenge_reader.file())
+            .expect("unable to create a memory map for input")
+    };
+
+    let accumulator = Phase1::deserialize(
+        &challenge_map,
+        challenge_is_compressed,
+        CheckForCorrectness::Full,
+        parameters,
+    )
+    .expect("unable to read the accumulator from the challenge file");
+
+    let contributions = match history_filename {
+        Some(history_filename) => {
+            let history_reader = File::open(history_filename).expect("unable to open history file in this directory");
+            let history_map = unsafe {
+                MmapOptions::new()
+                    .map(history_reader.file())
+                    .expect("unable to create a memory map for input")
+            };
+            let (_, contributions) = Phase1::read_ptau(&history_map, CheckForCorrectness::No, parameters)
+                .expect("unable to read the contributions from the history file");
+            println!(
+                "Copying {} contributions from {}",
+                contributions.len(),
+                history_filename
+            );
+            contributions
+        }
+        None => vec![],
+    };
+
+    let mut writer = BufWriter::new(File::create(ptau_filename).expect("unable to create ptau file"));
+    accumulator
+        .write_ptau(&contributions, &mut writer)
+        .expect("unable to write the accumulator to the ptau file");
+    writer.flush().expect("unable to flush the ptau file");
+
+    println!("Wrote the accumulator to {}", ptau_filename);
+}
@@ -0,0 +1,148 @@
+use crate::verify_transcript::{read_file, TranscriptFile};
+use phase1::{FileHeader, Phase1Parameters};
+use setup_utils::{calculate_hash, ContributionReceipt, ReceiptRandomness, UseCompression};
+
+use snarkvm_curves::PairingEngine as Engine;
+
+use fs_err::File;
+use std::{
+    collections::BTreeMap,
+    io::{BufReader, BufWriter, Write},
+    path::Path,
+    time::SystemTime,
+};
+
+/// Reads the response of a contribution which started at `started_at`, and writes a JSON
+/// receipt with the hashes of its challenge and of itself, its public key and its parameters.
+pub fn write_receipt<T: Engine>(
+    compressed_response: UseCompression,
+    response_filename: &str,
+    receipt_filename: &str,
+    started_at: SystemTime,
+    randomness: ReceiptRandomness,
+    parameters: &Phase1Parameters<T>,
+) {
+    let response = read_response(Path::new(response_filename), compressed_response, parameters)
+        .unwrap_or_else(|e| panic!("unable to read the response: {}", e));
+    let receipt = ContributionReceipt::new(
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_VERSION"),
+        receipt_parameters(compressed_response, parameters),
+        &response[..parameters.hash_size],
+        &calculate_hash(&response),
+        public_key(&response, compressed_response, parameters),
+        started_at,
+        randomness,
+    );
+
+    let mut writer = BufWriter::new(File::create(receipt_filename).expect("unable to create the receipt file"));
+    serde_json::to_writer_pretty(&mut writer, &receipt).expect("unable to write the receipt");
+    writer.flush().expect("unable to flush the receipt file");
+    println!("Wrote the receipt of the contribution to {}", receipt_filename);
+}
+
+/// Reads a JSON receipt, finds the response with the same hash in the transcript directory,
+/// and checks that the receipt matches its parameters, its public key and its challenge.
+pub fn verify_receipt<T: Engine>(
+    compressed_challenge: UseCompression,
+    compressed_response: UseCompression,
+    receipt_filename: &str,
+    transcript_directory: &str,
+    parameters: &Phase1Parameters<T>,
+) {
+    let reader = BufReader::new(File::open(receipt_filename).expect("unable to open the receipt file"));
+    let receipt: ContributionReceipt = serde_json::from_reader(reader).expect("unable to read the receipt");
+
+    // The responses are named as in `verify-transcript`.
+    let directory = Path::new(transcript_directory);
+    let mut index = 0;
+    let response = loop {
+        let path = directory.join(format!("response_{}", index));
+        if !path.exists() {
+            panic!(
+                "No response in {} has the hash {} of the receipt",
+                transcript_directory, receipt.response_hash
+            );
+        }
+        let response = read_response(&path, compressed_response, parameters)
+            .unwrap_or_else(|e| panic!("unable to read response_{}: {}", index, e));
+        if hex::encode(calculate_hash(&response)) == receipt.response_hash.to_lowercase() {
+            break response;
+        }
+        index += 1;
+    };
+    println!("The receipt is for response_{}", index);
+
+    if let Err(e) = receipt.check(
+        &receipt_parameters(compressed_response, parameters),
+        &response[..parameters.hash_size],
+        &calculate_hash(&response),
+        public_key(&response, compressed_response, parameters),
+    ) {
+        panic!("INVALID RECEIPT: {}", e);
+    }
+
+    // The response only claims the hash of its challenge, which is checked if the challenge is there.
+    let challenge_path = directory.join(format!("challenge_{}", index));
+    if challenge_path.exists() {
+        let challenge_length = match compressed_challenge {
+            UseCompression::Yes => parameters.contribution_size - parameters.public_key_size,
+            UseCompression::No => parameters.accumulator_size,
+        };
+        let challenge = read_file(&challenge_path, challenge_length, compressed_challenge, parameters)
+            .unwrap_or_else(|e| panic!("unable to read challenge_{}: {}", index, e));
+        if calculate_hash(&challenge)[..] != response[..parameters.hash_size] {
+            panic!(
+                "INVALID RECEIPT: response_{} is not based on challenge_{}",
+                index, index
+            );
+        }
+    }
+
+    println!("The receipt matches the transcript");
+}
+
+/// Returns the parameters recorded in a receipt, as they are described by a file header.
+fn receipt_parameters<T: Engine>(
+    compressed_response: UseCompression,
+    parameters: &Phase1Parameters<T>,
+) -> BTreeMap<String, String> {
+    let header = FileHeader::new(parameters, compressed_response).expect("the curve must be supported");
+    let mut receipt_parameters = BTreeMap::new();
+    receipt_parameters.insert("curve".to_string(), format!("{:?}", header.curve));
+    receipt_parameters.insert("proving_system".to_string(), format!("{:?}", header.proving_system));
+    receipt_parameters.insert("power".to_string(), header.total_size_in_log2.to_string());
+    receipt_parameters.insert(
+        "contribution_mode".to_string(),
+        format!("{:?}", header.contribution_mode),
+    );
+    receipt_parameters.insert("chunk_index".to_string(), header.chunk_index.to_string());
+    receipt_parameters.insert("chunk_size".to_string(), header.chunk_size.to_string());
+    receipt_parameters.insert("compression".to_string(), header.compression.to_string());
+    receipt_parameters
+}
+
+/// Returns the public key at the end of a response, as it is serialized.
+fn public_key<'a, T: Engine>(
+    response: &'a [u8],
+    compressed_response: UseCompression,
+    parameters: &Phase1Parameters<T>,
+) -> &'a [u8] {
+    let position = match compressed_response {
+        UseCompression::Yes => parameters.contribution_size - parameters.public_key_size,
+        UseCompression::No => parameters.accumulator_size,
+    };
+    &response[position..position + parameters.public_key_size]
+}
+
+fn read_response<T: Engine>(
+    path: &Path,
+    compressed_response: UseCompression,
+    parameters: &Phase1Parameters<T>,
+) -> Result<TranscriptFile, String> {
+    let response_length = match compressed_response {
+        UseCompression::Yes => parameters.contribution_size,
+        UseCompression::No => parameters.accumulator_size + parameters.public_key_size,
+    };
+    read_file(path, response_length, compressed_response, parameters)
+}
@@ -0,0 +1,93 @@
+use crate::header::{header_size, split_header, write_header};
+use phase1::{chunk_size, total_size_in_g1, ContributionMode, Phase1, Phase1Parameters};
+use setup_utils::{calculate_hash, print_hash, UseCompression};
+
+use snarkvm_curves::PairingEngine as Engine;
+
+use fs_err::OpenOptions;
+use memmap::*;
+
+/// Reads a full challenge and writes the challenge of each chunk, for the given number of chunks,
+/// to `{chunk_prefix}_{chunk_index}`. The chunks have a header if the full challenge has one.
+pub fn split<T: Engine + Sync>(
+    compressed: UseCompression,
+    challenge_filename: &str,
+    chunk_prefix: &str,
+    number_of_chunks: usize,
+    parameters: &Phase1Parameters<T>,
+) {
+    let full_parameters = parameters.into_chunk_parameters(ContributionMode::Full, 0, 0);
+    let proving_system = parameters.proving_system;
+    let power = parameters.total_size_in_log2;
+    let parameters = parameters.into_chunk_parameters(
+        ContributionMode::Chunked,
+        0,
+        chunk_size!(number_of_chunks, proving_system, power),
+    );
+    let total_size: usize = total_size_in_g1!(proving_system, power);
+    let number_of_chunks = (total_size + parameters.chunk_size - 1) / parameters.chunk_size;
+    println!(
+        "Will split {} into {} chunks of {} powers",
+        challenge_filename, number_of_chunks, parameters.chunk_size
+    );
+
+    let reader = OpenOptions::new()
+        .read(true)
+        .open(challenge_filename)
+        .expect("unable open challenge file in this directory");
+    let challenge_map = unsafe {
+        MmapOptions::new()
+            .map(reader.file())
+            .expect("unable to create a memory map for input")
+    };
+    let (challenge_header, challenge) = split_header(
+        &challenge_map,
+        "challenge",
+        full_parameters.get_length(compressed),
+        compressed,
+        &full_parameters,
+    );
+    let offset = header_size(challenge_header.is_some());
+
+    let mut writable_maps = (0..number_of_chunks)
+        .map(|chunk_index| {
+            let chunk_parameters =
+                parameters.into_chunk_parameters(ContributionMode::Chunked, chunk_index, parameters.chunk_size);
+            let file = OpenOptions::new()
+                .read(true)
+                .write(true)
+                .create_new(true)
+                .open(format!("{}_{}", chunk_prefix, chunk_index))
+                .expect("unable to create chunk challenge file in this directory");
+            file.set_len((offset + chunk_parameters.get_length(compressed)) as u64)
+                .expect("unable to allocate large enough file");
+
+            let mut writable_map = unsafe {
+                MmapOptions::new()
+                    .map_mut(file.file())
+                    .expect("unable to create a memory map for output")
+            };
+            if challenge_header.is_some() {
+                write_header(&mut writable_map, compressed, &chunk_parameters);
+            }
+            writable_map
+        })
+        .collect::<Vec<_>>();
+
+    Phase1::splitting(
+        (challenge, compressed),
+        &mut writable_maps
+            .iter_mut()
+            .map(|writable_map| (&mut writable_map[offset..], compressed))
+            .collect::<Vec<_>>(),
+        &parameters,
+    )
+    .expect("unable to split the challenge");
+
+    for (chunk_index, writable_map) in writable_maps.into_iter().enumerate() {
+        writable_map.flush().expect("unable to flush memmap to disk");
+        let output_readonly = writable_map.make_read_only().expect("must make a map readonly");
+        println!("Wrote {}_{} with a hash:", chunk_prefix, chunk_index);
+        print_hash(&calculate_hash(&output_readonly[offset..]));
+    }
+}
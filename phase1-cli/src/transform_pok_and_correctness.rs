@@ -1,3 +1,4 @@
+use crate::header::{header_size, split_header, write_header};
 use phase1::{Phase1, Phase1Parameters, PublicKey};
 use setup_utils::{calculate_hash, print_hash, CheckForCorrectness, UseCompression};
 
@@ -29,63 +30,54 @@ pub fn transform_pok_and_correctness<T: Engine + Sync>(
         .open(challenge_filename)
         .expect("unable open challenge file in this directory");
 
-    {
-        let metadata = challenge_reader
-            .metadata()
-            .expect("unable to get filesystem metadata for challenge file");
-        let expected_challenge_length = match challenge_is_compressed {
-            UseCompression::Yes => parameters.contribution_size - parameters.public_key_size,
-            UseCompression::No => parameters.accumulator_size,
-        };
-        if metadata.len() != (expected_challenge_length as u64) {
-            panic!(
-                "The size of challenge file should be {}, but it's {}, so something isn't right.",
-                expected_challenge_length,
-                metadata.len()
-            );
-        }
-    }
-
-    let challenge_readable_map = unsafe {
+    let challenge_map = unsafe {
         MmapOptions::new()
             .map(&challenge_reader)
             .expect("unable to create a memory map for input")
     };
 
+    let expected_challenge_length = match challenge_is_compressed {
+        UseCompression::Yes => parameters.contribution_size - parameters.public_key_size,
+        UseCompression::No => parameters.accumulator_size,
+    };
+    let (_, challenge_readable_map) = split_header(
+        &challenge_map,
+        "challenge",
+        expected_challenge_length,
+        challenge_is_compressed,
+        parameters,
+    );
+
     // Try to load response file from disk.
     let response_reader = OpenOptions::new()
         .read(true)
         .open(response_filename)
         .expect("unable open response file in this directory");
 
-    {
-        let metadata = response_reader
-            .metadata()
-            .expect("unable to get filesystem metadata for response file");
-        let expected_response_length = match contribution_is_compressed {
-            UseCompression::Yes => parameters.contribution_size,
-            UseCompression::No => parameters.accumulator_size + parameters.public_key_size,
-        };
-        if metadata.len() != (expected_response_length as u64) {
-            panic!(
-                "The size of response file should be {}, but it's {}, so something isn't right.",
-                expected_response_length,
-                metadata.len()
-            );
-        }
-    }
-
-    let response_readable_map = unsafe {
+    let response_map = unsafe {
         MmapOptions::new()
             .map(&response_reader)
             .expect("unable to create a memory map for input")
     };
 
+    // The response may start with a header, in which case the new challenge gets one too.
+    let expected_response_length = match contribution_is_compressed {
+        UseCompression::Yes => parameters.contribution_size,
+        UseCompression::No => parameters.accumulator_size + parameters.public_key_size,
+    };
+    let (response_header, response_readable_map) = split_header(
+        &response_map,
+        "response",
+        expected_response_length,
+        contribution_is_compressed,
+        parameters,
+    );
+
     println!("Calculating previous challenge hash...");
 
     // Check that contribution is correct
 
-    let current_accumulator_hash = calculate_hash(&challenge_readable_map);
+    let current_accumulator_hash = calculate_hash(challenge_readable_map);
 
     println!("Hash of the `challenge` file for verification:");
     print_hash(&current_accumulator_hash);
@@ -108,13 +100,13 @@ pub fn transform_pok_and_correctness<T: Engine + Sync>(
         }
     }
 
-    let response_hash = calculate_hash(&response_readable_map);
+    let response_hash = calculate_hash(response_readable_map);
 
     println!("Hash of the response file for verification:");
     print_hash(&response_hash);
 
     // get the contributor's public key
-    let public_key = PublicKey::read(&response_readable_map, contribution_is_compressed, &parameters)
+    let public_key = PublicKey::read(response_readable_map, contribution_is_compressed, &parameters)
         .expect("wasn't able to deserialize the response file's public key");
 
     // check that it follows the protocol
@@ -122,8 +114,8 @@ pub fn transform_pok_and_correctness<T: Engine + Sync>(
     println!("Verifying a contribution to contain proper powers and correspond to the public key...");
 
     let res = Phase1::verification(
-        &challenge_readable_map,
-        &response_readable_map,
+        challenge_readable_map,
+        response_readable_map,
         &public_key,
         current_accumulator_hash.as_slice(),
         challenge_is_compressed,
@@ -177,8 +169,9 @@ pub fn transform_pok_and_correctness<T: Engine + Sync>(
             .expect("unable to create new challenge file in this directory");
 
         // Recomputation strips the public key and uses hashing to link with the previous contribution after decompression
+        let new_challenge_offset = header_size(response_header.is_some());
         writer
-            .set_len(parameters.accumulator_size as u64)
+            .set_len((new_challenge_offset + parameters.accumulator_size) as u64)
             .expect("must make output file large enough");
 
         let mut writable_map = unsafe {
@@ -186,9 +179,12 @@ pub fn transform_pok_and_correctness<T: Engine + Sync>(
                 .map_mut(&writer)
                 .expect("unable to create a memory map for output")
         };
+        if response_header.is_some() {
+            write_header(&mut writable_map, compress_new_challenge, parameters);
+        }
 
         {
-            (&mut writable_map[0..])
+            (&mut writable_map[new_challenge_offset..])
                 .write_all(response_hash.as_slice())
                 .expect("unable to write a default hash to mmap");
 
@@ -198,8 +194,8 @@ pub fn transform_pok_and_correctness<T: Engine + Sync>(
         }
 
         Phase1::decompress(
-            &response_readable_map,
-            &mut writable_map,
+            response_readable_map,
+            &mut writable_map[new_challenge_offset..],
             CheckForCorrectness::No,
             &parameters,
         )
@@ -209,7 +205,7 @@ pub fn transform_pok_and_correctness<T: Engine + Sync>(
 
         let new_challenge_readable_map = writable_map.make_read_only().expect("must make a map readonly");
 
-        let recompressed_hash = calculate_hash(&new_challenge_readable_map);
+        let recompressed_hash = calculate_hash(&new_challenge_readable_map[new_challenge_offset..]);
 
         println!("Here's the BLAKE2b hash of the decompressed participant's response as new_challenge file:");
         print_hash(&recompressed_hash);
@@ -1,12 +1,17 @@
+use crate::header::split_header;
 use phase1::{Phase1, Phase1Parameters};
-use setup_utils::{calculate_hash, print_hash, CheckForCorrectness, UseCompression};
+use setup_utils::{calculate_hash, print_hash, CheckForCorrectness, UseCompression, VerificationStrategy};
 
 use snarkvm_curves::PairingEngine as Engine;
 
 use fs_err::OpenOptions;
 use memmap::*;
 
-pub fn transform_ratios<T: Engine + Sync>(response_filename: &str, parameters: &Phase1Parameters<T>) {
+pub fn transform_ratios<T: Engine + Sync>(
+    response_filename: &str,
+    strategy: VerificationStrategy,
+    parameters: &Phase1Parameters<T>,
+) {
     println!(
         "Will verify ratios in a contribution of accumulator for 2^{} powers of tau",
         parameters.total_size_in_log2
@@ -18,35 +23,30 @@ pub fn transform_ratios<T: Engine + Sync>(response_filename: &str, parameters: &
         .open(response_filename)
         .expect("unable open response file in this directory");
 
-    {
-        let parameters = Phase1Parameters::<T>::new_chunk(
-            parameters.contribution_mode,
-            0,
-            parameters.powers_g1_length,
-            parameters.proving_system,
-            parameters.total_size_in_log2,
-            parameters.batch_size,
-        );
-        let metadata = response_reader
-            .metadata()
-            .expect("unable to get filesystem metadata for response file");
-        let expected_response_length = parameters.accumulator_size;
-        if metadata.len() != (expected_response_length as u64) {
-            panic!(
-                "The size of response file should be {}, but it's {}, so something isn't right.",
-                expected_response_length,
-                metadata.len()
-            );
-        }
-    }
-
-    let response_readable_map = unsafe {
+    let response_map = unsafe {
         MmapOptions::new()
             .map(&response_reader.file())
             .expect("unable to create a memory map for input")
     };
 
-    let response_hash = calculate_hash(&response_readable_map);
+    let expected_response_length = Phase1Parameters::<T>::new_chunk(
+        parameters.contribution_mode,
+        0,
+        parameters.powers_g1_length,
+        parameters.proving_system,
+        parameters.total_size_in_log2,
+        parameters.batch_size,
+    )
+    .accumulator_size;
+    let (_, response_readable_map) = split_header(
+        &response_map,
+        "response",
+        expected_response_length,
+        UseCompression::No,
+        parameters,
+    );
+
+    let response_hash = calculate_hash(response_readable_map);
 
     println!("Hash of the response file for verification:");
     print_hash(&response_hash);
@@ -55,7 +55,8 @@ pub fn transform_ratios<T: Engine + Sync>(response_filename: &str, parameters: &
     println!("Verifying a contribution to contain proper powers and correspond to the public key...");
 
     let res = Phase1::aggregate_verification(
-        (&response_readable_map, UseCompression::No, CheckForCorrectness::No),
+        (response_readable_map, UseCompression::No, CheckForCorrectness::No),
+        strategy,
         &parameters,
     );
 
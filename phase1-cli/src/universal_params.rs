@@ -0,0 +1,56 @@
+use crate::header::split_header;
+use phase1::{Phase1, Phase1Parameters};
+use setup_utils::{CheckForCorrectness, UseCompression};
+
+use snarkvm_curves::PairingEngine as Engine;
+
+use fs_err::{File, OpenOptions};
+use memmap::*;
+use std::io::{BufWriter, Write};
+
+/// Reads the challenge file of a full Marlin ceremony and writes the universal
+/// parameters of the KZG10 and SonicKZG10 polynomial commitments, as serialized by snarkVM.
+pub fn export_universal_params<T: Engine + Sync>(
+    challenge_is_compressed: UseCompression,
+    challenge_filename: &str,
+    output_filename: &str,
+    parameters: &Phase1Parameters<T>,
+) {
+    println!(
+        "Will export the universal parameters for 2^{} powers of tau to {}",
+        parameters.total_size_in_log2, output_filename
+    );
+
+    let challenge_reader = OpenOptions::new()
+        .read(true)
+        .open(challenge_filename)
+        .expect("unable open challenge file in this directory");
+    let challenge_map = unsafe {
+        MmapOptions::new()
+            .map(challenge_reader.file())
+            .expect("unable to create a memory map for input")
+    };
+    let (_, challenge) = split_header(
+        &challenge_map,
+        "challenge",
+        parameters.get_length(challenge_is_compressed),
+        challenge_is_compressed,
+        parameters,
+    );
+
+    let accumulator = Phase1::deserialize(
+        challenge,
+        challenge_is_compressed,
+        CheckForCorrectness::Full,
+        parameters,
+    )
+    .expect("unable to read the accumulator from the challenge file");
+
+    let mut writer = BufWriter::new(File::create(output_filename).expect("unable to create universal parameters file"));
+    accumulator
+        .write_universal_params(&mut writer)
+        .expect("unable to write the universal parameters");
+    writer.flush().expect("unable to flush the universal parameters file");
+
+    println!("Wrote the universal parameters to {}", output_filename);
+}
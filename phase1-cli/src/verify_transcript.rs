@@ -0,0 +1,381 @@
+use phase1::{check_file_header, ContributionMode, Phase1, Phase1Parameters, PublicKey};
+use setup_utils::{blank_hash, calculate_hash, print_hash, CheckForCorrectness, UseCompression, VerificationStrategy};
+
+use snarkvm_curves::PairingEngine as Engine;
+
+use fs_err::OpenOptions;
+use memmap::*;
+use std::{
+    ops::{Deref, DerefMut},
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Replays a ceremony from its genesis challenge and verifies every contribution.
+///
+/// The transcript directory must contain the files `challenge_0`, `response_0`, `challenge_1`,
+/// `response_1`, ..., where `challenge_0` is the initial accumulator and `challenge_{i+1}` is the
+/// challenge produced from `response_i`. The replay stops at the first missing response.
+pub fn verify_transcript<T: Engine + Sync>(
+    challenge_is_compressed: UseCompression,
+    contribution_is_compressed: UseCompression,
+    transcript_directory: &str,
+    strategy: VerificationStrategy,
+    parameters: &Phase1Parameters<T>,
+) {
+    println!(
+        "Will verify the transcript in {} for 2^{} powers of tau",
+        transcript_directory, parameters.total_size_in_log2
+    );
+
+    if parameters.contribution_mode != ContributionMode::Full {
+        panic!("The transcript can only be verified for full contributions");
+    }
+    if challenge_is_compressed == UseCompression::Yes && contribution_is_compressed == UseCompression::No {
+        panic!("Compressed challenges can not be produced from uncompressed responses");
+    }
+
+    let (contributions, failures) = replay_transcript(
+        challenge_is_compressed,
+        contribution_is_compressed,
+        Path::new(transcript_directory),
+        strategy,
+        parameters,
+    );
+
+    println!("Verified {} contributions", contributions);
+    if failures.is_empty() {
+        println!("Transcript verification succeeded!");
+    } else {
+        println!("Transcript verification failed:");
+        for failure in &failures {
+            println!("  - {}", failure);
+        }
+        panic!("INVALID TRANSCRIPT!!!");
+    }
+}
+
+/// Replays the transcript in the given directory, and returns the number of contributions and the failures.
+///
+/// The files of the transcript are memory-mapped, and the replayed challenges are computed into
+/// memory-mapped temporary files, so that no challenge or response is held in memory.
+fn replay_transcript<T: Engine + Sync>(
+    challenge_is_compressed: UseCompression,
+    contribution_is_compressed: UseCompression,
+    directory: &Path,
+    strategy: VerificationStrategy,
+    parameters: &Phase1Parameters<T>,
+) -> (usize, Vec<String>) {
+    let challenge_length = match challenge_is_compressed {
+        UseCompression::Yes => parameters.contribution_size - parameters.public_key_size,
+        UseCompression::No => parameters.accumulator_size,
+    };
+    let response_length = match contribution_is_compressed {
+        UseCompression::Yes => parameters.contribution_size,
+        UseCompression::No => parameters.accumulator_size + parameters.public_key_size,
+    };
+
+    let mut failures = vec![];
+
+    // The first challenge must be the initial accumulator, which starts the hash chain.
+    let mut challenge = match read_file(
+        &directory.join("challenge_0"),
+        challenge_length,
+        challenge_is_compressed,
+        parameters,
+    ) {
+        Ok(challenge) => Challenge::File(challenge),
+        Err(e) => panic!("Unable to read the initial challenge: {}", e),
+    };
+    {
+        let mut genesis = TemporaryFile::new(challenge_length).expect("unable to create a temporary file");
+        genesis[..parameters.hash_size].copy_from_slice(blank_hash().as_slice());
+        Phase1::initialization(&mut genesis, challenge_is_compressed, parameters)
+            .expect("generation of initial accumulator is successful");
+        if challenge[..] != genesis[..] {
+            failures.push("challenge_0 is not the initial accumulator".to_string());
+        }
+    }
+
+    let mut contributions = 0;
+    while directory.join(format!("response_{}", contributions)).exists() {
+        let index = contributions;
+        let response = match read_file(
+            &directory.join(format!("response_{}", index)),
+            response_length,
+            contribution_is_compressed,
+            parameters,
+        ) {
+            Ok(response) => response,
+            Err(e) => {
+                failures.push(format!("response_{}: {}", index, e));
+                break;
+            }
+        };
+        contributions += 1;
+
+        let challenge_hash = calculate_hash(&challenge);
+        println!("Verifying contribution {} to the challenge with hash:", index);
+        print_hash(&challenge_hash);
+
+        // Check the hash chain - a response must be based on the previous challenge!
+        if response[..parameters.hash_size] != challenge_hash[..] {
+            failures.push(format!("response_{} is not based on challenge_{}", index, index));
+        }
+
+        match PublicKey::read(&response, contribution_is_compressed, parameters) {
+            Ok(public_key) => {
+                if let Err(e) = Phase1::verification(
+                    &challenge,
+                    &response,
+                    &public_key,
+                    challenge_hash.as_slice(),
+                    challenge_is_compressed,
+                    contribution_is_compressed,
+                    CheckForCorrectness::No,
+                    CheckForCorrectness::Full,
+                    parameters,
+                ) {
+                    failures.push(format!("response_{} is not a valid contribution: {}", index, e));
+                }
+            }
+            Err(e) => failures.push(format!("response_{} has an invalid public key: {}", index, e)),
+        }
+
+        // Replay the transformation of the response into the next challenge.
+        let mut next_challenge = TemporaryFile::new(challenge_length).expect("unable to create a temporary file");
+        next_challenge[..parameters.hash_size].copy_from_slice(calculate_hash(&response).as_slice());
+        if challenge_is_compressed == contribution_is_compressed {
+            next_challenge[parameters.hash_size..].copy_from_slice(&response[parameters.hash_size..challenge_length]);
+        } else if let Err(e) = Phase1::decompress(&response, &mut next_challenge, CheckForCorrectness::No, parameters) {
+            failures.push(format!("response_{} can not be decompressed: {}", index, e));
+            break;
+        }
+
+        // The next challenge is optional after the last response.
+        let next_challenge_path = directory.join(format!("challenge_{}", index + 1));
+        if next_challenge_path.exists() {
+            match read_file(
+                &next_challenge_path,
+                challenge_length,
+                challenge_is_compressed,
+                parameters,
+            ) {
+                Ok(file) if file[..] == next_challenge[..] => {}
+                Ok(_) => failures.push(format!(
+                    "challenge_{} was not produced from response_{}",
+                    index + 1,
+                    index
+                )),
+                Err(e) => failures.push(format!("challenge_{}: {}", index + 1, e)),
+            }
+        }
+        challenge = Challenge::Replayed(next_challenge);
+    }
+
+    if contributions == 0 {
+        failures.push("the transcript does not contain any response".to_string());
+    } else {
+        println!("Verifying the ratios of the final accumulator...");
+        if let Err(e) = Phase1::aggregate_verification(
+            (&challenge, challenge_is_compressed, CheckForCorrectness::No),
+            strategy,
+            parameters,
+        ) {
+            failures.push(format!("the final accumulator is invalid: {}", e));
+        }
+    }
+
+    (contributions, failures)
+}
+
+/// The challenge which the next response of a transcript is verified against.
+enum Challenge {
+    /// The initial challenge, as read from the transcript
+    File(TranscriptFile),
+    /// A challenge replayed from the previous response
+    Replayed(TemporaryFile),
+}
+
+impl Deref for Challenge {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            Challenge::File(file) => &file[..],
+            Challenge::Replayed(file) => &file[..],
+        }
+    }
+}
+
+/// A memory-mapped temporary file, which is removed when it is dropped.
+struct TemporaryFile {
+    map: Option<MmapMut>,
+    path: PathBuf,
+}
+
+impl TemporaryFile {
+    fn new(length: usize) -> Result<Self, String> {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let path = std::env::temp_dir().join(format!(
+            "phase1_transcript_{}_{}",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::SeqCst)
+        ));
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create_new(true)
+            .open(&path)
+            .map_err(|e| e.to_string())?;
+        // The file is removed if it can not be mapped.
+        let mut temporary_file = Self { map: None, path };
+        file.set_len(length as u64).map_err(|e| e.to_string())?;
+        temporary_file.map = Some(unsafe { MmapOptions::new().map_mut(file.file()).map_err(|e| e.to_string())? });
+        Ok(temporary_file)
+    }
+}
+
+impl Deref for TemporaryFile {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.map.as_ref().expect("the file is mapped until it is dropped")
+    }
+}
+
+impl DerefMut for TemporaryFile {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        self.map.as_mut().expect("the file is mapped until it is dropped")
+    }
+}
+
+impl Drop for TemporaryFile {
+    fn drop(&mut self) {
+        // The file is unmapped first, as mapped files can not be removed on every platform.
+        self.map.take();
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// A memory map of a transcript file, which dereferences to the file without its header.
+pub(crate) struct TranscriptFile {
+    map: Mmap,
+    header_size: usize,
+}
+
+impl Deref for TranscriptFile {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.map[self.header_size..]
+    }
+}
+
+/// Maps a transcript file, and checks its header and that it has the expected length.
+pub(crate) fn read_file<T: Engine>(
+    path: &Path,
+    expected_length: usize,
+    compression: UseCompression,
+    parameters: &Phase1Parameters<T>,
+) -> Result<TranscriptFile, String> {
+    let reader = OpenOptions::new().read(true).open(path).map_err(|e| e.to_string())?;
+    let map = unsafe { MmapOptions::new().map(reader.file()).map_err(|e| e.to_string())? };
+    let (_, body) = check_file_header(&map, compression, parameters).map_err(|e| e.to_string())?;
+    if body.len() != expected_length {
+        return Err(format!(
+            "the size of the file should be {}, but it's {}",
+            expected_length,
+            body.len()
+        ));
+    }
+
+    let header_size = map.len() - body.len();
+    Ok(TranscriptFile { map, header_size })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use phase1::ProvingSystem;
+
+    use snarkvm_curves::bls12_377::Bls12_377;
+
+    use rand::thread_rng;
+    use std::fs;
+
+    /// Writes a transcript of the given number of contributions to the given directory.
+    fn write_transcript(directory: &Path, contributions: usize, parameters: &Phase1Parameters<Bls12_377>) {
+        let mut challenge = vec![0; parameters.accumulator_size];
+        challenge[..parameters.hash_size].copy_from_slice(blank_hash().as_slice());
+        Phase1::initialization(&mut challenge, UseCompression::No, parameters).unwrap();
+
+        for index in 0..contributions {
+            fs::write(directory.join(format!("challenge_{}", index)), &challenge).unwrap();
+
+            let challenge_hash = calculate_hash(&challenge);
+            let (public_key, private_key) = Phase1::key_generation(&mut thread_rng(), challenge_hash.as_ref()).unwrap();
+            let mut response = vec![0; parameters.contribution_size];
+            response[..parameters.hash_size].copy_from_slice(challenge_hash.as_slice());
+            Phase1::computation(
+                &challenge,
+                &mut response,
+                UseCompression::No,
+                UseCompression::Yes,
+                CheckForCorrectness::No,
+                &private_key,
+                parameters,
+            )
+            .unwrap();
+            public_key
+                .write(&mut response, UseCompression::Yes, parameters)
+                .unwrap();
+            fs::write(directory.join(format!("response_{}", index)), &response).unwrap();
+
+            challenge = vec![0; parameters.accumulator_size];
+            challenge[..parameters.hash_size].copy_from_slice(calculate_hash(&response).as_slice());
+            Phase1::decompress(&response, &mut challenge, CheckForCorrectness::No, parameters).unwrap();
+        }
+        fs::write(directory.join(format!("challenge_{}", contributions)), &challenge).unwrap();
+    }
+
+    #[test]
+    fn test_verify_transcript() {
+        let parameters = Phase1Parameters::<Bls12_377>::new_full(ProvingSystem::Groth16, 3, 4);
+        let directory = std::env::temp_dir().join(format!("phase1_verify_transcript_test_{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        write_transcript(&directory, 2, &parameters);
+
+        let replay = || {
+            replay_transcript(
+                UseCompression::No,
+                UseCompression::Yes,
+                &directory,
+                VerificationStrategy::PerBatch,
+                &parameters,
+            )
+        };
+        assert_eq!(replay(), (2, vec![]));
+
+        // A tampered response is rejected, even though the following challenge is replayed from it.
+        let response_path = directory.join("response_1");
+        let response = fs::read(&response_path).unwrap();
+        let mut tampered = response.clone();
+        tampered[parameters.hash_size] ^= 1;
+        fs::write(&response_path, &tampered).unwrap();
+        let (contributions, failures) = replay();
+        assert_eq!(contributions, 2);
+        assert!(failures.iter().any(|failure| failure.starts_with("response_1")));
+        fs::write(&response_path, &response).unwrap();
+
+        // A challenge which was not produced from the previous response is rejected.
+        let challenge_path = directory.join("challenge_1");
+        let mut challenge = fs::read(&challenge_path).unwrap();
+        challenge[parameters.hash_size] ^= 1;
+        fs::write(&challenge_path, &challenge).unwrap();
+        let (_, failures) = replay();
+        assert!(failures.contains(&"challenge_1 was not produced from response_0".to_string()));
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
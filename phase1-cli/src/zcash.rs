@@ -0,0 +1,92 @@
+use phase1::{Phase1, Phase1Parameters};
+use setup_utils::{calculate_hash, print_hash, CheckForCorrectness, Result};
+
+use snarkvm_curves::PairingEngine as Engine;
+
+use fs_err::OpenOptions;
+use memmap::*;
+
+/// Reads a powersoftau challenge file and writes it in the format of this crate.
+pub fn import_zcash<T: Engine + Sync>(zcash_filename: &str, output_filename: &str, parameters: &Phase1Parameters<T>) {
+    println!(
+        "Will import a powersoftau challenge for 2^{} powers of tau from {}",
+        parameters.total_size_in_log2, zcash_filename
+    );
+
+    convert(
+        zcash_filename,
+        output_filename,
+        parameters.accumulator_size,
+        |input, output| Phase1::from_zcash(input, output, CheckForCorrectness::Full, parameters),
+    );
+}
+
+/// Reads a challenge file and writes it in the powersoftau format.
+pub fn export_zcash<T: Engine + Sync>(input_filename: &str, zcash_filename: &str, parameters: &Phase1Parameters<T>) {
+    println!(
+        "Will export a challenge for 2^{} powers of tau to {}",
+        parameters.total_size_in_log2, zcash_filename
+    );
+
+    convert(
+        input_filename,
+        zcash_filename,
+        parameters.accumulator_size,
+        |input, output| Phase1::to_zcash(input, output, CheckForCorrectness::Full, parameters),
+    );
+}
+
+/// Converts a challenge file. Challenges have the same length in both formats.
+fn convert(
+    input_filename: &str,
+    output_filename: &str,
+    length: usize,
+    conversion: impl Fn(&[u8], &mut [u8]) -> Result<()>,
+) {
+    let reader = OpenOptions::new()
+        .read(true)
+        .open(input_filename)
+        .expect("unable open input file in this directory");
+    {
+        let metadata = reader
+            .metadata()
+            .expect("unable to get filesystem metadata for input file");
+        if metadata.len() != (length as u64) {
+            panic!(
+                "The size of input file should be {}, but it's {}, so something isn't right.",
+                length,
+                metadata.len()
+            );
+        }
+    }
+
+    let input_map = unsafe {
+        MmapOptions::new()
+            .map(reader.file())
+            .expect("unable to create a memory map for input")
+    };
+    println!("Input file hash:");
+    print_hash(&calculate_hash(&input_map));
+
+    let file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create_new(true)
+        .open(output_filename)
+        .expect("unable to create output file");
+    file.set_len(length as u64)
+        .expect("unable to allocate large enough file");
+
+    let mut writable_map = unsafe {
+        MmapOptions::new()
+            .map_mut(file.file())
+            .expect("unable to create a memory map")
+    };
+
+    conversion(&input_map, &mut writable_map).expect("unable to convert the input file");
+    writable_map.flush().expect("unable to flush memmap to disk");
+
+    let output_readonly = writable_map.make_read_only().expect("must make a map readonly");
+    println!("Wrote the converted file with a hash:");
+    print_hash(&calculate_hash(&output_readonly));
+}
@@ -0,0 +1,74 @@
+use crate::authentication::Signature;
+
+use rand::thread_rng;
+use snarkvm_dpc::{parameters::testnet2::Testnet2Parameters, Address, ViewKey};
+use snarkvm_utilities::{FromBytes, ToBytes};
+use std::str::FromStr;
+
+/// The Aleo account signature scheme, used by contributors and verifiers
+/// to sign their contributions and requests with their view key.
+pub struct Aleo;
+
+impl Signature for Aleo {
+    /// Returns the name of the signature scheme.
+    fn name(&self) -> String {
+        "AleoSignatureScheme".to_string()
+    }
+
+    /// Returns `true` if the signature scheme is safe for use in production.
+    fn is_secure(&self) -> bool {
+        true
+    }
+
+    /// Signs the given message using the given view key,
+    /// and returns the signature as a hex string.
+    fn sign(&self, signing_key: &str, message: &str) -> anyhow::Result<String> {
+        let view_key = ViewKey::<Testnet2Parameters>::from_str(signing_key)?;
+        let signature = view_key.sign(message.as_bytes(), &mut thread_rng())?;
+        Ok(hex::encode(signature.to_bytes_le()?))
+    }
+
+    /// Verifies the given hex signature for the given message and Aleo address,
+    /// and returns `true` if the signature is valid.
+    fn verify(&self, public_key: &str, message: &str, signature: &str) -> bool {
+        let address = match Address::<Testnet2Parameters>::from_str(public_key) {
+            Ok(address) => address,
+            Err(_) => return false,
+        };
+
+        let signature = match hex::decode(signature) {
+            Ok(signature) => signature,
+            Err(_) => return false,
+        };
+
+        match FromBytes::from_bytes_le(&signature) {
+            Ok(signature) => address
+                .verify_signature(message.as_bytes(), &signature)
+                .unwrap_or(false),
+            Err(_) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Example view key and its corresponding address.
+    const TEST_VIEW_KEY: &str = "AViewKey1cWY7CaSDuwAEXoFki7Z1JELj7ksum8JxfZGpsPLHJACx";
+    const TEST_ADDRESS: &str = "aleo1en3lu60j0gcetvnpscvzwcxgujj069tlr3qlrm7y5kcrncxu3y8qva8p7k";
+
+    #[test]
+    fn test_aleo_signature() {
+        let message = "post /v1/contributor/try_lock";
+
+        let aleo = Aleo;
+
+        let signature = aleo.sign(TEST_VIEW_KEY, message).unwrap();
+        assert_eq!(64, hex::decode(&signature).unwrap().len());
+
+        assert!(aleo.verify(TEST_ADDRESS, message, &signature));
+        assert!(!aleo.verify(TEST_ADDRESS, "get /v1/contributor/try_lock", &signature));
+        assert!(!aleo.verify("aleo1invalid", message, &signature));
+    }
+}
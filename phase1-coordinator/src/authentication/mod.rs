@@ -1,3 +1,8 @@
+#[cfg(feature = "server")]
+pub mod aleo;
+#[cfg(feature = "server")]
+pub use aleo::*;
+
 pub mod dummy;
 pub use dummy::*;
 
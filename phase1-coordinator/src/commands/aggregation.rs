@@ -1,10 +1,11 @@
 use crate::{
     environment::Environment,
     objects::Round,
-    storage::{ContributionLocator, Disk, DiskObjectReader, Locator, Object, StorageLocator, StorageObject},
+    storage::{ContributionLocator, Locator, Object, ObjectWriter, Storage, StorageLocator, StorageReader},
     CoordinatorError,
 };
 use phase1::{helpers::CurveKind, Phase1};
+use setup_utils::curves::Bls12_381;
 use snarkvm_curves::{bls12_377::Bls12_377, bw6_761::BW6_761};
 
 use std::time::Instant;
@@ -15,7 +16,7 @@ pub(crate) struct Aggregation;
 impl Aggregation {
     /// Runs aggregation for a given environment, storage, and round.
     #[inline]
-    pub(crate) fn run(environment: &Environment, storage: &mut Disk, round: &Round) -> anyhow::Result<()> {
+    pub(crate) fn run(environment: &Environment, storage: &mut dyn Storage, round: &Round) -> anyhow::Result<()> {
         let start = Instant::now();
 
         // Fetch the round height.
@@ -31,7 +32,7 @@ impl Aggregation {
         let round_locator = Locator::RoundFile { round_height };
 
         // Check that the round locator does not already exist.
-        if storage.exists(&round_locator) {
+        if storage.exists(&round_locator)? {
             return Err(CoordinatorError::RoundLocatorAlreadyExists.into());
         }
 
@@ -46,15 +47,21 @@ impl Aggregation {
         let chunk_id = 0usize;
         let settings = environment.parameters();
         let curve = settings.curve();
+        let mut writer = storage.writer(&round_locator)?;
         let result = match curve {
             CurveKind::Bls12_377 => Phase1::aggregation(
                 &contribution_readers,
-                (storage.writer(&round_locator)?.as_mut(), compressed_input),
+                (writer.as_mut(), compressed_input),
                 &phase1_chunked_parameters!(Bls12_377, settings, chunk_id),
             ),
+            CurveKind::Bls12_381 => Phase1::aggregation(
+                &contribution_readers,
+                (writer.as_mut(), compressed_input),
+                &phase1_chunked_parameters!(Bls12_381, settings, chunk_id),
+            ),
             CurveKind::BW6 => Phase1::aggregation(
                 &contribution_readers,
-                (storage.writer(&round_locator)?.as_mut(), compressed_input),
+                (writer.as_mut(), compressed_input),
                 &phase1_chunked_parameters!(BW6_761, settings, chunk_id),
             ),
         };
@@ -62,6 +69,7 @@ impl Aggregation {
             error!("Aggregation failed with {}", error);
             return Err(CoordinatorError::RoundAggregationFailed.into());
         }
+        writer.flush()?;
 
         // Run aggregate verification on the given round.
         let settings = environment.parameters();
@@ -73,14 +81,25 @@ impl Aggregation {
                     setup_utils::UseCompression::No,
                     setup_utils::CheckForCorrectness::Full,
                 ),
+                environment.verification_strategy(),
                 &phase1_full_parameters!(Bls12_377, settings),
             )?,
+            CurveKind::Bls12_381 => Phase1::aggregate_verification(
+                (
+                    &storage.reader(&round_locator)?.as_ref(),
+                    setup_utils::UseCompression::No,
+                    setup_utils::CheckForCorrectness::Full,
+                ),
+                environment.verification_strategy(),
+                &phase1_full_parameters!(Bls12_381, settings),
+            )?,
             CurveKind::BW6 => Phase1::aggregate_verification(
                 (
                     &storage.reader(&round_locator)?.as_ref(),
                     setup_utils::UseCompression::No,
                     setup_utils::CheckForCorrectness::Full,
                 ),
+                environment.verification_strategy(),
                 &phase1_full_parameters!(BW6_761, settings),
             )?,
         };
@@ -95,9 +114,9 @@ impl Aggregation {
     #[inline]
     fn readers<'a>(
         environment: &Environment,
-        storage: &'a Disk,
+        storage: &'a dyn Storage,
         round: &Round,
-    ) -> anyhow::Result<Vec<DiskObjectReader>> {
+    ) -> anyhow::Result<Vec<StorageReader>> {
         let mut readers = vec![];
 
         // Fetch the round height.
@@ -126,7 +145,7 @@ impl Aggregation {
             // Check the corresponding verified contribution locator exists.
             let verified_contribution =
                 Locator::ContributionFile(ContributionLocator::new(round_height + 1, chunk_id, 0, true));
-            if !storage.exists(&verified_contribution) {
+            if !storage.exists(&verified_contribution)? {
                 error!("{} is missing", storage.to_path(&verified_contribution)?);
                 return Err(CoordinatorError::ContributionMissingVerifiedLocator.into());
             }
@@ -277,7 +296,7 @@ mod tests {
             // Fetch the round locator for the given round.
             let round_locator = Locator::RoundFile { round_height };
 
-            assert!(storage.exists(&round_locator));
+            assert!(storage.exists(&round_locator).unwrap());
         }
     }
 }
@@ -2,11 +2,11 @@ use crate::{
     authentication::Signature,
     commands::SigningKey,
     environment::Environment,
-    storage::{Disk, Locator, StorageLocator, StorageObject},
+    storage::{Locator, ObjectWriter, Storage, StorageLocator},
     CoordinatorError,
 };
 use phase1::{helpers::CurveKind, Phase1, Phase1Parameters};
-use setup_utils::{calculate_hash, derive_rng_from_seed, UseCompression};
+use setup_utils::{calculate_hash, curves::Bls12_381, derive_rng_from_seed, Progress, UseCompression};
 
 use snarkvm_curves::{bls12_377::Bls12_377, bw6_761::BW6_761, PairingEngine as Engine};
 
@@ -28,15 +28,19 @@ impl Computation {
     /// and response file have been initialized, typically as part of a call to
     /// `Coordinator::try_lock` to lock the contribution chunk.
     ///
+    /// The computation reports its progress to `progress`, and stops
+    /// with an error if `progress` cancels it.
+    ///
     pub(crate) fn run(
         environment: &Environment,
-        storage: &mut Disk,
+        storage: &mut dyn Storage,
         signature: Arc<dyn Signature>,
         contributor_signing_key: &SigningKey,
         challenge_locator: &Locator,
         response_locator: &Locator,
         contribution_file_signature_locator: &Locator,
         seed: &Seed,
+        progress: &dyn Progress,
     ) -> anyhow::Result<()> {
         let start = Instant::now();
         info!(
@@ -58,25 +62,37 @@ impl Computation {
         // Run computation on chunk.
         let settings = environment.parameters();
         let curve = settings.curve();
+        let mut writer = storage.writer(response_locator)?;
         if let Err(error) = match curve {
             CurveKind::Bls12_377 => Self::contribute(
                 environment,
                 storage.reader(challenge_locator)?.as_ref(),
-                storage.writer(response_locator)?.as_mut(),
+                writer.as_mut(),
                 &phase1_chunked_parameters!(Bls12_377, settings, chunk_id),
                 derive_rng_from_seed(&seed[..]),
+                progress,
+            ),
+            CurveKind::Bls12_381 => Self::contribute(
+                environment,
+                storage.reader(challenge_locator)?.as_ref(),
+                writer.as_mut(),
+                &phase1_chunked_parameters!(Bls12_381, settings, chunk_id),
+                derive_rng_from_seed(&seed[..]),
+                progress,
             ),
             CurveKind::BW6 => Self::contribute(
                 environment,
                 storage.reader(challenge_locator)?.as_ref(),
-                storage.writer(response_locator)?.as_mut(),
+                writer.as_mut(),
                 &phase1_chunked_parameters!(BW6_761, settings, chunk_id),
                 derive_rng_from_seed(&seed[..]),
+                progress,
             ),
         } {
             error!("Computation failed with {}", error);
             return Err(CoordinatorError::ComputationFailed.into());
         }
+        writer.flush()?;
 
         // Load a contribution response reader.
         let reader = storage.reader(response_locator)?;
@@ -121,6 +137,7 @@ impl Computation {
         mut response_writer: &mut [u8],
         parameters: &Phase1Parameters<T>,
         mut rng: impl Rng + CryptoRng,
+        progress: &dyn Progress,
     ) -> Result<(), CoordinatorError> {
         // Fetch the environment settings.
         let compressed_inputs = environment.compressed_inputs();
@@ -151,7 +168,7 @@ impl Computation {
 
         // Perform the transformation
         trace!("Computing and writing your contribution, this could take a while");
-        Phase1::computation(
+        Phase1::computation_with_progress(
             challenge_reader,
             response_writer,
             compressed_inputs,
@@ -159,6 +176,7 @@ impl Computation {
             check_input_for_correctness,
             &private_key,
             &parameters,
+            progress,
         )?;
         response_writer.flush()?;
         trace!("Finishing writing your contribution to response file");
@@ -175,10 +193,10 @@ mod tests {
     use crate::{
         authentication::{Dummy, Signature},
         commands::{Computation, Initialization, Seed, SEED_LENGTH},
-        storage::{ContributionLocator, ContributionSignatureLocator, Locator, Object, StorageObject},
+        storage::{ContributionLocator, ContributionSignatureLocator, Locator, Object, Storage},
         testing::prelude::*,
     };
-    use setup_utils::calculate_hash;
+    use setup_utils::{calculate_hash, NoProgress};
 
     use rand::RngCore;
     use std::sync::Arc;
@@ -223,11 +241,11 @@ mod tests {
                 ContributionSignatureLocator::new(round_height, chunk_id, 1, false),
             );
 
-            if !storage.exists(response_locator) {
+            if !storage.exists(response_locator).unwrap() {
                 let expected_filesize = Object::contribution_file_size(&TEST_ENVIRONMENT_3, chunk_id, false);
                 storage.initialize(response_locator.clone(), expected_filesize).unwrap();
             }
-            if !storage.exists(contribution_file_signature_locator) {
+            if !storage.exists(contribution_file_signature_locator).unwrap() {
                 let expected_filesize = Object::contribution_file_signature_size(false);
                 storage
                     .initialize(contribution_file_signature_locator.clone(), expected_filesize)
@@ -247,6 +265,7 @@ mod tests {
                 response_locator,
                 contribution_file_signature_locator,
                 &seed,
+                &NoProgress,
             )
             .unwrap();
 
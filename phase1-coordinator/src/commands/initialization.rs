@@ -1,10 +1,10 @@
 use crate::{
     environment::Environment,
-    storage::{ContributionLocator, Disk, Locator, Object, StorageObject},
+    storage::{ContributionLocator, Locator, Object, ObjectWriter, Storage},
     CoordinatorError,
 };
 use phase1::{helpers::CurveKind, Phase1, Phase1Parameters};
-use setup_utils::{blank_hash, calculate_hash, UseCompression};
+use setup_utils::{blank_hash, calculate_hash, curves::Bls12_381, UseCompression};
 use snarkvm_curves::{bls12_377::Bls12_377, bw6_761::BW6_761};
 
 use snarkvm_curves::PairingEngine as Engine;
@@ -23,7 +23,7 @@ impl Initialization {
     #[inline]
     pub(crate) fn run(
         environment: &Environment,
-        storage: &mut Disk,
+        storage: &mut dyn Storage,
         round_height: u64,
         chunk_id: u64,
     ) -> anyhow::Result<Vec<u8>> {
@@ -40,15 +40,21 @@ impl Initialization {
 
         // Run ceremony initialization on chunk.
         let settings = environment.parameters();
+        let mut writer = storage.writer(&contribution_locator)?;
 
         if let Err(error) = match settings.curve() {
             CurveKind::Bls12_377 => Self::initialization(
-                storage.writer(&contribution_locator)?.as_mut(),
+                writer.as_mut(),
                 environment.compressed_inputs(),
                 &phase1_chunked_parameters!(Bls12_377, settings, chunk_id),
             ),
+            CurveKind::Bls12_381 => Self::initialization(
+                writer.as_mut(),
+                environment.compressed_inputs(),
+                &phase1_chunked_parameters!(Bls12_381, settings, chunk_id),
+            ),
             CurveKind::BW6 => Self::initialization(
-                storage.writer(&contribution_locator)?.as_mut(),
+                writer.as_mut(),
                 environment.compressed_inputs(),
                 &phase1_chunked_parameters!(BW6_761, settings, chunk_id),
             ),
@@ -56,6 +62,7 @@ impl Initialization {
             error!("Initialization failed with {}", error);
             return Err(CoordinatorError::InitializationFailed.into());
         }
+        writer.flush()?;
 
         // Copy the current transcript to the next transcript.
         // This operation will *overwrite* the contents of `next_transcript`.
@@ -100,7 +107,7 @@ impl Initialization {
     /// Compute both contribution hashes and check for equivalence.
     #[inline]
     fn check_hash(
-        storage: &Disk,
+        storage: &dyn Storage,
         contribution_locator: &Locator,
         next_contribution_locator: &Locator,
     ) -> anyhow::Result<Vec<u8>> {
@@ -122,7 +129,7 @@ impl Initialization {
 mod tests {
     use crate::{
         commands::Initialization,
-        storage::{ContributionLocator, Locator, StorageObject},
+        storage::{ContributionLocator, Locator, Storage},
         testing::prelude::*,
     };
     use setup_utils::{blank_hash, calculate_hash, GenericArray};
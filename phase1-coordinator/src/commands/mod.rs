@@ -18,19 +18,58 @@ pub(crate) use verification::*;
 use crate::{
     authentication::Signature,
     objects::{ContributionFileSignature, ContributionState},
-    storage::{Disk, Locator, StorageLocator, StorageObject},
+    storage::{Locator, ObjectWriter, Storage, StorageLocator},
     CoordinatorError,
+    TimeSource,
 };
 
 #[cfg(any(test, feature = "operator"))]
-use setup_utils::calculate_hash;
+use setup_utils::{calculate_hash, Progress};
 
 #[cfg(any(test, feature = "operator"))]
 use std::{io::Write, sync::Arc};
+#[cfg(any(test, feature = "operator"))]
+use time::OffsetDateTime;
 
 #[cfg(any(test, feature = "operator"))]
 pub type SigningKey = String;
 
+///
+/// Cancels a computation or a verification once the lock of the participant
+/// on the chunk has been held for longer than the participant lock timeout,
+/// as the participant is then dropped and its contribution is discarded.
+///
+#[cfg(any(test, feature = "operator"))]
+pub(crate) struct LockDeadline {
+    time: Arc<dyn TimeSource>,
+    deadline: Option<OffsetDateTime>,
+}
+
+#[cfg(any(test, feature = "operator"))]
+impl LockDeadline {
+    ///
+    /// Creates a new instance of `LockDeadline`, which never cancels
+    /// the operation if the given `deadline` is `None`.
+    ///
+    pub(crate) fn new(time: Arc<dyn TimeSource>, deadline: Option<OffsetDateTime>) -> Self {
+        Self { time, deadline }
+    }
+}
+
+#[cfg(any(test, feature = "operator"))]
+impl Progress for LockDeadline {
+    fn on_progress(&self, processed: usize, total: usize) {
+        tracing::trace!("Processed {} out of {} batches", processed, total);
+    }
+
+    fn is_cancelled(&self) -> bool {
+        match self.deadline {
+            Some(deadline) => self.time.now_utc() > deadline,
+            None => false,
+        }
+    }
+}
+
 ///
 /// Writes the contribution file signature to a given `contribution_file_signature` locator.
 ///
@@ -47,7 +86,7 @@ pub type SigningKey = String;
 #[cfg(any(test, feature = "operator"))]
 #[inline]
 pub(crate) fn write_contribution_file_signature(
-    storage: &mut Disk,
+    storage: &mut dyn Storage,
     signature: Arc<dyn Signature>,
     signing_key: &SigningKey,
     challenge_locator: &Locator,
@@ -5,16 +5,16 @@ use crate::{
     storage::{
         ContributionLocator,
         ContributionSignatureLocator,
-        Disk,
         Locator,
         Object,
+        ObjectWriter,
+        Storage,
         StorageLocator,
-        StorageObject,
     },
     CoordinatorError,
 };
-use phase1::{helpers::CurveKind, Phase1, Phase1Parameters, PublicKey};
-use setup_utils::{calculate_hash, CheckForCorrectness, GenericArray, U64};
+use phase1::{check_file_header, helpers::CurveKind, Phase1, Phase1Parameters, PublicKey};
+use setup_utils::{calculate_hash, curves::Bls12_381, CheckForCorrectness, GenericArray, Progress, U64};
 use snarkvm_curves::{bls12_377::Bls12_377, bw6_761::BW6_761, PairingEngine as Engine};
 
 use std::{io::Write, sync::Arc, time::Instant};
@@ -28,16 +28,20 @@ impl Verification {
     /// round height, chunk ID, and contribution ID of the
     /// unverified response file.
     ///
+    /// The verification reports its progress to `progress`, and stops
+    /// with an error if `progress` cancels it.
+    ///
     #[inline]
     pub(crate) fn run(
         environment: &Environment,
-        storage: &mut Disk,
+        storage: &mut dyn Storage,
         signature: Arc<dyn Signature>,
         signing_key: &SigningKey,
         round_height: u64,
         chunk_id: u64,
         current_contribution_id: u64,
         is_final_contribution: bool,
+        progress: &dyn Progress,
     ) -> Result<(), CoordinatorError> {
         info!(
             "Starting verification of round {} chunk {} contribution {}",
@@ -112,6 +116,7 @@ impl Verification {
             challenge_locator.clone(),
             response_locator.clone(),
             next_challenge_locator.clone(),
+            progress,
         ) {
             error!("Verification failed with {}", error);
             return Err(error);
@@ -123,7 +128,7 @@ impl Verification {
         );
 
         // Initialize the contribution file signature locator, if it does not exist.
-        if !storage.exists(&contribution_file_signature_locator) {
+        if !storage.exists(&contribution_file_signature_locator)? {
             let expected_filesize = Object::contribution_file_signature_size(true);
             storage.initialize(contribution_file_signature_locator.clone(), expected_filesize)?;
         }
@@ -156,14 +161,15 @@ impl Verification {
     #[inline]
     fn verification(
         environment: &Environment,
-        storage: &mut Disk,
+        storage: &mut dyn Storage,
         chunk_id: u64,
         challenge_locator: Locator,
         response_locator: Locator,
         next_challenge_locator: Locator,
+        progress: &dyn Progress,
     ) -> Result<(), CoordinatorError> {
         // Check that the previous and current locators exist in storage.
-        if !storage.exists(&challenge_locator) || !storage.exists(&response_locator) {
+        if !storage.exists(&challenge_locator)? || !storage.exists(&response_locator) {
             return Err(CoordinatorError::ContributionLocatorMissing);
         }
 
@@ -175,16 +181,29 @@ impl Verification {
                 storage.reader(&challenge_locator)?.as_ref(),
                 storage.reader(&response_locator)?.as_ref(),
                 &phase1_chunked_parameters!(Bls12_377, settings, chunk_id),
+                progress,
+            ),
+            CurveKind::Bls12_381 => Self::transform_pok_and_correctness(
+                environment,
+                storage.reader(&challenge_locator)?.as_ref(),
+                storage.reader(&response_locator)?.as_ref(),
+                &phase1_chunked_parameters!(Bls12_381, settings, chunk_id),
+                progress,
             ),
             CurveKind::BW6 => Self::transform_pok_and_correctness(
                 environment,
                 storage.reader(&challenge_locator)?.as_ref(),
                 storage.reader(&response_locator)?.as_ref(),
                 &phase1_chunked_parameters!(BW6_761, settings, chunk_id),
+                progress,
             ),
         };
         let response_hash = match result {
             Ok(response_hash) => response_hash,
+            Err(CoordinatorError::Phase1Setup(setup_utils::Error::VerificationError(error))) => {
+                error!("Verification of chunk {} failed with {}", chunk_id, error);
+                return Err(CoordinatorError::ContributionInvalid(error));
+            }
             Err(error) => {
                 error!("Verification failed with {}", error);
                 return Err(CoordinatorError::VerificationFailed.into());
@@ -208,27 +227,35 @@ impl Verification {
             trace!("Starting decompression of the response file for the next challenge file");
 
             // Initialize the next contribution locator, if it does not exist.
-            if !storage.exists(&next_challenge_locator) {
+            if !storage.exists(&next_challenge_locator)? {
                 storage.initialize(
                     next_challenge_locator.clone(),
                     Object::contribution_file_size(environment, chunk_id, true),
                 )?;
             }
 
+            let mut writer = storage.writer(&next_challenge_locator)?;
             match settings.curve() {
                 CurveKind::Bls12_377 => Self::decompress(
                     storage.reader(&response_locator)?.as_ref(),
-                    storage.writer(&next_challenge_locator)?.as_mut(),
+                    writer.as_mut(),
                     response_hash.as_ref(),
                     &phase1_chunked_parameters!(Bls12_377, settings, chunk_id),
                 )?,
+                CurveKind::Bls12_381 => Self::decompress(
+                    storage.reader(&response_locator)?.as_ref(),
+                    writer.as_mut(),
+                    response_hash.as_ref(),
+                    &phase1_chunked_parameters!(Bls12_381, settings, chunk_id),
+                )?,
                 CurveKind::BW6 => Self::decompress(
                     storage.reader(&response_locator)?.as_ref(),
-                    storage.writer(&next_challenge_locator)?.as_mut(),
+                    writer.as_mut(),
                     response_hash.as_ref(),
                     &phase1_chunked_parameters!(BW6_761, settings, chunk_id),
                 )?,
             };
+            writer.flush()?;
 
             calculate_hash(storage.reader(&next_challenge_locator)?.as_ref())
         };
@@ -263,9 +290,18 @@ impl Verification {
         challenge_reader: &[u8],
         response_reader: &[u8],
         parameters: &Phase1Parameters<T>,
+        progress: &dyn Progress,
     ) -> Result<GenericArray<u8, U64>, CoordinatorError> {
         debug!("Verifying 2^{} powers of tau", parameters.total_size_in_log2);
 
+        // Fetch the compression settings.
+        let compressed_challenge = environment.compressed_inputs();
+        let compressed_response = environment.compressed_outputs();
+
+        // Check and strip the optional headers of the files.
+        let (_, challenge_reader) = check_file_header(challenge_reader, compressed_challenge, parameters)?;
+        let (_, response_reader) = check_file_header(response_reader, compressed_response, parameters)?;
+
         // Check that the challenge hashes match.
         let challenge_hash = {
             // Compute the challenge hash using the challenge file.
@@ -291,16 +327,12 @@ impl Verification {
         // Compute the response hash using the response file.
         let response_hash = calculate_hash(response_reader);
 
-        // Fetch the compression settings.
-        let compressed_challenge = environment.compressed_inputs();
-        let compressed_response = environment.compressed_outputs();
-
         // Fetch the public key of the contributor.
         let public_key = PublicKey::read(response_reader, compressed_response, &parameters)?;
         // trace!("Public key of the contributor is {:#?}", public_key);
 
         trace!("Starting verification");
-        Phase1::verification(
+        Phase1::verification_with_progress(
             challenge_reader,
             response_reader,
             &public_key,
@@ -310,6 +342,7 @@ impl Verification {
             CheckForCorrectness::No,
             CheckForCorrectness::Full,
             &parameters,
+            progress,
         )?;
         trace!("Completed verification");
 
@@ -356,6 +389,7 @@ mod tests {
 
     use once_cell::sync::Lazy;
     use rand::RngCore;
+    use setup_utils::NoProgress;
     use time::OffsetDateTime;
 
     #[test]
@@ -407,11 +441,11 @@ mod tests {
             let signature = coordinator.signature();
             let storage = coordinator.storage_mut();
 
-            if !storage.exists(response_locator) {
+            if !storage.exists(response_locator).unwrap() {
                 let expected_filesize = Object::contribution_file_size(&TEST_ENVIRONMENT_3, chunk_id, false);
                 storage.initialize(response_locator.clone(), expected_filesize).unwrap();
             }
-            if !storage.exists(contribution_file_signature_locator) {
+            if !storage.exists(contribution_file_signature_locator).unwrap() {
                 let expected_filesize = Object::contribution_file_signature_size(false);
                 storage
                     .initialize(contribution_file_signature_locator.clone(), expected_filesize)
@@ -430,6 +464,7 @@ mod tests {
                 response_locator,
                 contribution_file_signature_locator,
                 &seed,
+                &NoProgress,
             )
             .unwrap();
 
@@ -443,6 +478,7 @@ mod tests {
                 chunk_id,
                 1,
                 is_final,
+                &NoProgress,
             )
             .unwrap();
 
@@ -453,7 +489,7 @@ mod tests {
             };
 
             // Check the next challenge file exists.
-            assert!(storage.exists(&next));
+            assert!(storage.exists(&next).unwrap());
         }
     }
 }
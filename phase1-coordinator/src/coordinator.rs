@@ -18,13 +18,13 @@ use crate::{
     storage::{
         ContributionLocator,
         ContributionSignatureLocator,
-        Disk,
         Locator,
         LocatorPath,
         Object,
+        Storage,
         StorageAction,
         StorageLocator,
-        StorageObject,
+        StorageReader,
         UpdateAction,
     },
 };
@@ -70,6 +70,7 @@ pub enum CoordinatorError {
     ContributionIdIsNonzero,
     ContributionIdMismatch,
     ContributionIdMustBeNonzero,
+    ContributionInvalid(setup_utils::VerificationError),
     ContributionLocatorAlreadyExists,
     ContributionLocatorIncorrect,
     ContributionLocatorMissing,
@@ -342,7 +343,7 @@ pub struct Coordinator {
     /// The signature scheme for contributors & verifiers with this coordinator.
     signature: Arc<dyn Signature>,
     /// The storage of contributions and rounds for this coordinator.
-    storage: Disk,
+    storage: Box<dyn Storage>,
     /// The current round and participant self.
     state: CoordinatorState,
     /// The source of time, allows mocking system time for testing.
@@ -806,7 +807,7 @@ impl Coordinator {
         // Fetch the current round from storage.
         match self.storage.exists(&Locator::RoundState {
             round_height: current_round_height,
-        }) {
+        })? {
             // Case 1 - This is a typical round of the ceremony.
             true => Ok(current_round_height),
             // Case 2 - Storage failed to locate the current round.
@@ -1185,7 +1186,7 @@ impl Coordinator {
                 };
 
                 // Remove the invalid next challenge file from storage.
-                if self.storage.exists(&next_challenge) {
+                if self.storage.exists(&next_challenge)? {
                     self.storage.remove(&next_challenge)?;
                 }
 
@@ -1365,7 +1366,7 @@ impl Coordinator {
         // Fetch the chunk ID corresponding to the given locator path.
         let locator = self.storage.to_locator(&locator_path)?;
         match &locator {
-            Locator::ContributionFile(contribution_locator) => match self.storage.exists(&locator) {
+            Locator::ContributionFile(contribution_locator) => match self.storage.exists(&locator)? {
                 true => Ok(contribution_locator.chunk_id()),
                 false => Err(CoordinatorError::ContributionLocatorMissing),
             },
@@ -1379,6 +1380,95 @@ impl Coordinator {
         self.storage.to_path(&locator)
     }
 
+    ///
+    /// Returns a reader to the given contribution file in storage.
+    ///
+    pub fn read_contribution(
+        &self,
+        contribution_locator: ContributionLocator,
+    ) -> Result<StorageReader, CoordinatorError> {
+        self.storage.reader(&Locator::ContributionFile(contribution_locator))
+    }
+
+    ///
+    /// Writes the given contribution file, uploaded by a participant,
+    /// to the given contribution locator in storage.
+    ///
+    /// The contribution locator must have been initialized when the
+    /// participant acquired its task, and the size of the given
+    /// contribution must match the size it was initialized with.
+    /// A contribution may start with a file header, which must match
+    /// the parameters of the chunk, and is stripped before it is stored.
+    ///
+    pub fn write_contribution(
+        &mut self,
+        contribution_locator: ContributionLocator,
+        contribution: Vec<u8>,
+    ) -> Result<(), CoordinatorError> {
+        let locator = Locator::ContributionFile(contribution_locator);
+
+        // Check and strip the optional header of the uploaded contribution.
+        let contribution = Object::strip_contribution_header(&self.environment, &contribution_locator, contribution)?;
+
+        // Check that the uploaded contribution matches the expected contribution size.
+        if self.storage.size(&locator)? != contribution.len() as u64 {
+            error!(
+                "Contribution uploaded to {} has an incorrect size",
+                self.storage.to_path(&locator)?
+            );
+            return Err(CoordinatorError::ContributionFileSizeMismatch);
+        }
+
+        self.storage.update(&locator, Object::ContributionFile(contribution))
+    }
+
+    ///
+    /// Writes the given contribution file signature, uploaded by a participant,
+    /// to the given contribution file signature locator in storage.
+    ///
+    pub fn write_contribution_file_signature(
+        &mut self,
+        contribution_file_signature_locator: ContributionSignatureLocator,
+        contribution_file_signature: ContributionFileSignature,
+    ) -> Result<(), CoordinatorError> {
+        self.storage.update(
+            &Locator::ContributionFileSignature(contribution_file_signature_locator),
+            Object::ContributionFileSignature(contribution_file_signature),
+        )
+    }
+
+    ///
+    /// Checks that the given contribution file signature, uploaded by a participant,
+    /// was signed by the participant over the contribution state it contains.
+    ///
+    /// On failure, this function returns a `CoordinatorError`.
+    ///
+    pub fn check_contribution_file_signature(
+        &self,
+        participant: &Participant,
+        contribution_file_signature: &ContributionFileSignature,
+    ) -> Result<(), CoordinatorError> {
+        let address = participant.to_string();
+        let address = address
+            .split('.')
+            .next()
+            .expect("splitting a string should yield at least one item");
+
+        if !self.signature.verify(
+            address,
+            &serde_json::to_string(&contribution_file_signature.get_state())?,
+            contribution_file_signature.get_signature(),
+        ) {
+            error!("Contribution file signature failed to verify for {}", participant);
+            return match participant {
+                Participant::Contributor(_) => Err(CoordinatorError::ContributorSignatureInvalid),
+                Participant::Verifier(_) => Err(CoordinatorError::VerifierSignatureInvalid),
+            };
+        }
+
+        Ok(())
+    }
+
     ///
     /// Attempts to acquire the lock for a given chunk ID and
     /// participant.
@@ -1863,14 +1953,14 @@ impl Coordinator {
         // Check that the current round state exists in storage.
         if !self.storage.exists(&Locator::RoundState {
             round_height: current_round_height,
-        }) {
+        })? {
             return Err(CoordinatorError::RoundStateMissing);
         }
 
         // Check that the next round state does not exist in storage.
         if self.storage.exists(&Locator::RoundState {
             round_height: current_round_height + 1,
-        }) {
+        })? {
             return Err(CoordinatorError::RoundShouldNotExist);
         }
 
@@ -1878,7 +1968,7 @@ impl Coordinator {
         let round_file = Locator::RoundFile {
             round_height: current_round_height,
         };
-        if self.storage.exists(&round_file) {
+        if self.storage.exists(&round_file)? {
             warn!(
                 "Round file locator already exists ({}), removing...",
                 self.storage.to_path(&round_file)?
@@ -1899,7 +1989,7 @@ impl Coordinator {
                 contribution_id,
                 false,
             ));
-            if !self.storage.exists(&locator) {
+            if !self.storage.exists(&locator)? {
                 error!(
                     "Unverified contribution is missing ({})",
                     self.storage.to_path(&locator)?
@@ -1909,7 +1999,7 @@ impl Coordinator {
             // Check that the final verified contribution locator exists.
             let locator =
                 Locator::ContributionFile(ContributionLocator::new(current_round_height + 1, chunk_id, 0, true));
-            if !self.storage.exists(&locator) {
+            if !self.storage.exists(&locator)? {
                 error!("Verified contribution is missing ({})", self.storage.to_path(&locator)?);
                 return Err(CoordinatorError::ContributionMissing);
             }
@@ -1930,7 +2020,7 @@ impl Coordinator {
         }
 
         // Check that the round file for the current round now exists.
-        if !self.storage.exists(&round_file) {
+        if !self.storage.exists(&round_file)? {
             error!("Round file locator is missing ({})", self.storage.to_path(&round_file)?);
             return Err(CoordinatorError::RoundFileMissing);
         }
@@ -1973,7 +2063,7 @@ impl Coordinator {
             let round_file = Locator::RoundFile {
                 round_height: current_round_height,
             };
-            if !self.storage.exists(&round_file) {
+            if !self.storage.exists(&round_file)? {
                 error!("Round file locator is missing ({})", self.storage.to_path(&round_file)?);
                 warn!("Coordinator may be missing a call to `try_aggregate` for the current round");
                 return Err(CoordinatorError::RoundFileMissing);
@@ -1990,7 +2080,7 @@ impl Coordinator {
         let locator = Locator::RoundState {
             round_height: new_height,
         };
-        if self.storage.exists(&locator) {
+        if self.storage.exists(&locator)? {
             error!(
                 "Round {} already exists ({})",
                 new_height,
@@ -2003,7 +2093,7 @@ impl Coordinator {
         for chunk_id in 0..self.environment.number_of_chunks() {
             debug!("Locating round {} chunk {} contribution 0", new_height, chunk_id);
             let locator = Locator::ContributionFile(ContributionLocator::new(new_height, chunk_id, 0, true));
-            if !self.storage.exists(&locator) {
+            if !self.storage.exists(&locator)? {
                 error!("Contribution locator is missing ({})", self.storage.to_path(&locator)?);
                 return Err(CoordinatorError::ContributionLocatorMissing);
             }
@@ -2057,14 +2147,14 @@ impl Coordinator {
         let round_height = 0;
 
         // Check that the current round does not exist in storage.
-        if self.storage.exists(&Locator::RoundState { round_height }) {
+        if self.storage.exists(&Locator::RoundState { round_height })? {
             return Err(CoordinatorError::RoundShouldNotExist);
         }
 
         // Check that the next round does not exist in storage.
         if self.storage.exists(&Locator::RoundState {
             round_height: round_height + 1,
-        }) {
+        })? {
             return Err(CoordinatorError::RoundShouldNotExist);
         }
 
@@ -2090,7 +2180,7 @@ impl Coordinator {
         for chunk_id in 0..self.environment.number_of_chunks() {
             // 1 - Check that the contribution locator corresponding to this round's chunk does not exist.
             let locator = Locator::ContributionFile(ContributionLocator::new(round_height, chunk_id, 0, true));
-            if self.storage.exists(&locator) {
+            if self.storage.exists(&locator)? {
                 error!(
                     "Contribution locator already exists ({})",
                     self.storage.to_path(&locator)?
@@ -2100,7 +2190,7 @@ impl Coordinator {
 
             // 2 - Check that the contribution locator corresponding to the next round's chunk does not exists.
             let locator = Locator::ContributionFile(ContributionLocator::new(round_height + 1, chunk_id, 0, true));
-            if self.storage.exists(&locator) {
+            if self.storage.exists(&locator)? {
                 error!(
                     "Contribution locator already exists ({})",
                     self.storage.to_path(&locator)?
@@ -2114,14 +2204,14 @@ impl Coordinator {
 
             // 1 - Check that the contribution locator corresponding to this round's chunk now exists.
             let locator = Locator::ContributionFile(ContributionLocator::new(round_height, chunk_id, 0, true));
-            if !self.storage.exists(&locator) {
+            if !self.storage.exists(&locator)? {
                 error!("Contribution locator is missing ({})", self.storage.to_path(&locator)?);
                 return Err(CoordinatorError::ContributionLocatorMissing);
             }
 
             // 2 - Check that the contribution locator corresponding to the next round's chunk now exists.
             let locator = Locator::ContributionFile(ContributionLocator::new(round_height + 1, chunk_id, 0, true));
-            if !self.storage.exists(&locator) {
+            if !self.storage.exists(&locator)? {
                 error!("Contribution locator is missing ({})", self.storage.to_path(&locator)?);
                 return Err(CoordinatorError::ContributionLocatorMissing);
             }
@@ -2203,8 +2293,8 @@ impl Coordinator {
     }
 
     #[inline]
-    fn load_current_round_height(storage: &Disk) -> Result<u64, CoordinatorError> {
-        if storage.exists(&Locator::RoundHeight) {
+    fn load_current_round_height(storage: &dyn Storage) -> Result<u64, CoordinatorError> {
+        if storage.exists(&Locator::RoundHeight)? {
             // Fetch the current round height from storage.
             match storage.get(&Locator::RoundHeight)? {
                 // Case 1 - This is a typical round of the ceremony.
@@ -2218,7 +2308,7 @@ impl Coordinator {
     }
 
     #[inline]
-    fn load_current_round(storage: &Disk) -> Result<Round, CoordinatorError> {
+    fn load_current_round(storage: &dyn Storage) -> Result<Round, CoordinatorError> {
         // Fetch the current round height from storage.
         let current_round_height = Self::load_current_round_height(storage)?;
 
@@ -2227,7 +2317,7 @@ impl Coordinator {
     }
 
     #[inline]
-    fn load_round(storage: &Disk, round_height: u64) -> Result<Round, CoordinatorError> {
+    fn load_round(storage: &dyn Storage, round_height: u64) -> Result<Round, CoordinatorError> {
         // Fetch the current round height from storage.
         let current_round_height = Self::load_current_round_height(storage)?;
 
@@ -2251,8 +2341,8 @@ impl Coordinator {
     ///
     #[cfg(test)]
     #[inline]
-    pub(super) fn storage(&self) -> &Disk {
-        &self.storage
+    pub(super) fn storage(&self) -> &dyn Storage {
+        &*self.storage
     }
 
     ///
@@ -2261,8 +2351,8 @@ impl Coordinator {
     ///
     #[cfg(test)]
     #[inline]
-    pub(super) fn storage_mut(&mut self) -> &mut Disk {
-        &mut self.storage
+    pub(super) fn storage_mut(&mut self) -> &mut dyn Storage {
+        &mut *self.storage
     }
 
     ///
@@ -2335,7 +2425,7 @@ impl Coordinator {
 }
 
 #[cfg(any(test, feature = "operator"))]
-use crate::commands::{Computation, Seed, SigningKey, Verification};
+use crate::commands::{Computation, LockDeadline, Seed, SigningKey, Verification};
 
 #[cfg(any(test, feature = "operator"))]
 impl Coordinator {
@@ -2501,6 +2591,7 @@ impl Coordinator {
             response_locator,
             contribution_file_signature_locator,
             participant_seed,
+            &self.lock_deadline(participant, chunk_id),
         )?;
         info!(
             "Completed computation on round {} chunk {} contribution {} as {}",
@@ -2555,7 +2646,7 @@ impl Coordinator {
         // Check that the contribution locator corresponding to the response file exists.
         let response_locator =
             Locator::ContributionFile(ContributionLocator::new(round_height, chunk_id, contribution_id, false));
-        if !self.storage.exists(&response_locator) {
+        if !self.storage.exists(&response_locator)? {
             error!(
                 "Response file at {} is missing",
                 self.storage.to_path(&response_locator)?
@@ -2593,6 +2684,7 @@ impl Coordinator {
             chunk_id,
             contribution_id,
             is_final_contribution,
+            &self.lock_deadline(participant, chunk_id),
         )?;
         info!(
             "Completed verification on round {} chunk {} contribution {} as {}",
@@ -2600,7 +2692,7 @@ impl Coordinator {
         );
 
         // Check that the verified contribution locator exists.
-        if !self.storage.exists(&verified_locator) {
+        if !self.storage.exists(&verified_locator)? {
             let verified_response = self.storage.to_path(&verified_locator)?;
             error!("Verified response file at {} is missing", verified_response);
             return Err(CoordinatorError::ContributionLocatorMissing);
@@ -2609,6 +2701,25 @@ impl Coordinator {
         Ok(self.storage.to_path(&verified_locator)?)
     }
 
+    ///
+    /// Returns the progress of a computation or a verification by the given participant
+    /// on the given chunk, which cancels it once the participant would be dropped for
+    /// holding the lock on the chunk for longer than the participant lock timeout.
+    ///
+    #[inline]
+    fn lock_deadline(&self, participant: &Participant, chunk_id: u64) -> LockDeadline {
+        // Coordinator contributors are never dropped for holding a lock too long.
+        let deadline = match self.state.is_coordinator_contributor(participant) {
+            true => None,
+            false => self
+                .state
+                .current_participant_info(participant)
+                .and_then(|participant_info| participant_info.locked_chunks().get(&chunk_id))
+                .map(|lock| *lock.lock_time() + self.environment.participant_lock_timeout()),
+        };
+        LockDeadline::new(self.time.clone(), deadline)
+    }
+
     ///
     /// Returns a reference to the instantiation of `CoordinatorState` that this
     /// coordinator is using.
@@ -2655,9 +2766,12 @@ mod tests {
         commands::{Seed, SigningKey, SEED_LENGTH},
         environment::*,
         objects::{Participant, Task},
+        storage::ContributionLocator,
         testing::prelude::*,
         Coordinator,
     };
+    use phase1::{helpers::CurveKind, FileHeader};
+    use snarkvm_curves::bls12_377::Bls12_377;
 
     use once_cell::sync::Lazy;
     use rand::RngCore;
@@ -3023,6 +3137,66 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    #[serial]
+    fn coordinator_contributor_upload_contribution_with_header() -> anyhow::Result<()> {
+        initialize_test_environment(&TEST_ENVIRONMENT_3);
+
+        let contributor = Lazy::force(&TEST_CONTRIBUTOR_ID);
+        let contributor_signing_key: SigningKey = "secret_key".to_string();
+
+        let mut coordinator = Coordinator::new(TEST_ENVIRONMENT_3.clone(), Arc::new(Dummy))?;
+        initialize_coordinator(&mut coordinator)?;
+
+        // Compute round 1 chunk 0 contribution 1.
+        let round_height = coordinator.current_round_height()?;
+        let chunk_id = 0;
+        let contribution_id = 1;
+        coordinator.try_lock_chunk(chunk_id, &contributor)?;
+        let mut seed: Seed = [0; SEED_LENGTH];
+        rand::thread_rng().fill_bytes(&mut seed[..]);
+        coordinator.run_computation(
+            round_height,
+            chunk_id,
+            contribution_id,
+            contributor,
+            &contributor_signing_key,
+            &seed,
+        )?;
+
+        let contribution_locator = ContributionLocator::new(round_height, chunk_id, contribution_id, false);
+        let contribution = coordinator.read_contribution(contribution_locator)?.to_vec();
+
+        // Upload the contribution with a header which describes the chunk.
+        let settings = TEST_ENVIRONMENT_3.parameters();
+        assert_eq!(settings.curve(), CurveKind::Bls12_377);
+        let parameters = phase1_chunked_parameters!(Bls12_377, settings, chunk_id);
+        let compressed = TEST_ENVIRONMENT_3.compressed_outputs();
+        let mut upload = FileHeader::new(&parameters, compressed)?.to_bytes().to_vec();
+        upload.extend_from_slice(&contribution);
+        coordinator.write_contribution(contribution_locator, upload)?;
+
+        // The header is stripped before the contribution is stored.
+        assert_eq!(coordinator.read_contribution(contribution_locator)?.to_vec(), contribution);
+
+        // A header which does not describe the chunk is rejected.
+        let mut header = FileHeader::new(&parameters, compressed)?;
+        header.chunk_index += 1;
+        let mut upload = header.to_bytes().to_vec();
+        upload.extend_from_slice(&contribution);
+        assert!(coordinator.write_contribution(contribution_locator, upload).is_err());
+
+        // The stored contribution is verified as usual.
+        coordinator.add_contribution(chunk_id, &contributor)?;
+        let verifier = Lazy::force(&TEST_VERIFIER_ID).clone();
+        let verifier_signing_key: SigningKey = "secret_key".to_string();
+        let task = Task::new(chunk_id, contribution_id);
+        coordinator.run_verification(round_height, &task, &verifier, &verifier_signing_key)?;
+        coordinator.verify_contribution(&task, &verifier)?;
+
+        Ok(())
+    }
+
     #[test]
     #[serial]
     // This test runs a round with a single coordinator and single verifier
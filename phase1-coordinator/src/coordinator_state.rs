@@ -4,7 +4,7 @@ use crate::{
         participant::*,
         task::{initialize_tasks, Task},
     },
-    storage::{Disk, Locator, Object},
+    storage::{Locator, Object, Storage},
     CoordinatorError,
     TimeSource,
 };
@@ -2807,7 +2807,9 @@ impl CoordinatorState {
 
                         let estimated_time_remaining = match self.environment.parameters().proving_system() {
                             ProvingSystem::Groth16 => (cumulative_seconds / number_of_contributors_left) / 2,
-                            ProvingSystem::Marlin => cumulative_seconds / number_of_contributors_left,
+                            ProvingSystem::Marlin | ProvingSystem::Kzg => {
+                                cumulative_seconds / number_of_contributors_left
+                            }
                         };
 
                         let estimated_aggregation_time = (contributor_average_per_task + verifier_average_per_task)
@@ -3273,7 +3275,7 @@ impl CoordinatorState {
 
     /// Save the coordinator state in storage.
     #[inline]
-    pub(crate) fn save(&self, storage: &mut Disk) -> Result<(), CoordinatorError> {
+    pub(crate) fn save(&self, storage: &mut dyn Storage) -> Result<(), CoordinatorError> {
         storage.update(&Locator::CoordinatorState, Object::CoordinatorState(self.clone()))
     }
 }
@@ -1,6 +1,11 @@
-use crate::{objects::Participant, storage::Disk};
+#[cfg(feature = "s3")]
+use crate::storage::{ObjectStore, ObjectStoreSettings};
+use crate::{
+    objects::Participant,
+    storage::{Disk, Memory, Storage},
+};
 use phase1::{chunk_size, helpers::CurveKind, total_size_in_g1, ContributionMode, ProvingSystem};
-use setup_utils::{CheckForCorrectness, UseCompression};
+use setup_utils::{CheckForCorrectness, UseCompression, VerificationStrategy};
 
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use serde::{Deserialize, Serialize};
@@ -78,11 +83,31 @@ pub enum Deployment {
     Production,
 }
 
+/// The storage backend of the coordinator.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum StorageBackend {
+    /// Stores all objects as files in the local base directory.
+    Disk,
+    /// Stores all objects in memory, which is lost when the coordinator exits.
+    Memory,
+    /// Stores all objects in an S3-compatible object store.
+    #[cfg(feature = "s3")]
+    ObjectStore(ObjectStoreSettings),
+}
+
+impl Default for StorageBackend {
+    fn default() -> Self {
+        StorageBackend::Disk
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum Parameters {
     AleoInner,
     AleoOuter,
     AleoUniversal,
+    AleoKzg,
+    Bls12_381,
     Custom(Settings),
     Test3Chunks,
     Test8Chunks,
@@ -103,6 +128,8 @@ impl Parameters {
             Parameters::AleoInner => Self::aleo_inner(),
             Parameters::AleoOuter => Self::aleo_outer(),
             Parameters::AleoUniversal => Self::aleo_universal(),
+            Parameters::AleoKzg => Self::aleo_kzg(),
+            Parameters::Bls12_381 => Self::bls12_381(),
             Parameters::Custom(settings) => settings.clone(),
             Parameters::Test3Chunks => Self::test_3_chunks(),
             Parameters::Test8Chunks => Self::test_8_chunks(),
@@ -148,6 +175,28 @@ impl Parameters {
         )
     }
 
+    fn aleo_kzg() -> Settings {
+        Settings::new(
+            ContributionMode::Chunked,
+            ProvingSystem::Kzg,
+            CurveKind::Bls12_377,
+            Power::from(28_usize),
+            BatchSize::from(2097152_usize),
+            ChunkSize::from(65536_usize),
+        )
+    }
+
+    fn bls12_381() -> Settings {
+        Settings::new(
+            ContributionMode::Chunked,
+            ProvingSystem::Groth16,
+            CurveKind::Bls12_381,
+            Power::from(21_usize),
+            BatchSize::from(2097152_usize),
+            ChunkSize::from(65536_usize),
+        )
+    }
+
     fn test_3_chunks() -> Settings {
         Settings::new(
             ContributionMode::Chunked,
@@ -208,6 +257,9 @@ pub struct Environment {
     compressed_outputs: UseCompression,
     /// The input correctness check preference of the coordinator.
     check_input_for_correctness: CheckForCorrectness,
+    /// The strategy used to verify the ratios of an aggregated round.
+    #[serde(default)]
+    verification_strategy: VerificationStrategy,
 
     /// The minimum number of contributors permitted to participate in a round.
     minimum_contributors_per_round: usize,
@@ -255,6 +307,9 @@ pub struct Environment {
     deployment: Deployment,
     /// The base directory for disk storage of this coordinator.
     local_base_directory: String,
+    /// The storage backend of this coordinator.
+    #[serde(default)]
+    storage_backend: StorageBackend,
 
     disable_reliability_zeroing: bool,
 }
@@ -297,6 +352,16 @@ impl Environment {
         self.check_input_for_correctness
     }
 
+    ///
+    /// Returns the strategy used to verify the ratios of an aggregated round.
+    ///
+    /// The default choice should be `VerificationStrategy::Batched` to minimize
+    /// time spent by the coordinator on verifying a round after aggregation.
+    ///
+    pub const fn verification_strategy(&self) -> VerificationStrategy {
+        self.verification_strategy
+    }
+
     ///
     /// Returns the minimum number of contributors permitted to
     /// participate in a round.
@@ -435,6 +500,13 @@ impl Environment {
         &self.local_base_directory
     }
 
+    ///
+    /// Returns the storage backend of this coordinator.
+    ///
+    pub const fn storage_backend(&self) -> &StorageBackend {
+        &self.storage_backend
+    }
+
     ///
     /// Returns the appropriate number of chunks for the coordinator
     /// to run given a proof system, power and chunk size.
@@ -447,8 +519,13 @@ impl Environment {
     }
 
     /// Returns the storage system of the coordinator.
-    pub(crate) fn storage(&self) -> anyhow::Result<Disk> {
-        Ok(Disk::load(self)?)
+    pub(crate) fn storage(&self) -> anyhow::Result<Box<dyn Storage>> {
+        Ok(match &self.storage_backend {
+            StorageBackend::Disk => Box::new(Disk::load(self)?),
+            StorageBackend::Memory => Box::new(Memory::load(self)?),
+            #[cfg(feature = "s3")]
+            StorageBackend::ObjectStore(settings) => Box::new(ObjectStore::load(self, settings)?),
+        })
     }
 
     pub(crate) fn disable_reliability_zeroing(&self) -> bool {
@@ -496,6 +573,16 @@ impl Testing {
         self
     }
 
+    pub fn storage_backend(mut self, storage_backend: StorageBackend) -> Self {
+        self.environment.storage_backend = storage_backend;
+        self
+    }
+
+    pub fn verification_strategy(mut self, verification_strategy: VerificationStrategy) -> Self {
+        self.environment.verification_strategy = verification_strategy;
+        self
+    }
+
     #[inline]
     pub fn coordinator_contributors(&self, contributors: &[Participant]) -> Self {
         // Check that all participants are contributors.
@@ -563,6 +650,7 @@ impl std::default::Default for Testing {
                 compressed_inputs: UseCompression::No,
                 compressed_outputs: UseCompression::Yes,
                 check_input_for_correctness: CheckForCorrectness::No,
+                verification_strategy: VerificationStrategy::Batched,
 
                 minimum_contributors_per_round: 1,
                 maximum_contributors_per_round: 5,
@@ -585,6 +673,7 @@ impl std::default::Default for Testing {
                 software_version: 1,
                 deployment: Deployment::Testing,
                 local_base_directory: "./transcript/testing".to_string(),
+                storage_backend: StorageBackend::Disk,
 
                 disable_reliability_zeroing: false,
             },
@@ -624,6 +713,16 @@ impl Development {
         self
     }
 
+    pub fn storage_backend(mut self, storage_backend: StorageBackend) -> Self {
+        self.environment.storage_backend = storage_backend;
+        self
+    }
+
+    pub fn verification_strategy(mut self, verification_strategy: VerificationStrategy) -> Self {
+        self.environment.verification_strategy = verification_strategy;
+        self
+    }
+
     #[inline]
     pub fn coordinator_contributors(&self, contributors: &[Participant]) -> Self {
         // Check that all participants are contributors.
@@ -679,6 +778,7 @@ impl std::default::Default for Development {
                 compressed_inputs: UseCompression::No,
                 compressed_outputs: UseCompression::Yes,
                 check_input_for_correctness: CheckForCorrectness::No,
+                verification_strategy: VerificationStrategy::Batched,
 
                 minimum_contributors_per_round: 1,
                 maximum_contributors_per_round: 5,
@@ -701,6 +801,7 @@ impl std::default::Default for Development {
                 software_version: 1,
                 deployment: Deployment::Development,
                 local_base_directory: "./transcript/development".to_string(),
+                storage_backend: StorageBackend::Disk,
 
                 disable_reliability_zeroing: false,
             },
@@ -745,6 +846,16 @@ impl Production {
         self
     }
 
+    pub fn storage_backend(mut self, storage_backend: StorageBackend) -> Self {
+        self.environment.storage_backend = storage_backend;
+        self
+    }
+
+    pub fn verification_strategy(mut self, verification_strategy: VerificationStrategy) -> Self {
+        self.environment.verification_strategy = verification_strategy;
+        self
+    }
+
     #[inline]
     pub fn coordinator_contributors(&self, contributors: &[Participant]) -> Self {
         // Check that all participants are contributors.
@@ -794,6 +905,7 @@ impl std::default::Default for Production {
                 compressed_inputs: UseCompression::No,
                 compressed_outputs: UseCompression::Yes,
                 check_input_for_correctness: CheckForCorrectness::No,
+                verification_strategy: VerificationStrategy::Batched,
 
                 minimum_contributors_per_round: 1,
                 maximum_contributors_per_round: 5,
@@ -816,6 +928,7 @@ impl std::default::Default for Production {
                 software_version: 1,
                 deployment: Deployment::Production,
                 local_base_directory: "./transcript".to_string(),
+                storage_backend: StorageBackend::Disk,
 
                 disable_reliability_zeroing: false,
             },
@@ -884,4 +997,17 @@ mod tests {
         assert_eq!(ChunkSize::from(1639_usize), chunk_size);
         assert_eq!(number_of_chunks as u64, Testing::from(parameters).number_of_chunks());
     }
+
+    #[test]
+    fn test_default_verification_strategy() {
+        let environment: Environment = Testing::from(Parameters::Test3Chunks)
+            .verification_strategy(VerificationStrategy::PerBatch)
+            .into();
+
+        // An environment serialized without the strategy uses the batched verification.
+        let mut value = serde_json::to_value(&environment).unwrap();
+        value.as_object_mut().unwrap().remove("verification_strategy");
+        let environment: Environment = serde_json::from_value(value).unwrap();
+        assert_eq!(VerificationStrategy::Batched, environment.verification_strategy());
+    }
 }
@@ -50,6 +50,9 @@ pub mod environment;
 pub mod objects;
 pub use objects::{ContributionFileSignature, ContributionState, Participant, Round};
 
+#[cfg(feature = "server")]
+pub mod rest;
+
 pub mod storage;
 
 #[cfg(any(test, feature = "testing"))]
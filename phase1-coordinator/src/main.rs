@@ -1,14 +1,110 @@
 use phase1_coordinator::{
-    authentication::{Dummy, Signature},
-    environment::{Development, Environment, Parameters},
+    authentication::{Aleo, Signature},
+    environment::{Development, Environment, Parameters, Production, StorageBackend},
+    rest,
+    storage::ObjectStoreSettings,
     Coordinator,
+    Participant,
 };
 use tracing_subscriber;
 
-use std::{sync::Arc, time::Duration};
+use std::{net::SocketAddr, sync::Arc, time::Duration};
+use structopt::StructOpt;
 use tokio::{sync::RwLock, task, time::sleep};
 use tracing::*;
 
+#[derive(Debug, StructOpt)]
+#[structopt(name = "phase1-coordinator", about = "The HTTP server of the Phase 1 coordinator")]
+struct Options {
+    #[structopt(
+        long,
+        default_value = "development",
+        possible_values = &["development", "inner", "outer", "universal", "kzg", "bls12_381"],
+        help = "The kind of setup run by the coordinator"
+    )]
+    setup: String,
+    #[structopt(
+        long,
+        default_value = "0.0.0.0:9000",
+        help = "The address the HTTP server listens on"
+    )]
+    address: SocketAddr,
+    #[structopt(
+        long,
+        required = true,
+        help = "The Aleo addresses of the verifiers managed by the coordinator"
+    )]
+    verifiers: Vec<String>,
+    #[structopt(
+        long,
+        help = "The bucket of the S3-compatible object store used as storage, instead of the local disk"
+    )]
+    s3_bucket: Option<String>,
+    #[structopt(
+        long,
+        default_value = "https://s3.amazonaws.com",
+        help = "The endpoint of the S3-compatible object store"
+    )]
+    s3_endpoint: String,
+    #[structopt(
+        long,
+        default_value = "us-east-1",
+        help = "The region of the S3-compatible object store"
+    )]
+    s3_region: String,
+}
+
+fn environment(options: &Options) -> Environment {
+    let verifiers: Vec<Participant> = options
+        .verifiers
+        .iter()
+        .map(|verifier| Participant::new_verifier(verifier))
+        .collect();
+
+    // The credentials of the object store are read from `AWS_ACCESS_KEY_ID` and `AWS_SECRET_ACCESS_KEY`.
+    let storage_backend = match &options.s3_bucket {
+        Some(bucket) => StorageBackend::ObjectStore(ObjectStoreSettings {
+            endpoint: options.s3_endpoint.clone(),
+            region: options.s3_region.clone(),
+            bucket: bucket.clone(),
+        }),
+        None => StorageBackend::Disk,
+    };
+
+    // The parameters of each setup must match the environments selected by
+    // the contributors and verifiers from the coordinator public settings.
+    match options.setup.as_str() {
+        "inner" => Production::from(Parameters::AleoInner)
+            .storage_backend(storage_backend)
+            .coordinator_verifiers(&verifiers)
+            .into(),
+        "outer" => Production::from(Parameters::AleoOuter)
+            .storage_backend(storage_backend)
+            .coordinator_verifiers(&verifiers)
+            .into(),
+        "universal" => Production::from(Parameters::AleoUniversal)
+            .storage_backend(storage_backend)
+            .coordinator_verifiers(&verifiers)
+            .into(),
+        "kzg" => Production::from(Parameters::AleoKzg)
+            .storage_backend(storage_backend)
+            .coordinator_verifiers(&verifiers)
+            .into(),
+        "bls12_381" => Production::from(Parameters::Bls12_381)
+            .storage_backend(storage_backend)
+            .coordinator_verifiers(&verifiers)
+            .into(),
+        _ => Development::from(Parameters::TestCustom {
+            number_of_chunks: 64,
+            power: 16,
+            batch_size: 512,
+        })
+        .storage_backend(storage_backend)
+        .coordinator_verifiers(&verifiers)
+        .into(),
+    }
+}
+
 fn coordinator(environment: &Environment, signature: Arc<dyn Signature>) -> anyhow::Result<Coordinator> {
     Ok(Coordinator::new(environment.clone(), signature)?)
 }
@@ -17,38 +113,38 @@ fn coordinator(environment: &Environment, signature: Arc<dyn Signature>) -> anyh
 pub async fn main() -> anyhow::Result<()> {
     tracing_subscriber::fmt::init();
 
+    let options = Options::from_args();
+
     // Set the environment.
-    let environment: Environment = Development::from(Parameters::TestCustom {
-        number_of_chunks: 8,
-        power: 12,
-        batch_size: 256,
-    })
-    .into();
-    // use phase1_coordinator::environment::Production;
-    // let environment: Environment = Production::from(Parameters::AleoInner).into();
+    let environment = environment(&options);
 
     // Instantiate the coordinator.
-    let coordinator: Arc<RwLock<Coordinator>> = Arc::new(RwLock::new(coordinator(&environment, Arc::new(Dummy))?));
+    let coordinator: Arc<RwLock<Coordinator>> = Arc::new(RwLock::new(coordinator(&environment, Arc::new(Aleo))?));
 
-    let ceremony_coordinator = coordinator.clone();
     // Initialize the coordinator.
-    let ceremony = task::spawn(async move {
-        // Initialize the coordinator.
-        ceremony_coordinator.write().await.initialize().unwrap();
+    coordinator.write().await.initialize()?;
 
-        // Initialize the coordinator loop.
+    let ceremony_coordinator = coordinator.clone();
+    // Initialize the coordinator loop.
+    let ceremony = task::spawn(async move {
         loop {
             // Run the update operation.
             if let Err(error) = ceremony_coordinator.write().await.update() {
-                error!("{}", error);
+                error!("{:?}", error);
             }
 
             // Sleep for 10 seconds in between iterations.
             sleep(Duration::from_secs(10)).await;
-            break;
         }
     });
 
+    // Initialize the HTTP server.
+    let server = {
+        let routes = rest::routes(coordinator.clone());
+        info!("Starting the coordinator HTTP server on {}", options.address);
+        task::spawn(warp::serve(routes).run(options.address))
+    };
+
     // Initialize the shutdown procedure.
     let shutdown_handler = {
         let shutdown_coordinator = coordinator.clone();
@@ -71,6 +167,9 @@ pub async fn main() -> anyhow::Result<()> {
         _ = ceremony => {
             println!("Ceremony completed first")
         }
+        _ = server => {
+            println!("Server completed first")
+        }
     };
 
     Ok(())
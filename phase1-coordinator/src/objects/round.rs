@@ -4,10 +4,10 @@ use crate::{
     storage::{
         ContributionLocator,
         ContributionSignatureLocator,
-        Disk,
         Locator,
         LocatorPath,
         Object,
+        Storage,
         StorageAction,
         StorageLocator,
         UpdateAction,
@@ -89,7 +89,7 @@ impl Round {
     #[inline]
     pub(crate) fn new(
         environment: &Environment,
-        storage: &mut Disk,
+        storage: &mut dyn Storage,
         round_height: u64,
         started_at: OffsetDateTime,
         contributor_ids: Vec<Participant>,
@@ -345,7 +345,7 @@ impl Round {
     )]
     pub(crate) fn current_contribution_locator(
         &self,
-        storage: &Disk,
+        storage: &dyn Storage,
         chunk_id: u64,
         verified: bool,
     ) -> Result<ContributionLocator, CoordinatorError> {
@@ -362,7 +362,7 @@ impl Round {
 
         // Check that the contribution locator corresponding to the current contribution ID
         // exists for the current round and given chunk ID.
-        if !storage.exists(&Locator::ContributionFile(current_contribution_locator.clone())) {
+        if !storage.exists(&Locator::ContributionFile(current_contribution_locator.clone()))? {
             error!(
                 "{} is missing",
                 storage.to_path(&Locator::ContributionFile(current_contribution_locator.clone()))?
@@ -406,7 +406,7 @@ impl Round {
     )]
     pub(crate) fn next_contribution_locator(
         &self,
-        storage: &Disk,
+        storage: &dyn Storage,
         chunk_id: u64,
     ) -> Result<ContributionLocator, CoordinatorError> {
         // Fetch the current round height.
@@ -429,7 +429,7 @@ impl Round {
 
         // Check that the contribution locator corresponding to the next contribution ID
         // does NOT exist for the current round and given chunk ID.
-        if storage.exists(&Locator::ContributionFile(next_contribution_locator.clone())) {
+        if storage.exists(&Locator::ContributionFile(next_contribution_locator.clone()))? {
             tracing::error!("Contribution locator already exists: {:?}", next_contribution_locator);
             return Err(CoordinatorError::ContributionLocatorAlreadyExists);
         }
@@ -462,7 +462,7 @@ impl Round {
     #[inline]
     pub(crate) fn next_contribution_file_signature_locator(
         &self,
-        storage: &Disk,
+        storage: &dyn Storage,
         chunk_id: u64,
     ) -> Result<ContributionSignatureLocator, CoordinatorError> {
         // Fetch the current round height.
@@ -487,7 +487,7 @@ impl Round {
         // does NOT exist for the current round and given chunk ID.
         if storage.exists(&Locator::ContributionFileSignature(
             contribution_file_signature_locator.clone(),
-        )) {
+        ))? {
             return Err(CoordinatorError::ContributionFileSignatureLocatorAlreadyExists);
         }
 
@@ -513,7 +513,7 @@ impl Round {
     pub(crate) fn try_lock_chunk(
         &mut self,
         environment: &Environment,
-        storage: &mut Disk,
+        storage: &mut dyn Storage,
         chunk_id: u64,
         participant: &Participant,
     ) -> Result<LockedLocators, CoordinatorError> {
@@ -643,7 +643,7 @@ impl Round {
     pub fn initialize_verifier_response_files(
         &self,
         environment: &Environment,
-        storage: &mut Disk,
+        storage: &mut dyn Storage,
         participant: &Participant,
         chunk_id: u64,
         locators: &LockedLocators,
@@ -669,7 +669,7 @@ impl Round {
     /// Returns previous contribution, current contribution and next contribution paths
     pub(crate) fn get_chunk_locators_for_verifier(
         &self,
-        storage: &Disk,
+        storage: &dyn Storage,
         participant: &Participant,
         chunk_id: u64,
         contribution_id: u64,
@@ -787,7 +787,7 @@ impl Round {
     /// Remove a contributor from the round.
     pub(crate) fn remove_contributor_unsafe(
         &mut self,
-        storage: &mut Disk,
+        storage: &mut dyn Storage,
         contributor: &Participant,
         locked_chunks: &[u64],
         tasks: &[Task],
@@ -824,7 +824,7 @@ impl Round {
     #[inline]
     pub(crate) fn remove_locks_unsafe(
         &mut self,
-        storage: &mut Disk,
+        storage: &mut dyn Storage,
         participant: &Participant,
         locked_chunks: &[u64],
     ) -> Result<(), CoordinatorError> {
@@ -871,7 +871,7 @@ impl Round {
                         next_contribution_id,
                         false,
                     ));
-                    if storage.exists(&response_locator) {
+                    if storage.exists(&response_locator)? {
                         storage.remove(&response_locator)?;
                     }
 
@@ -879,7 +879,7 @@ impl Round {
                     let response_signature_locator = Locator::ContributionFileSignature(
                         ContributionSignatureLocator::new(current_round_height, *chunk_id, next_contribution_id, false),
                     );
-                    if storage.exists(&response_signature_locator) {
+                    if storage.exists(&response_signature_locator)? {
                         storage.remove(&response_signature_locator)?;
                     }
 
@@ -887,7 +887,7 @@ impl Round {
                     let response_signature_locator = Locator::ContributionFileSignature(
                         ContributionSignatureLocator::new(current_round_height, *chunk_id, next_contribution_id, true),
                     );
-                    if storage.exists(&response_signature_locator) {
+                    if storage.exists(&response_signature_locator)? {
                         storage.remove(&response_signature_locator)?;
                     }
 
@@ -919,7 +919,7 @@ impl Round {
                             ))
                         };
                         // Don't remove initial challenge
-                        if storage.exists(&contribution_file)
+                        if storage.exists(&contribution_file)?
                             && chunk.current_contribution()?.get_contributor().is_some()
                         {
                             storage.remove(&contribution_file)?;
@@ -944,7 +944,7 @@ impl Round {
                         )),
                     };
 
-                    if storage.exists(&response_locator) {
+                    if storage.exists(&response_locator)? {
                         storage.remove(&response_locator)?;
                     }
 
@@ -963,7 +963,7 @@ impl Round {
                         )),
                     };
 
-                    if storage.exists(&response_locator_signature) {
+                    if storage.exists(&response_locator_signature)? {
                         storage.remove(&response_locator_signature)?;
                     }
                 }
@@ -998,7 +998,7 @@ impl Round {
     )]
     pub(crate) fn remove_chunk_contributions_unsafe(
         &mut self,
-        storage: &mut Disk,
+        storage: &mut dyn Storage,
         participant: &Participant,
         tasks: &[Task],
     ) -> Result<(), CoordinatorError> {
@@ -1024,7 +1024,7 @@ impl Round {
                 // Remove the unverified contribution file, if it exists.
                 if let Some(locator) = contribution.get_contributed_location() {
                     let path = storage.to_locator(&locator)?;
-                    if storage.exists(&path) {
+                    if storage.exists(&path)? {
                         storage.remove(&path)?;
                     }
                 }
@@ -1032,7 +1032,7 @@ impl Round {
                 // Remove the contribution signature file, if it exists.
                 if let Some(locator) = contribution.get_contributed_signature_location() {
                     let path = storage.to_locator(&locator)?;
-                    if storage.exists(&path) {
+                    if storage.exists(&path)? {
                         storage.remove(&path)?;
                     }
                 }
@@ -1040,7 +1040,7 @@ impl Round {
                 // Remove the verified contribution file, if it exists.
                 if let Some(locator) = contribution.get_verified_location() {
                     let path = storage.to_locator(&locator)?;
-                    if storage.exists(&path) {
+                    if storage.exists(&path)? {
                         storage.remove(&path)?;
                     }
                 }
@@ -1048,7 +1048,7 @@ impl Round {
                 // Remove the verified contribution file signature, if it exists.
                 if let Some(locator) = contribution.get_verified_signature_location() {
                     let path = storage.to_locator(&locator)?;
-                    if storage.exists(&path) {
+                    if storage.exists(&path)? {
                         storage.remove(&path)?;
                     }
                 }
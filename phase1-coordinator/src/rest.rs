@@ -0,0 +1,786 @@
+//! The HTTP API of the [Coordinator].
+//!
+//! This module exposes the `/v1` routes used by the contributors in
+//! `setup1-contributor` and the verifiers in `setup1-verifier`. Each
+//! authenticated request carries an `Authorization` header of the form
+//! `Aleo <address>:<signature>`, where the signature is produced by
+//! the participant's view key over the lowercase `<method> <path>`.
+//!
+//! The reliability checks (`/v1/contributor/reliability`), the Ethereum
+//! address and the Twitter attestation routes of the contributor are not
+//! served here, as they depend on services outside of the coordinator.
+//! The public settings disable the reliability checks, and the contributor
+//! only logs the failure of the optional address and attestation prompts.
+
+use crate::{
+    authentication::{Aleo, Signature},
+    environment::{Deployment, Environment},
+    objects::{ContributionFileSignature, ContributionState, Task},
+    storage::{ContributionLocator, ContributionSignatureLocator, Locator},
+    Coordinator,
+    CoordinatorError,
+    Participant,
+};
+use phase1::{helpers::CurveKind, ProvingSystem};
+use setup1_shared::structures::{AssignedTask, ContributorStatus, LockResponse, PublicSettings, SetupKind};
+
+use std::{convert::Infallible, net::SocketAddr, sync::Arc};
+use tokio::sync::RwLock;
+use tracing::*;
+use warp::{
+    http::{header, Method, Response, StatusCode},
+    hyper::{body::Bytes, Body},
+    path::FullPath,
+    reject::{Reject, Rejection},
+    reply::Reply,
+    Filter,
+};
+
+/// The reliability score assigned to contributors joining the queue.
+const DEFAULT_RELIABILITY_SCORE: u8 = 10;
+
+/// The size of the signature and of each hash prefixed to an uploaded file.
+const SIGNATURE_SIZE: usize = 64;
+const HASH_SIZE: usize = 64;
+/// The size of the chunks contribution files are streamed in.
+const STREAM_CHUNK_SIZE: usize = 1 << 20;
+
+/// The coordinator shared between the HTTP handlers and the update loop.
+pub type SharedCoordinator = Arc<RwLock<Coordinator>>;
+
+/// The errors returned by the HTTP handlers.
+#[derive(Debug)]
+pub enum ResponseError {
+    /// The request has an invalid or missing authorization header.
+    Unauthorized,
+    /// The request body is malformed.
+    InvalidUpload(&'static str),
+    /// The request was rejected by the coordinator.
+    Coordinator(CoordinatorError),
+    /// The requested file could not be read from storage.
+    IOError(std::io::Error),
+}
+
+impl Reject for ResponseError {}
+
+impl From<CoordinatorError> for ResponseError {
+    fn from(error: CoordinatorError) -> Self {
+        ResponseError::Coordinator(error)
+    }
+}
+
+impl From<std::io::Error> for ResponseError {
+    fn from(error: std::io::Error) -> Self {
+        ResponseError::IOError(error)
+    }
+}
+
+/// Returns the public settings that the contributors and verifiers
+/// use to select the environment of the coordinator.
+pub fn public_settings(environment: &Environment) -> PublicSettings {
+    let settings = environment.parameters();
+    let setup = match (environment.deployment(), settings.proving_system(), settings.curve()) {
+        (Deployment::Production, ProvingSystem::Marlin, _) => SetupKind::Universal,
+        (Deployment::Production, ProvingSystem::Kzg, _) => SetupKind::Kzg,
+        (Deployment::Production, _, CurveKind::Bls12_381) => SetupKind::Bls12_381,
+        (Deployment::Production, _, CurveKind::BW6) => SetupKind::Outer,
+        (Deployment::Production, _, _) => SetupKind::Inner,
+        (_, _, _) => SetupKind::Development,
+    };
+
+    PublicSettings {
+        setup,
+        check_reliability: false,
+    }
+}
+
+/// Returns all of the `/v1` routes of the coordinator.
+pub fn routes(
+    coordinator: SharedCoordinator,
+) -> impl Filter<Extract = impl Reply, Error = Infallible> + Clone + Send + Sync + 'static {
+    let settings = warp::post()
+        .and(warp::path!("v1" / "coordinator" / "settings"))
+        .and(with_coordinator(coordinator.clone()))
+        .and_then(get_settings);
+
+    let current_round = warp::get()
+        .and(warp::path!("v1" / "round" / "current"))
+        .and(with_coordinator(coordinator.clone()))
+        .and_then(get_current_round);
+
+    let join_queue = warp::post()
+        .and(warp::path!("v1" / "queue" / "contributor" / "join" / u64 / u64 / u64))
+        .and(authenticate())
+        .and(warp::addr::remote())
+        .and(with_coordinator(coordinator.clone()))
+        .and_then(join_queue);
+
+    let join_verifier_queue = warp::post()
+        .and(warp::path!("v1" / "queue" / "verifier" / "join"))
+        .and(authenticate())
+        .and(with_coordinator(coordinator.clone()))
+        .and_then(join_verifier_queue);
+
+    let heartbeat = warp::post()
+        .and(warp::path!("v1" / "contributor" / "heartbeat"))
+        .and(authenticate())
+        .and(with_coordinator(coordinator.clone()))
+        .and_then(heartbeat);
+
+    let contributor_status = warp::post()
+        .and(warp::path!("v1" / "contributor" / "status"))
+        .and(authenticate())
+        .and(with_coordinator(coordinator.clone()))
+        .and_then(get_contributor_status);
+
+    let try_lock = warp::post()
+        .and(warp::path!("v1" / "contributor" / "try_lock"))
+        .and(authenticate())
+        .and(with_coordinator(coordinator.clone()))
+        .and_then(try_lock);
+
+    let try_contribute = warp::post()
+        .and(warp::path!("v1" / "contributor" / "try_contribute" / u64))
+        .and(authenticate())
+        .and(with_coordinator(coordinator.clone()))
+        .and_then(try_contribute);
+
+    let download_challenge = warp::get()
+        .and(warp::path!("v1" / "download" / "challenge" / u64 / u64))
+        .and(authenticate())
+        .and(with_coordinator(coordinator.clone()))
+        .and_then(download_challenge);
+
+    let upload_response = warp::post()
+        .and(warp::path!("v1" / "upload" / "response" / u64 / u64))
+        .and(authenticate())
+        .and(warp::body::bytes())
+        .and(with_coordinator(coordinator.clone()))
+        .and_then(upload_response);
+
+    let get_task = warp::post()
+        .and(warp::path!("v1" / "verifier" / "get_task"))
+        .and(authenticate())
+        .and(with_coordinator(coordinator.clone()))
+        .and_then(get_task);
+
+    let download_response = warp::get()
+        .and(warp::path!("v1" / "download" / "response" / u64 / u64))
+        .and(authenticate())
+        .and(with_coordinator(coordinator.clone()))
+        .and_then(download_response);
+
+    let download_verifier_challenge = warp::get()
+        .and(warp::path!("v1" / "download" / "verifier_challenge" / u64 / u64))
+        .and(authenticate())
+        .and(with_coordinator(coordinator.clone()))
+        .and_then(download_verifier_challenge);
+
+    let upload_challenge = warp::post()
+        .and(warp::path!("v1" / "upload" / "challenge" / u64 / u64))
+        .and(authenticate())
+        .and(warp::body::bytes())
+        .and(with_coordinator(coordinator))
+        .and_then(upload_challenge);
+
+    settings
+        .or(current_round)
+        .or(join_queue)
+        .or(join_verifier_queue)
+        .or(heartbeat)
+        .or(contributor_status)
+        .or(try_lock)
+        .or(try_contribute)
+        .or(download_challenge)
+        .or(upload_response)
+        .or(get_task)
+        .or(download_response)
+        .or(download_verifier_challenge)
+        .or(upload_challenge)
+        .recover(handle_rejection)
+}
+
+fn with_coordinator(
+    coordinator: SharedCoordinator,
+) -> impl Filter<Extract = (SharedCoordinator,), Error = Infallible> + Clone {
+    warp::any().map(move || coordinator.clone())
+}
+
+/// Extracts the Aleo address of the sender of the request,
+/// if the authorization header is valid for the request method and path.
+fn authenticate() -> impl Filter<Extract = (String,), Error = Rejection> + Clone {
+    warp::method()
+        .and(warp::path::full())
+        .and(warp::header::<String>("authorization"))
+        .and_then(|method: Method, path: FullPath, authorization: String| async move {
+            verify_authorization(&method, path.as_str(), &authorization).map_err(warp::reject::custom)
+        })
+}
+
+///
+/// Verifies the given authorization header of the form `Aleo <address>:<signature>`
+/// for the given request method and path.
+///
+/// On success, returns the address of the sender.
+///
+fn verify_authorization(method: &Method, path: &str, authorization: &str) -> Result<String, ResponseError> {
+    let (auth_type, credentials) = authorization
+        .split_once(' ')
+        .ok_or(ResponseError::Unauthorized)?;
+    if auth_type.to_lowercase() != "aleo" {
+        return Err(ResponseError::Unauthorized);
+    }
+
+    let (address, signature) = credentials.split_once(':').ok_or(ResponseError::Unauthorized)?;
+
+    // Construct the message that is signed.
+    let message = format!("{} {}", method.as_str().to_lowercase(), path.to_lowercase());
+    trace!("Authenticating {} for {:?}", address, message);
+
+    match Aleo.verify(address, &message, signature) {
+        true => Ok(address.to_string()),
+        false => Err(ResponseError::Unauthorized),
+    }
+}
+
+async fn get_settings(coordinator: SharedCoordinator) -> Result<impl Reply, Rejection> {
+    let settings = public_settings(coordinator.read().await.environment());
+    Ok(warp::reply::json(&settings))
+}
+
+async fn get_current_round(coordinator: SharedCoordinator) -> Result<impl Reply, Rejection> {
+    let round = coordinator.read().await.current_round().map_err(reject)?;
+    Ok(warp::reply::json(&round))
+}
+
+async fn join_queue(
+    major: u64,
+    minor: u64,
+    patch: u64,
+    address: String,
+    remote: Option<SocketAddr>,
+    coordinator: SharedCoordinator,
+) -> Result<impl Reply, Rejection> {
+    let participant = Participant::new_contributor(&address);
+    info!(
+        "{} is joining the queue with contributor version {}.{}.{}",
+        participant, major, minor, patch
+    );
+
+    let mut coordinator = coordinator.write().await;
+    if coordinator.is_queue_contributor(&participant) {
+        return Ok(warp::reply::json(&true));
+    }
+
+    coordinator
+        .add_to_queue(
+            participant,
+            remote.map(|remote| remote.ip()),
+            DEFAULT_RELIABILITY_SCORE,
+        )
+        .map_err(reject)?;
+
+    Ok(warp::reply::json(&true))
+}
+
+async fn join_verifier_queue(address: String, coordinator: SharedCoordinator) -> Result<impl Reply, Rejection> {
+    // Verifiers are not queued, and are assigned tasks as long as they are coordinator verifiers.
+    let participant = Participant::new_verifier(&address);
+    match coordinator.read().await.is_coordinator_verifier(&participant) {
+        true => Ok(warp::reply::json(&true)),
+        false => Err(reject(CoordinatorError::ParticipantUnauthorized)),
+    }
+}
+
+async fn heartbeat(address: String, coordinator: SharedCoordinator) -> Result<impl Reply, Rejection> {
+    let participant = Participant::new_contributor(&address);
+    coordinator.write().await.heartbeat(&participant).map_err(reject)?;
+    Ok(StatusCode::OK)
+}
+
+async fn get_contributor_status(address: String, coordinator: SharedCoordinator) -> Result<impl Reply, Rejection> {
+    let participant = Participant::new_contributor(&address);
+    let coordinator = coordinator.read().await;
+
+    let status = if coordinator.is_queue_contributor(&participant) {
+        let queue = coordinator.queue_contributors();
+        let position = queue
+            .iter()
+            .position(|(queue_participant, _)| queue_participant == &participant)
+            .unwrap_or_default();
+        ContributorStatus::Queue(position as u64 + 1, queue.len() as u64)
+    } else if coordinator.is_current_contributor(&participant) {
+        ContributorStatus::Round
+    } else if coordinator.is_finished_contributor(&participant) {
+        ContributorStatus::Finished
+    } else {
+        ContributorStatus::Other
+    };
+
+    Ok(warp::reply::json(&status))
+}
+
+async fn try_lock(address: String, coordinator: SharedCoordinator) -> Result<impl Reply, Rejection> {
+    let participant = Participant::new_contributor(&address);
+    let (chunk_id, locked_locators) = coordinator.write().await.try_lock(&participant).map_err(reject)?;
+
+    let coordinator = coordinator.read().await;
+    let path = |locator: ContributionLocator| -> Result<String, Rejection> {
+        Ok(coordinator
+            .locator_to_path(Locator::ContributionFile(locator))
+            .map_err(reject)?
+            .to_string())
+    };
+
+    let response = LockResponse {
+        chunk_id,
+        contribution_id: locked_locators.current_contribution().contribution_id(),
+        locked: true,
+        participant_id: participant.to_string(),
+        previous_response_locator: path(locked_locators.previous_contribution())?,
+        challenge_locator: path(locked_locators.current_contribution())?,
+        response_locator: path(locked_locators.next_contribution())?,
+        response_chunk_id: locked_locators.next_contribution().chunk_id(),
+        response_contribution_id: locked_locators.next_contribution().contribution_id(),
+    };
+
+    Ok(warp::reply::json(&response))
+}
+
+async fn try_contribute(chunk_id: u64, address: String, coordinator: SharedCoordinator) -> Result<impl Reply, Rejection> {
+    let participant = Participant::new_contributor(&address);
+    let locator = coordinator
+        .write()
+        .await
+        .try_contribute(&participant, chunk_id)
+        .map_err(reject)?;
+
+    debug!(
+        "{} contributed to chunk {} contribution {}",
+        participant,
+        chunk_id,
+        locator.contribution_id()
+    );
+
+    Ok(StatusCode::OK)
+}
+
+async fn download_challenge(
+    chunk_id: u64,
+    contribution_id: u64,
+    address: String,
+    coordinator: SharedCoordinator,
+) -> Result<impl Reply, Rejection> {
+    let participant = Participant::new_contributor(&address);
+    let locator = {
+        let coordinator = coordinator.read().await;
+        let round = coordinator.current_round().map_err(reject)?;
+
+        // Check that the contributor holds the lock on the requested chunk.
+        if !round.is_chunk_locked_by(chunk_id, &participant) {
+            return Err(reject(CoordinatorError::ChunkNotLockedOrByWrongParticipant));
+        }
+
+        ContributionLocator::new(round.round_height(), chunk_id, contribution_id, true)
+    };
+
+    stream_contribution(&coordinator, locator).await
+}
+
+async fn upload_response(
+    chunk_id: u64,
+    contribution_id: u64,
+    address: String,
+    body: Bytes,
+    coordinator: SharedCoordinator,
+) -> Result<impl Reply, Rejection> {
+    let participant = Participant::new_contributor(&address);
+    let upload = Upload::parse(&body, false)?;
+
+    let mut coordinator = coordinator.write().await;
+    let round = coordinator.current_round().map_err(reject)?;
+
+    // Check that the contributor holds the lock on the uploaded chunk.
+    if !round.is_chunk_locked_by(chunk_id, &participant) {
+        return Err(reject(CoordinatorError::ChunkNotLockedOrByWrongParticipant));
+    }
+
+    // Check that the upload is the next contribution of the locked chunk.
+    let next_contribution_id = round
+        .chunk(chunk_id)
+        .and_then(|chunk| chunk.next_contribution_id(round.expected_number_of_contributions()))
+        .map_err(reject)?;
+    if contribution_id != next_contribution_id {
+        return Err(reject(CoordinatorError::ContributionIdMismatch));
+    }
+
+    // Check the contribution file signature before anything is stored.
+    coordinator
+        .check_contribution_file_signature(&participant, &upload.signature)
+        .map_err(reject)?;
+
+    let round_height = round.round_height();
+    coordinator
+        .write_contribution_file_signature(
+            ContributionSignatureLocator::new(round_height, chunk_id, contribution_id, false),
+            upload.signature,
+        )
+        .map_err(reject)?;
+    coordinator
+        .write_contribution(
+            ContributionLocator::new(round_height, chunk_id, contribution_id, false),
+            upload.contribution.to_vec(),
+        )
+        .map_err(reject)?;
+
+    Ok(StatusCode::OK)
+}
+
+async fn get_task(address: String, coordinator: SharedCoordinator) -> Result<impl Reply, Rejection> {
+    let participant = Participant::new_verifier(&address);
+    let mut coordinator = coordinator.write().await;
+
+    // Fetch a pending verification assigned to this verifier, if one exists.
+    let task = coordinator
+        .get_pending_verifications()
+        .iter()
+        .filter(|(_, verifier)| *verifier == &participant)
+        .map(|(task, _)| task.clone())
+        .min_by_key(|task| (task.chunk_id(), task.contribution_id()));
+
+    let task = match task {
+        Some(task) => task,
+        None => return Ok(warp::reply::json(&Option::<AssignedTask>::None)),
+    };
+
+    // Initialize the next challenge file and its contribution file signature.
+    let locators = coordinator
+        .get_chunk_locators_for_verifier(&participant, task.chunk_id(), task.contribution_id())
+        .map_err(reject)?;
+    match coordinator.initialize_verifier_response_files(&participant, task.chunk_id(), &locators) {
+        Ok(()) | Err(CoordinatorError::StorageLocatorAlreadyExists) => {}
+        Err(error) => return Err(reject(error)),
+    }
+
+    let assigned_task = AssignedTask {
+        round_id: coordinator.current_round_height().map_err(reject)?,
+        chunk_id: task.chunk_id(),
+        contribution_id: task.contribution_id(),
+    };
+
+    Ok(warp::reply::json(&Some(assigned_task)))
+}
+
+async fn download_response(
+    chunk_id: u64,
+    contribution_id: u64,
+    address: String,
+    coordinator: SharedCoordinator,
+) -> Result<impl Reply, Rejection> {
+    let participant = Participant::new_verifier(&address);
+    let locator = coordinator
+        .read()
+        .await
+        .get_chunk_locators_for_verifier(&participant, chunk_id, contribution_id)
+        .map_err(reject)?
+        .current_contribution();
+
+    stream_contribution(&coordinator, locator).await
+}
+
+async fn download_verifier_challenge(
+    chunk_id: u64,
+    contribution_id: u64,
+    address: String,
+    coordinator: SharedCoordinator,
+) -> Result<impl Reply, Rejection> {
+    let participant = Participant::new_verifier(&address);
+    let locator = coordinator
+        .read()
+        .await
+        .get_chunk_locators_for_verifier(&participant, chunk_id, contribution_id)
+        .map_err(reject)?
+        .previous_contribution();
+
+    stream_contribution(&coordinator, locator).await
+}
+
+async fn upload_challenge(
+    chunk_id: u64,
+    contribution_id: u64,
+    address: String,
+    body: Bytes,
+    coordinator: SharedCoordinator,
+) -> Result<impl Reply, Rejection> {
+    let participant = Participant::new_verifier(&address);
+    let upload = Upload::parse(&body, true)?;
+
+    let mut coordinator = coordinator.write().await;
+    let locators = coordinator
+        .get_chunk_locators_for_verifier(&participant, chunk_id, contribution_id)
+        .map_err(reject)?;
+    coordinator
+        .check_contribution_file_signature(&participant, &upload.signature)
+        .map_err(reject)?;
+
+    coordinator
+        .write_contribution_file_signature(locators.next_contribution_file_signature(), upload.signature)
+        .map_err(reject)?;
+    coordinator
+        .write_contribution(locators.next_contribution(), upload.contribution.to_vec())
+        .map_err(reject)?;
+
+    // Apply the verification to the round.
+    coordinator
+        .try_verify(&participant, &Task::new(chunk_id, contribution_id))
+        .map_err(reject)?;
+
+    Ok(warp::reply::with_status("verified", StatusCode::OK))
+}
+
+///
+/// Streams the contribution file at the given locator from storage.
+///
+async fn stream_contribution(
+    coordinator: &SharedCoordinator,
+    locator: ContributionLocator,
+) -> Result<Response<Body>, Rejection> {
+    let reader = coordinator.read().await.read_contribution(locator).map_err(reject)?;
+    let size = reader.len();
+
+    trace!("Streaming {:?} ({} bytes)", locator, size);
+
+    // Stream the contribution from the storage reader, so that every storage backend can be served.
+    let chunks = (0..size).step_by(STREAM_CHUNK_SIZE).map(move |start| {
+        let end = std::cmp::min(start + STREAM_CHUNK_SIZE, size);
+        Ok::<_, Infallible>(Bytes::copy_from_slice(&reader[start..end]))
+    });
+
+    Response::builder()
+        .header(header::CONTENT_TYPE, "application/octet-stream")
+        .header(header::CONTENT_LENGTH, size)
+        .body(Body::wrap_stream(futures::stream::iter(chunks)))
+        .map_err(|_| warp::reject::reject())
+}
+
+///
+/// A contribution file uploaded by a participant, which is prefixed with
+/// a verifier flag, the contribution file signature, and the contribution hashes.
+///
+struct Upload<'a> {
+    signature: ContributionFileSignature,
+    contribution: &'a [u8],
+}
+
+impl<'a> Upload<'a> {
+    ///
+    /// Parses an upload of the form
+    /// `[verifier_flag, signature, challenge_hash, response_hash, (next_challenge_hash), contribution]`,
+    /// where the next challenge hash is only present for uploads from verifiers.
+    ///
+    fn parse(body: &'a [u8], is_verifier: bool) -> Result<Self, Rejection> {
+        let invalid = |reason| warp::reject::custom(ResponseError::InvalidUpload(reason));
+
+        let (verifier_flag, body) = body.split_first().ok_or_else(|| invalid("missing verifier flag"))?;
+        if *verifier_flag != is_verifier as u8 {
+            return Err(invalid("unexpected verifier flag"));
+        }
+
+        let number_of_hashes = if is_verifier { 3 } else { 2 };
+        if body.len() < SIGNATURE_SIZE + number_of_hashes * HASH_SIZE {
+            return Err(invalid("missing contribution file signature"));
+        }
+
+        let (signature, body) = body.split_at(SIGNATURE_SIZE);
+        let (challenge_hash, body) = body.split_at(HASH_SIZE);
+        let (response_hash, body) = body.split_at(HASH_SIZE);
+        let (next_challenge_hash, contribution) = match is_verifier {
+            true => {
+                let (next_challenge_hash, contribution) = body.split_at(HASH_SIZE);
+                (Some(next_challenge_hash.to_vec()), contribution)
+            }
+            false => (None, body),
+        };
+
+        let state = ContributionState::new(challenge_hash.to_vec(), response_hash.to_vec(), next_challenge_hash)
+            .map_err(reject)?;
+        let signature = ContributionFileSignature::new(hex::encode(signature), state).map_err(reject)?;
+
+        Ok(Self {
+            signature,
+            contribution,
+        })
+    }
+}
+
+fn reject(error: CoordinatorError) -> Rejection {
+    warp::reject::custom(ResponseError::from(error))
+}
+
+async fn handle_rejection(rejection: Rejection) -> Result<impl Reply, Infallible> {
+    let (status, message) = if rejection.is_not_found() {
+        (StatusCode::NOT_FOUND, "Not found".to_string())
+    } else if let Some(error) = rejection.find::<ResponseError>() {
+        match error {
+            ResponseError::Unauthorized => (StatusCode::UNAUTHORIZED, "Unauthorized".to_string()),
+            ResponseError::InvalidUpload(reason) => (StatusCode::BAD_REQUEST, format!("Invalid upload - {}", reason)),
+            ResponseError::Coordinator(error) => (StatusCode::BAD_REQUEST, format!("{:?}", error)),
+            ResponseError::IOError(error) => (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()),
+        }
+    } else if rejection.find::<warp::reject::MissingHeader>().is_some() {
+        (StatusCode::UNAUTHORIZED, "Missing authorization header".to_string())
+    } else if rejection.find::<warp::reject::MethodNotAllowed>().is_some() {
+        (StatusCode::METHOD_NOT_ALLOWED, "Method not allowed".to_string())
+    } else {
+        error!("Unhandled rejection {:?}", rejection);
+        (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error".to_string())
+    };
+
+    if status != StatusCode::NOT_FOUND {
+        warn!("Request failed with {} - {}", status, message);
+    }
+
+    Ok(warp::reply::with_status(message, status))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        authentication::Dummy,
+        environment::{Parameters, Production},
+        storage::Object,
+        testing::{initialize_test_environment, TEST_ENVIRONMENT_3},
+    };
+
+    use serial_test::serial;
+    use std::net::{IpAddr, Ipv4Addr};
+
+    /// Returns a contributor upload of the given contribution, signed with the dummy signature scheme.
+    fn contributor_upload(contribution: Vec<u8>, is_signed: bool) -> Bytes {
+        let state = ContributionState::new(vec![2u8; HASH_SIZE], vec![3u8; HASH_SIZE], None).unwrap();
+        let signature = match is_signed {
+            true => hex::decode(Dummy.sign("", &serde_json::to_string(&state).unwrap()).unwrap()).unwrap(),
+            false => vec![1u8; SIGNATURE_SIZE],
+        };
+        let body = [
+            vec![0u8],
+            signature,
+            vec![2u8; HASH_SIZE],
+            vec![3u8; HASH_SIZE],
+            contribution,
+        ]
+        .concat();
+        Bytes::from(body)
+    }
+
+    #[test]
+    fn test_verify_authorization_invalid_type() {
+        let method = Method::GET;
+        let path = "/v1/download/challenge/0/1";
+
+        assert!(verify_authorization(&method, path, "TEST aleo1address:signature").is_err());
+        assert!(verify_authorization(&method, path, "Aleo").is_err());
+        assert!(verify_authorization(&method, path, "Aleo aleo1address").is_err());
+    }
+
+    #[test]
+    fn test_verify_authorization() {
+        const TEST_VIEW_KEY: &str = "AViewKey1cWY7CaSDuwAEXoFki7Z1JELj7ksum8JxfZGpsPLHJACx";
+        const TEST_ADDRESS: &str = "aleo1en3lu60j0gcetvnpscvzwcxgujj069tlr3qlrm7y5kcrncxu3y8qva8p7k";
+
+        let path = "/v1/contributor/try_lock";
+        let signature = Aleo.sign(TEST_VIEW_KEY, &format!("post {}", path)).unwrap();
+        let authorization = format!("Aleo {}:{}", TEST_ADDRESS, signature);
+
+        assert_eq!(
+            TEST_ADDRESS,
+            verify_authorization(&Method::POST, path, &authorization).unwrap()
+        );
+        assert!(verify_authorization(&Method::GET, path, &authorization).is_err());
+    }
+
+    #[test]
+    fn test_parse_upload() {
+        let contribution = vec![7u8; 32];
+        let body = [
+            vec![0u8],
+            vec![1u8; SIGNATURE_SIZE],
+            vec![2u8; HASH_SIZE],
+            vec![3u8; HASH_SIZE],
+            contribution.clone(),
+        ]
+        .concat();
+
+        let upload = Upload::parse(&body, false).unwrap();
+        assert_eq!(contribution, upload.contribution);
+        assert_eq!(hex::encode(vec![2u8; HASH_SIZE]), upload.signature.get_challenge_hash());
+        assert_eq!(hex::encode(vec![3u8; HASH_SIZE]), upload.signature.get_response_hash());
+        assert!(upload.signature.get_next_challenge_hash().is_none());
+
+        // A contributor upload is not a valid verifier upload.
+        assert!(Upload::parse(&body, true).is_err());
+        // A truncated upload is invalid.
+        assert!(Upload::parse(&body[..100], false).is_err());
+    }
+
+    #[test]
+    fn test_public_settings() {
+        let setup = |parameters| public_settings(&Production::from(parameters).into()).setup;
+
+        assert!(matches!(setup(Parameters::AleoInner), SetupKind::Inner));
+        assert!(matches!(setup(Parameters::AleoOuter), SetupKind::Outer));
+        assert!(matches!(setup(Parameters::AleoUniversal), SetupKind::Universal));
+        assert!(matches!(setup(Parameters::AleoKzg), SetupKind::Kzg));
+        assert!(matches!(setup(Parameters::Bls12_381), SetupKind::Bls12_381));
+        assert!(matches!(
+            public_settings(&TEST_ENVIRONMENT_3).setup,
+            SetupKind::Development
+        ));
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_upload_response() {
+        let environment = initialize_test_environment(&TEST_ENVIRONMENT_3);
+        let mut coordinator = Coordinator::new(environment.clone(), Arc::new(Dummy)).unwrap();
+        coordinator.initialize().unwrap();
+
+        // Lock a chunk of round 1 for the contributor.
+        let address = "test-contributor";
+        let participant = Participant::new_contributor(address);
+        coordinator
+            .add_to_queue(participant.clone(), Some(IpAddr::V4(Ipv4Addr::LOCALHOST)), 10)
+            .unwrap();
+        coordinator.update().unwrap();
+        let (chunk_id, locked_locators) = coordinator.try_lock(&participant).unwrap();
+        let contribution_id = locked_locators.next_contribution().contribution_id();
+        let size = Object::contribution_file_size(&environment, chunk_id, false) as usize;
+        let coordinator: SharedCoordinator = Arc::new(RwLock::new(coordinator));
+
+        // The upload must be the next contribution of the locked chunk.
+        let upload = contributor_upload(vec![0u8; size], true);
+        let response = upload_response(
+            chunk_id,
+            contribution_id + 1,
+            address.to_string(),
+            upload.clone(),
+            coordinator.clone(),
+        );
+        assert!(response.await.is_err());
+
+        // The contribution file signature must be valid.
+        let unsigned_upload = contributor_upload(vec![0u8; size], false);
+        let response = upload_response(
+            chunk_id,
+            contribution_id,
+            address.to_string(),
+            unsigned_upload,
+            coordinator.clone(),
+        );
+        assert!(response.await.is_err());
+
+        let response = upload_response(chunk_id, contribution_id, address.to_string(), upload, coordinator);
+        assert!(response.await.is_ok());
+    }
+}
@@ -1,6 +1,5 @@
 use crate::{
     environment::Environment,
-    objects::{ContributionFileSignature, Round},
     storage::{
         ContributionLocator,
         ContributionSignatureLocator,
@@ -8,8 +7,10 @@ use crate::{
         Object,
         ObjectReader,
         ObjectWriter,
+        Storage,
         StorageLocator,
-        StorageObject,
+        StorageReader,
+        StorageWriter,
     },
     CoordinatorError,
     CoordinatorState,
@@ -58,7 +59,7 @@ impl Disk {
         };
 
         // Create the coordinator state locator if it does not exist yet.
-        if !storage.exists(&Locator::CoordinatorState) {
+        if !storage.exists(&Locator::CoordinatorState)? {
             storage.insert(
                 Locator::CoordinatorState,
                 Object::CoordinatorState(CoordinatorState::new(environment.clone())),
@@ -69,13 +70,79 @@ impl Disk {
         Ok(storage)
     }
 
+    /// Clears all files related to a round - used for round reset purposes.
+    fn clear_round_files(&mut self, round_height: u64) {
+        // Let's first fully clear any files in the next round - these will be
+        // verifications and represent the initial challenges.
+        let next_round_dir = self.resolver.round_directory(round_height + 1);
+        self.clear_dir_files(next_round_dir.into(), true);
+
+        // Now, let's clear all the contributions made on this round.
+        let round_dir = self.resolver.round_directory(round_height);
+        self.clear_dir_files(round_dir.into(), false);
+    }
+
+    /// Removes all files in the given directory, recursively, except for
+    /// the round states and (optionally) the initial contributions.
+    fn clear_dir_files(&mut self, path: PathBuf, delete_initial_contribution: bool) {
+        let entries = match fs::read_dir(path.as_path()) {
+            Ok(entries) => entries,
+            Err(e) => {
+                tracing::warn!("Could not read directory at {:?} - {:?}", path, e);
+                return;
+            }
+        };
+
+        for entry in entries {
+            if let Err(e) = entry {
+                tracing::error!("Found erroneous entry - {:?}", e);
+                continue;
+            }
+
+            let entry = entry.unwrap();
+
+            match entry.path().is_dir() {
+                true => self.clear_dir_files(entry.path(), delete_initial_contribution),
+                false => {
+                    let file_path = match entry.path().to_str() {
+                        Some(file_path) => file_path.to_owned(),
+                        None => {
+                            tracing::error!("Could not turn fs entry into file path");
+                            continue;
+                        }
+                    };
+
+                    if !delete_initial_contribution && file_path.contains("contribution_0")
+                        || file_path.contains("state.json")
+                    {
+                        continue;
+                    }
+
+                    let locator = match self.resolver.to_locator(&LocatorPath::new(file_path)) {
+                        Ok(locator) => locator,
+                        Err(e) => {
+                            tracing::error!("Could not turn file path into locator - {:?}", e);
+                            continue;
+                        }
+                    };
+
+                    if let Err(e) = self.remove(&locator) {
+                        tracing::error!("Could not remove locator - {:?}", e);
+                    }
+                }
+            };
+        }
+    }
+}
+
+impl Storage for Disk {
     /// Initializes the location corresponding to the given locator.
-    pub fn initialize(&mut self, locator: Locator, size: u64) -> Result<(), CoordinatorError> {
+    fn initialize(&mut self, locator: Locator, size: u64) -> Result<(), CoordinatorError> {
         let locator_path = self.to_path(&locator)?;
         trace!("Initializing {:?}", locator_path);
 
         // Check that the locator does not already exist in storage.
-        if self.exists(&locator) {
+        if self.exists(&locator)? {
             error!(
                 "Locator {:?} in call to initialize() already exists in storage.",
                 locator_path
@@ -104,29 +171,24 @@ impl Disk {
     }
 
     /// Checks whether the given locator exists in the storage or not.
-    pub fn exists(&self, locator: &Locator) -> bool {
-        let path = match self.to_path(locator) {
-            Ok(path) => path,
-            Err(e) => {
-                error!("Could not convert locator to path - {}", e);
-                return false;
-            }
-        };
+    fn exists(&self, locator: &Locator) -> Result<bool, CoordinatorError> {
+        let path = self.to_path(locator)?;
 
         trace!("Ensuring that {} exists in storage", path);
         match fs::metadata(path) {
-            Ok(metadata) => metadata.is_file(),
-            Err(_) => false,
+            Ok(metadata) => Ok(metadata.is_file()),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(false),
+            Err(error) => Err(error.into()),
         }
     }
 
     /// Returns a copy of an object at the given locator in storage, if it exists.
-    pub fn get(&self, locator: &Locator) -> Result<Object, CoordinatorError> {
+    fn get(&self, locator: &Locator) -> Result<Object, CoordinatorError> {
         let path = self.to_path(locator)?;
         trace!("Fetching {}", path);
 
         // Check that the given locator exists in storage.
-        if !self.exists(locator) {
+        if !self.exists(locator)? {
             error!("Locator missing in call to get() in storage - {:?}", locator);
             return Err(CoordinatorError::StorageLocatorMissing);
         }
@@ -134,91 +196,18 @@ impl Disk {
         // read the file to a byte array
         let file_bytes = fs::read(path)?;
 
-        let object = match locator {
-            Locator::CoordinatorState => {
-                let coordinator_state: CoordinatorState = serde_json::from_slice(&file_bytes)?;
-                Ok(Object::CoordinatorState(coordinator_state))
-            }
-            Locator::RoundHeight => {
-                let round_height: u64 = serde_json::from_slice(&file_bytes)?;
-                Ok(Object::RoundHeight(round_height))
-            }
-            Locator::RoundState { round_height: _ } => {
-                let round: Round = serde_json::from_slice(&file_bytes)?;
-                Ok(Object::RoundState(round))
-            }
-            Locator::RoundFile { round_height } => {
-                // Check that the round size is correct.
-                let expected_size = Object::round_file_size(&self.environment);
-                let found_size = file_bytes.len() as u64;
-                debug!("Round {} filesize is {}", round_height, found_size);
-                if found_size == 0 || expected_size != found_size {
-                    error!("Round file size should be {} but found {}", expected_size, found_size);
-                    return Err(CoordinatorError::RoundFileSizeMismatch.into());
-                }
-
-                Ok(Object::RoundFile(file_bytes))
-            }
-            Locator::ContributionFile(contribution_locator) => {
-                // Check that the contribution size is correct.
-                let expected_size = Object::contribution_file_size(
-                    &self.environment,
-                    contribution_locator.chunk_id(),
-                    contribution_locator.is_verified(),
-                );
-                let found_size = file_bytes.len() as u64;
-                debug!(
-                    "Round {} chunk {} filesize is {}",
-                    contribution_locator.round_height(),
-                    contribution_locator.chunk_id(),
-                    found_size
-                );
-                if found_size == 0 || expected_size != found_size {
-                    error!(
-                        "Contribution file size should be {} but found {}",
-                        expected_size, found_size
-                    );
-                    return Err(CoordinatorError::ContributionFileSizeMismatch.into());
-                }
-
-                let mut contribution_file: Vec<u8> = Vec::with_capacity(expected_size as usize);
-                contribution_file.write_all(&file_bytes)?;
-                Ok(Object::ContributionFile(contribution_file))
-            }
-            Locator::ContributionFileSignature(contribution_locator) => {
-                // Check that the contribution file signature size is correct.
-                let expected_size = Object::contribution_file_signature_size(contribution_locator.is_verified());
-                let found_size = file_bytes.len() as u64;
-                debug!(
-                    "Round {} chunk {} contribution {} signature filesize is {}",
-                    contribution_locator.round_height(),
-                    contribution_locator.chunk_id(),
-                    contribution_locator.contribution_id(),
-                    found_size
-                );
-                if found_size == 0 || expected_size != found_size {
-                    error!(
-                        "Contribution signature file size should be {} but found {}",
-                        expected_size, found_size
-                    );
-                    return Err(CoordinatorError::ContributionSignatureFileSizeMismatch.into());
-                }
-
-                let contribution_file_signature: ContributionFileSignature = serde_json::from_slice(&file_bytes)?;
-                Ok(Object::ContributionFileSignature(contribution_file_signature))
-            }
-        };
+        let object = Object::from_bytes(&self.environment, locator, file_bytes);
 
         trace!("Fetched {}", self.to_path(locator)?);
         object
     }
 
     /// Inserts a new object at the given locator into storage, if it does not exist.
-    pub fn insert(&mut self, locator: Locator, object: Object) -> Result<(), CoordinatorError> {
+    fn insert(&mut self, locator: Locator, object: Object) -> Result<(), CoordinatorError> {
         trace!("Inserting {}", self.to_path(&locator)?);
 
         // Check that the given locator does not exist in storage.
-        if self.exists(&locator) {
+        if self.exists(&locator)? {
             error!("Locator in call to insert() already exists in storage.");
             return Err(CoordinatorError::StorageLocatorAlreadyExists);
         }
@@ -234,12 +223,12 @@ impl Disk {
     }
 
     /// Updates an existing object for the given locator in storage, if it exists.
-    pub fn update(&mut self, locator: &Locator, object: Object) -> Result<(), CoordinatorError> {
+    fn update(&mut self, locator: &Locator, object: Object) -> Result<(), CoordinatorError> {
         let path = self.to_path(locator)?;
         trace!("Updating {}", path);
 
         // Check that the given locator exists in storage.
-        if !self.exists(locator) {
+        if !self.exists(locator)? {
             error!("Locator missing in call to update() in storage.");
             return Err(CoordinatorError::StorageLocatorMissing);
         }
@@ -255,7 +244,7 @@ impl Disk {
     }
 
     /// Copies an object from the given source locator to the given destination locator.
-    pub fn copy(&mut self, source_locator: &Locator, destination_locator: &Locator) -> Result<(), CoordinatorError> {
+    fn copy(&mut self, source_locator: &Locator, destination_locator: &Locator) -> Result<(), CoordinatorError> {
         trace!(
             "Copying from A to B\n\n\tA: {}\n\tB: {}\n",
             self.to_path(source_locator)?,
@@ -263,13 +252,13 @@ impl Disk {
         );
 
         // Check that the given source locator exists in storage.
-        if !self.exists(source_locator) {
+        if !self.exists(source_locator)? {
             error!("Source locator missing in call to copy() in storage.");
             return Err(CoordinatorError::StorageLocatorMissing);
         }
 
         // Check that the given destination locator does NOT exist in storage.
-        if self.exists(destination_locator) {
+        if self.exists(destination_locator)? {
             error!("Destination locator in call to copy() already exists in storage.");
             return Err(CoordinatorError::StorageLocatorAlreadyExists);
         }
@@ -288,12 +277,12 @@ impl Disk {
     }
 
     /// Removes the object corresponding to the given locator from storage.
-    pub fn remove(&mut self, locator: &Locator) -> Result<(), CoordinatorError> {
+    fn remove(&mut self, locator: &Locator) -> Result<(), CoordinatorError> {
         let path = self.to_path(locator)?;
         trace!("Removing {}", path);
 
         // Check that the locator exists in storage.
-        if !self.exists(&locator) {
+        if !self.exists(&locator)? {
             error!("Locator in call to remove() doesn't exist in storage.");
             return Err(CoordinatorError::StorageLocatorMissing);
         }
@@ -308,12 +297,12 @@ impl Disk {
     }
 
     /// Returns the size of the object stored at the given locator.
-    pub fn size(&self, locator: &Locator) -> Result<u64, CoordinatorError> {
+    fn size(&self, locator: &Locator) -> Result<u64, CoordinatorError> {
         let path = self.to_path(locator)?;
         trace!("Fetching size of {}", path);
 
         // Check that the given locator exists in storage.
-        if !self.exists(locator) {
+        if !self.exists(locator)? {
             error!("Locator missing in call to size() in storage.");
             return Err(CoordinatorError::StorageLocatorMissing);
         }
@@ -325,77 +314,63 @@ impl Disk {
         Ok(file.metadata()?.len())
     }
 
-    /// Process a [StorageAction] which mutates the storage.
-    pub fn process(&mut self, action: StorageAction) -> Result<()> {
-        match action {
-            StorageAction::Remove(remove_action) => {
-                let locator = remove_action.try_into_locator(self)?;
-                Ok(self.remove(&locator)?)
-            }
-            StorageAction::Update(update_action) => Ok(self.update(&update_action.locator, update_action.object)?),
-            StorageAction::ClearRoundFiles(round_height) => Ok(self.clear_round_files(round_height)),
+    /// Returns an object reader for the given locator.
+    #[inline]
+    fn reader(&self, locator: &Locator) -> Result<StorageReader, CoordinatorError> {
+        let path = self.to_path(&locator)?;
+
+        // Check that the locator exists in storage.
+        if !self.exists(&locator)? {
+            error!("Locator {} missing in call to reader() in storage.", path);
+            return Err(CoordinatorError::StorageLocatorMissing);
         }
-    }
 
-    /// Clears all files related to a round - used for round reset purposes.
-    fn clear_round_files(&mut self, round_height: u64) {
-        // Let's first fully clear any files in the next round - these will be
-        // verifications and represent the initial challenges.
-        let next_round_dir = self.resolver.round_directory(round_height + 1);
-        self.clear_dir_files(next_round_dir.into(), true);
+        let file = OpenOptions::new().read(true).open(path)?;
 
-        // Now, let's clear all the contributions made on this round.
-        let round_dir = self.resolver.round_directory(round_height);
-        self.clear_dir_files(round_dir.into(), false);
+        // Load the file into memory.
+        let mut data = vec![];
+        file.file()
+            .read_to_end(&mut data)
+            .map_err(|e| CoordinatorError::IOError(e))?;
+
+        // Check that the round or contribution size is correct.
+        Object::check_size(&self.environment, locator, data.len() as u64)?;
+
+        Ok(StorageReader::new(DiskObjectReader { data }))
     }
 
-    fn clear_dir_files(&mut self, path: PathBuf, delete_initial_contribution: bool) {
-        let entries = match fs::read_dir(path.as_path()) {
-            Ok(entries) => entries,
-            Err(e) => {
-                tracing::warn!("Could not read directory at {:?} - {:?}", path, e);
-                return;
-            }
-        };
+    /// Returns an object writer for the given locator.
+    #[inline]
+    fn writer(&self, locator: &Locator) -> Result<StorageWriter, CoordinatorError> {
+        let path = self.to_path(&locator)?;
 
-        for entry in entries {
-            if let Err(e) = entry {
-                tracing::error!("Found erroneous entry - {:?}", e);
-                continue;
-            }
+        // Check that the locator exists in storage.
+        if !self.exists(&locator)? {
+            error!("Locator {} missing in call to writer() in storage.", path);
+            return Err(CoordinatorError::StorageLocatorMissing);
+        }
 
-            let entry = entry.unwrap();
+        let file = OpenOptions::new().read(true).write(true).open(path)?;
 
-            match entry.path().is_dir() {
-                true => self.clear_dir_files(entry.path(), delete_initial_contribution),
-                false => {
-                    let file_path = match entry.path().to_str() {
-                        Some(file_path) => file_path.to_owned(),
-                        None => {
-                            tracing::error!("Could not turn fs entry into file path");
-                            continue;
-                        }
-                    };
+        // Load the file into memory.
+        let memmap = unsafe { MmapOptions::new().map_mut(&file.file())? };
 
-                    if !delete_initial_contribution && file_path.contains("contribution_0")
-                        || file_path.contains("state.json")
-                    {
-                        continue;
-                    }
+        // Check that the round or contribution size is correct.
+        debug!("File size of {} is {}", self.to_path(locator)?, memmap.len());
+        Object::check_size(&self.environment, locator, memmap.len() as u64)?;
 
-                    let locator = match self.resolver.to_locator(&LocatorPath::new(file_path)) {
-                        Ok(locator) => locator,
-                        Err(e) => {
-                            tracing::error!("Could not turn file path into locator - {:?}", e);
-                            continue;
-                        }
-                    };
+        Ok(StorageWriter::new(DiskObjectWriter { _file: file, memmap }))
+    }
 
-                    if let Err(e) = self.remove(&locator) {
-                        tracing::error!("Could not remove locator - {:?}", e);
-                    }
-                }
-            };
+    /// Process a [StorageAction] which mutates the storage.
+    fn process(&mut self, action: StorageAction) -> anyhow::Result<()> {
+        match action {
+            StorageAction::Remove(remove_action) => {
+                let locator = remove_action.try_into_locator(self)?;
+                Ok(self.remove(&locator)?)
+            }
+            StorageAction::Update(update_action) => Ok(self.update(&update_action.locator, update_action.object)?),
+            StorageAction::ClearRoundFiles(round_height) => Ok(self.clear_round_files(round_height)),
         }
     }
 }
@@ -463,133 +438,14 @@ impl ObjectWriter for DiskObjectWriter {
     }
 }
 
-impl StorageObject for Disk {
-    type Reader = DiskObjectReader;
-    type Writer = DiskObjectWriter;
-
-    /// Returns an object reader for the given locator.
-    #[inline]
-    fn reader<'a>(&self, locator: &Locator) -> Result<Self::Reader, CoordinatorError> {
-        let path = self.to_path(&locator)?;
-
-        // Check that the locator exists in storage.
-        if !self.exists(&locator) {
-            error!("Locator {} missing in call to reader() in storage.", path);
-            return Err(CoordinatorError::StorageLocatorMissing);
-        }
-
-        let file = OpenOptions::new().read(true).open(path)?;
-
-        // Load the file into memory.
-        let mut data = vec![];
-        file.file()
-            .read_to_end(&mut data)
-            .map_err(|e| CoordinatorError::IOError(e))?;
-
-        match locator {
-            Locator::RoundFile { round_height } => {
-                // Check that the round size is correct.
-                let expected_size = Object::round_file_size(&self.environment);
-                let found_size = data.len() as u64;
-                debug!("Round {} filesize is {}", round_height, found_size);
-                if found_size != expected_size {
-                    error!(
-                        "Contribution file size should be {} but found {}",
-                        expected_size, found_size
-                    );
-                    return Err(CoordinatorError::RoundFileSizeMismatch.into());
-                }
-            }
-            Locator::ContributionFile(contribution_locator) => {
-                // Check that the contribution size is correct.
-                let expected_size = Object::contribution_file_size(
-                    &self.environment,
-                    contribution_locator.chunk_id(),
-                    contribution_locator.is_verified(),
-                );
-                let found_size = data.len() as u64;
-                debug!(
-                    "Round {} chunk {} filesize is {}",
-                    contribution_locator.round_height(),
-                    contribution_locator.chunk_id(),
-                    found_size
-                );
-                if found_size != expected_size {
-                    error!(
-                        "Contribution file size should be {} but found {}",
-                        expected_size, found_size
-                    );
-                    return Err(CoordinatorError::ContributionFileSizeMismatch.into());
-                }
-            }
-            _ => {}
-        }
-
-        Ok(DiskObjectReader { data })
-    }
-
-    /// Returns an object writer for the given locator.
-    #[inline]
-    fn writer(&self, locator: &Locator) -> Result<Self::Writer, CoordinatorError> {
-        let path = self.to_path(&locator)?;
-
-        // Check that the locator exists in storage.
-        if !self.exists(&locator) {
-            error!("Locator {} missing in call to writer() in storage.", path);
-            return Err(CoordinatorError::StorageLocatorMissing);
-        }
-
-        let file = OpenOptions::new().read(true).write(true).open(path)?;
-
-        // Load the file into memory.
-        let memmap = unsafe { MmapOptions::new().map_mut(&file.file())? };
-
-        match locator {
-            Locator::RoundFile { round_height: _ } => {
-                // Check that the round size is correct.
-                let expected_size = Object::round_file_size(&self.environment);
-                let found_size = memmap.len() as u64;
-                debug!("File size of {} is {}", self.to_path(locator)?, found_size);
-                if found_size != expected_size {
-                    error!(
-                        "Contribution file size should be {} but found {}",
-                        expected_size, found_size
-                    );
-                    return Err(CoordinatorError::RoundFileSizeMismatch.into());
-                }
-            }
-            Locator::ContributionFile(contribution_locator) => {
-                // Check that the contribution size is correct.
-                let expected_size = Object::contribution_file_size(
-                    &self.environment,
-                    contribution_locator.chunk_id(),
-                    contribution_locator.is_verified(),
-                );
-                let found_size = memmap.len() as u64;
-                debug!("File size of {} is {}", self.to_path(locator)?, found_size);
-                if found_size != expected_size {
-                    error!(
-                        "Contribution file size should be {} but found {}",
-                        expected_size, found_size
-                    );
-                    return Err(CoordinatorError::ContributionFileSizeMismatch.into());
-                }
-            }
-            _ => {}
-        }
-
-        Ok(DiskObjectWriter { _file: file, memmap })
-    }
-}
-
 #[derive(Debug)]
-struct DiskResolver {
+pub(crate) struct DiskResolver {
     base: String,
 }
 
 impl DiskResolver {
     #[inline]
-    fn new(base: &str) -> Self {
+    pub(crate) fn new(base: &str) -> Self {
         Self { base: base.to_string() }
     }
 }
@@ -797,7 +653,7 @@ impl StorageLocator for DiskResolver {
 impl DiskResolver {
     /// Returns the round directory for a given round height from the coordinator.
     #[inline]
-    fn round_directory(&self, round_height: u64) -> String {
+    pub(crate) fn round_directory(&self, round_height: u64) -> String {
         format!("{}/round_{}", self.base, round_height)
     }
 
@@ -0,0 +1,402 @@
+use crate::{
+    environment::Environment,
+    storage::{
+        is_cleared_by_round_reset,
+        DiskResolver,
+        Locator,
+        LocatorPath,
+        Object,
+        ObjectReader,
+        ObjectWriter,
+        Storage,
+        StorageAction,
+        StorageLocator,
+        StorageReader,
+        StorageWriter,
+    },
+    CoordinatorError,
+    CoordinatorState,
+};
+
+use std::{
+    collections::HashMap,
+    ops::{Deref, DerefMut},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+        RwLock,
+    },
+};
+use tracing::{error, trace};
+
+type Objects = Arc<RwLock<HashMap<Locator, Vec<u8>>>>;
+
+/// A storage backend which keeps all objects in memory.
+///
+/// This backend does not persist anything, and is intended for tests
+/// and short-lived coordinators which do not need to recover their state.
+#[derive(Debug)]
+pub struct Memory {
+    environment: Environment,
+    resolver: DiskResolver,
+    objects: Objects,
+}
+
+impl Memory {
+    /// Loads a new instance of `Memory`.
+    pub fn load(environment: &Environment) -> Result<Self, CoordinatorError> {
+        trace!("Loading memory storage");
+
+        let mut storage = Self {
+            environment: environment.clone(),
+            resolver: DiskResolver::new(environment.local_base_directory()),
+            objects: Default::default(),
+        };
+
+        // Create the coordinator state locator.
+        storage.insert(
+            Locator::CoordinatorState,
+            Object::CoordinatorState(CoordinatorState::new(environment.clone())),
+        )?;
+
+        trace!("Loaded memory storage");
+        Ok(storage)
+    }
+
+    /// Returns a copy of the bytes stored at the given locator.
+    fn bytes(&self, locator: &Locator) -> Result<Vec<u8>, CoordinatorError> {
+        match self
+            .objects
+            .read()
+            .expect("memory storage lock is poisoned")
+            .get(locator)
+        {
+            Some(bytes) => Ok(bytes.clone()),
+            None => {
+                error!("Locator missing in storage - {:?}", locator);
+                Err(CoordinatorError::StorageLocatorMissing)
+            }
+        }
+    }
+
+    /// Clears all objects related to a round - used for round reset purposes.
+    fn clear_round_files(&mut self, round_height: u64) {
+        self.objects
+            .write()
+            .expect("memory storage lock is poisoned")
+            .retain(|locator, _| !is_cleared_by_round_reset(locator, round_height));
+    }
+}
+
+impl Storage for Memory {
+    /// Initializes the location corresponding to the given locator.
+    fn initialize(&mut self, locator: Locator, size: u64) -> Result<(), CoordinatorError> {
+        trace!("Initializing {:?}", locator);
+
+        let mut objects = self.objects.write().expect("memory storage lock is poisoned");
+
+        // Check that the locator does not already exist in storage.
+        if objects.contains_key(&locator) {
+            error!(
+                "Locator {:?} in call to initialize() already exists in storage.",
+                locator
+            );
+            return Err(CoordinatorError::StorageLocatorAlreadyExists);
+        }
+
+        objects.insert(locator, vec![0u8; size as usize]);
+        Ok(())
+    }
+
+    /// Checks whether the given locator exists in the storage or not.
+    fn exists(&self, locator: &Locator) -> Result<bool, CoordinatorError> {
+        Ok(self
+            .objects
+            .read()
+            .expect("memory storage lock is poisoned")
+            .contains_key(locator))
+    }
+
+    /// Returns a copy of an object at the given locator in storage, if it exists.
+    fn get(&self, locator: &Locator) -> Result<Object, CoordinatorError> {
+        trace!("Fetching {:?}", locator);
+        Object::from_bytes(&self.environment, locator, self.bytes(locator)?)
+    }
+
+    /// Inserts a new object at the given locator into storage, if it does not exist.
+    fn insert(&mut self, locator: Locator, object: Object) -> Result<(), CoordinatorError> {
+        trace!("Inserting {:?}", locator);
+
+        // Initialize the new object with the object size.
+        self.initialize(locator, object.size())?;
+
+        // Insert the object at the given locator.
+        self.update(&locator, object)
+    }
+
+    /// Updates an existing object for the given locator in storage, if it exists.
+    fn update(&mut self, locator: &Locator, object: Object) -> Result<(), CoordinatorError> {
+        trace!("Updating {:?}", locator);
+
+        match self
+            .objects
+            .write()
+            .expect("memory storage lock is poisoned")
+            .get_mut(locator)
+        {
+            Some(bytes) => {
+                *bytes = object.to_bytes();
+                Ok(())
+            }
+            None => {
+                error!("Locator missing in call to update() in storage.");
+                Err(CoordinatorError::StorageLocatorMissing)
+            }
+        }
+    }
+
+    /// Copies an object from the given source locator to the given destination locator.
+    fn copy(&mut self, source_locator: &Locator, destination_locator: &Locator) -> Result<(), CoordinatorError> {
+        trace!("Copying from {:?} to {:?}", source_locator, destination_locator);
+
+        // Check that the given destination locator does NOT exist in storage.
+        if self.exists(destination_locator)? {
+            error!("Destination locator in call to copy() already exists in storage.");
+            return Err(CoordinatorError::StorageLocatorAlreadyExists);
+        }
+
+        let bytes = self.bytes(source_locator)?;
+        self.objects
+            .write()
+            .expect("memory storage lock is poisoned")
+            .insert(*destination_locator, bytes);
+        Ok(())
+    }
+
+    /// Removes the object corresponding to the given locator from storage.
+    fn remove(&mut self, locator: &Locator) -> Result<(), CoordinatorError> {
+        trace!("Removing {:?}", locator);
+
+        match self
+            .objects
+            .write()
+            .expect("memory storage lock is poisoned")
+            .remove(locator)
+        {
+            Some(_) => Ok(()),
+            None => {
+                error!("Locator in call to remove() doesn't exist in storage.");
+                Err(CoordinatorError::StorageLocatorMissing)
+            }
+        }
+    }
+
+    /// Returns the size of the object stored at the given locator.
+    fn size(&self, locator: &Locator) -> Result<u64, CoordinatorError> {
+        match self
+            .objects
+            .read()
+            .expect("memory storage lock is poisoned")
+            .get(locator)
+        {
+            Some(bytes) => Ok(bytes.len() as u64),
+            None => {
+                error!("Locator missing in call to size() in storage.");
+                Err(CoordinatorError::StorageLocatorMissing)
+            }
+        }
+    }
+
+    /// Returns an object reader for the given locator.
+    fn reader(&self, locator: &Locator) -> Result<StorageReader, CoordinatorError> {
+        let data = self.bytes(locator)?;
+
+        // Check that the round or contribution size is correct.
+        Object::check_size(&self.environment, locator, data.len() as u64)?;
+
+        Ok(StorageReader::new(MemoryObjectReader { data }))
+    }
+
+    /// Returns an object writer for the given locator.
+    ///
+    /// The contents of the writer are only stored when it is flushed.
+    fn writer(&self, locator: &Locator) -> Result<StorageWriter, CoordinatorError> {
+        let data = self.bytes(locator)?;
+
+        // Check that the round or contribution size is correct.
+        Object::check_size(&self.environment, locator, data.len() as u64)?;
+
+        Ok(StorageWriter::new(MemoryObjectWriter {
+            locator: *locator,
+            objects: self.objects.clone(),
+            data,
+            modified: AtomicBool::new(false),
+        }))
+    }
+
+    /// Process a [StorageAction] which mutates the storage.
+    fn process(&mut self, action: StorageAction) -> anyhow::Result<()> {
+        match action {
+            StorageAction::Remove(remove_action) => {
+                let locator = remove_action.try_into_locator(self)?;
+                Ok(self.remove(&locator)?)
+            }
+            StorageAction::Update(update_action) => Ok(self.update(&update_action.locator, update_action.object)?),
+            StorageAction::ClearRoundFiles(round_height) => Ok(self.clear_round_files(round_height)),
+        }
+    }
+}
+
+impl StorageLocator for Memory {
+    #[inline]
+    fn to_path(&self, locator: &Locator) -> Result<LocatorPath, CoordinatorError> {
+        self.resolver.to_path(locator)
+    }
+
+    #[inline]
+    fn to_locator(&self, path: &LocatorPath) -> Result<Locator, CoordinatorError> {
+        self.resolver.to_locator(path)
+    }
+}
+
+pub struct MemoryObjectReader {
+    data: Vec<u8>,
+}
+
+impl Deref for MemoryObjectReader {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &*self.data
+    }
+}
+
+impl AsRef<[u8]> for MemoryObjectReader {
+    fn as_ref(&self) -> &[u8] {
+        self.data.as_ref()
+    }
+}
+
+impl ObjectReader for MemoryObjectReader {}
+
+pub struct MemoryObjectWriter {
+    locator: Locator,
+    objects: Objects,
+    data: Vec<u8>,
+    modified: AtomicBool,
+}
+
+impl Deref for MemoryObjectWriter {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &*self.data
+    }
+}
+
+impl DerefMut for MemoryObjectWriter {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.modified.store(true, Ordering::SeqCst);
+        &mut *self.data
+    }
+}
+
+impl AsMut<[u8]> for MemoryObjectWriter {
+    fn as_mut(&mut self) -> &mut [u8] {
+        self.modified.store(true, Ordering::SeqCst);
+        self.data.as_mut()
+    }
+}
+
+impl ObjectWriter for MemoryObjectWriter {
+    fn flush(&self) -> std::io::Result<()> {
+        if self.modified.swap(false, Ordering::SeqCst) {
+            self.objects
+                .write()
+                .expect("memory storage lock is poisoned")
+                .insert(self.locator, self.data.clone());
+        }
+        Ok(())
+    }
+}
+
+impl Drop for MemoryObjectWriter {
+    fn drop(&mut self) {
+        if self.modified.load(Ordering::SeqCst) {
+            error!(
+                "The writer of {:?} was dropped without storing its contents",
+                self.locator
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{storage::ContributionLocator, testing::prelude::*};
+
+    #[test]
+    #[serial]
+    fn test_memory_insert_get_remove() {
+        let mut storage = Memory::load(&TEST_ENVIRONMENT).unwrap();
+        assert!(storage.exists(&Locator::CoordinatorState).unwrap());
+
+        let locator = Locator::RoundHeight;
+        assert!(!storage.exists(&locator).unwrap());
+
+        storage.insert(locator, Object::RoundHeight(7)).unwrap();
+        assert!(storage.exists(&locator).unwrap());
+        assert!(matches!(storage.get(&locator).unwrap(), Object::RoundHeight(7)));
+        assert!(storage.insert(locator, Object::RoundHeight(8)).is_err());
+
+        storage.update(&locator, Object::RoundHeight(8)).unwrap();
+        assert!(matches!(storage.get(&locator).unwrap(), Object::RoundHeight(8)));
+
+        storage.remove(&locator).unwrap();
+        assert!(!storage.exists(&locator).unwrap());
+        assert!(storage.remove(&locator).is_err());
+    }
+
+    #[test]
+    #[serial]
+    fn test_memory_writer_flush() {
+        let mut storage = Memory::load(&TEST_ENVIRONMENT).unwrap();
+
+        let locator = Locator::ContributionFile(ContributionLocator::new(1, 0, 0, true));
+        let size = Object::contribution_file_size(&TEST_ENVIRONMENT, 0, true);
+        storage.initialize(locator, size).unwrap();
+        assert_eq!(size, storage.size(&locator).unwrap());
+
+        let mut writer = storage.writer(&locator).unwrap();
+        writer.as_mut()[0] = 1;
+        assert_eq!(0, storage.reader(&locator).unwrap()[0]);
+        writer.flush().unwrap();
+        assert_eq!(1, storage.reader(&locator).unwrap()[0]);
+
+        let destination = Locator::ContributionFile(ContributionLocator::new(1, 0, 1, true));
+        storage.copy(&locator, &destination).unwrap();
+        assert_eq!(1, storage.reader(&destination).unwrap()[0]);
+    }
+
+    #[test]
+    #[serial]
+    fn test_memory_clear_round_files() {
+        let mut storage = Memory::load(&TEST_ENVIRONMENT).unwrap();
+
+        let initial = Locator::ContributionFile(ContributionLocator::new(1, 0, 0, true));
+        let contribution = Locator::ContributionFile(ContributionLocator::new(1, 0, 1, false));
+        let next_round = Locator::ContributionFile(ContributionLocator::new(2, 0, 0, true));
+        let round_state = Locator::RoundState { round_height: 1 };
+        for locator in &[&initial, &contribution, &next_round, &round_state] {
+            storage.initialize(**locator, 1).unwrap();
+        }
+
+        storage.process(StorageAction::ClearRoundFiles(1)).unwrap();
+
+        assert!(storage.exists(&initial).unwrap());
+        assert!(!storage.exists(&contribution).unwrap());
+        assert!(!storage.exists(&next_round).unwrap());
+        assert!(storage.exists(&round_state).unwrap());
+    }
+}
@@ -1,5 +1,13 @@
 pub mod disk;
 pub use disk::*;
 
+pub mod memory;
+pub use memory::*;
+
+#[cfg(feature = "s3")]
+pub mod object_store;
+#[cfg(feature = "s3")]
+pub use object_store::*;
+
 pub mod storage;
 pub use storage::*;
@@ -0,0 +1,520 @@
+use crate::{
+    environment::Environment,
+    storage::{
+        is_cleared_by_round_reset,
+        DiskResolver,
+        Locator,
+        LocatorPath,
+        Object,
+        ObjectReader,
+        ObjectWriter,
+        Storage,
+        StorageAction,
+        StorageLocator,
+        StorageReader,
+        StorageWriter,
+    },
+    CoordinatorError,
+    CoordinatorState,
+};
+
+use s3::{creds::Credentials, Bucket, Region};
+use serde::{Deserialize, Serialize};
+use std::{
+    ops::{Deref, DerefMut},
+    sync::atomic::{AtomicBool, Ordering},
+};
+use tracing::{error, trace};
+
+/// The largest object which the object store copies with a single request.
+const MAX_COPY_SIZE: u64 = 5 * 1024 * 1024 * 1024;
+/// The size of the ranges in which objects are downloaded.
+const DOWNLOAD_RANGE_SIZE: u64 = 64 * 1024 * 1024;
+
+/// The settings of an S3-compatible object store, such as AWS S3 or MinIO.
+///
+/// The credentials of the object store are read from the
+/// `AWS_ACCESS_KEY_ID` and `AWS_SECRET_ACCESS_KEY` environment variables.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ObjectStoreSettings {
+    /// The URL of the object store, e.g. `http://localhost:9090`.
+    pub endpoint: String,
+    /// The region of the object store, e.g. `us-east-1`.
+    pub region: String,
+    /// The name of the bucket containing the objects of the coordinator.
+    pub bucket: String,
+}
+
+/// A storage backend which keeps all objects in an S3-compatible object store.
+///
+/// The key of each object is the path of its locator relative to the
+/// local base directory, so the bucket mirrors the layout of [Disk](crate::storage::Disk).
+/// This allows a coordinator running on an ephemeral machine to recover
+/// its state from the object store on restart.
+///
+/// Objects are uploaded with multipart uploads and downloaded in ranges,
+/// as round and contribution files can exceed the 5 GB limit of a single request.
+#[derive(Debug)]
+pub struct ObjectStore {
+    environment: Environment,
+    resolver: DiskResolver,
+    bucket: Bucket,
+}
+
+impl ObjectStore {
+    /// Loads a new instance of `ObjectStore`.
+    pub fn load(environment: &Environment, settings: &ObjectStoreSettings) -> Result<Self, CoordinatorError> {
+        trace!("Loading object store storage from {}", settings.endpoint);
+
+        let region = Region::Custom {
+            region: settings.region.clone(),
+            endpoint: settings.endpoint.clone(),
+        };
+        let credentials = Credentials::from_env().map_err(|error| CoordinatorError::Error(error.into()))?;
+        // Path-style requests are required by MinIO and other self-hosted object stores.
+        let bucket = Bucket::new_with_path_style(&settings.bucket, region, credentials)
+            .map_err(|error| CoordinatorError::Error(error.into()))?;
+
+        let mut storage = Self {
+            environment: environment.clone(),
+            resolver: DiskResolver::new(environment.local_base_directory()),
+            bucket,
+        };
+
+        // Create the coordinator state locator if it does not exist yet.
+        if !storage.exists(&Locator::CoordinatorState)? {
+            storage.insert(
+                Locator::CoordinatorState,
+                Object::CoordinatorState(CoordinatorState::new(environment.clone())),
+            )?;
+        }
+
+        trace!("Loaded object store storage");
+        Ok(storage)
+    }
+
+    /// Returns the key of the object at the given locator.
+    fn key(&self, locator: &Locator) -> Result<String, CoordinatorError> {
+        Ok(self.to_path(locator)?.to_string().trim_start_matches("./").to_string())
+    }
+
+    /// Returns the locator path of the object with the given key.
+    fn path(&self, key: String) -> LocatorPath {
+        match self.environment.local_base_directory().starts_with("./") {
+            true => LocatorPath::new(format!("./{}", key)),
+            false => LocatorPath::new(key),
+        }
+    }
+
+    /// Returns the bytes stored at the given locator.
+    fn bytes(&self, locator: &Locator) -> Result<Vec<u8>, CoordinatorError> {
+        get_object(&self.bucket, &self.key(locator)?)
+    }
+
+    /// Stores the given bytes at the given locator.
+    fn put(&self, locator: &Locator, bytes: &[u8]) -> Result<(), CoordinatorError> {
+        put_object(&self.bucket, &self.key(locator)?, bytes)
+    }
+
+    /// Clears all objects related to a round - used for round reset purposes.
+    fn clear_round_files(&mut self, round_height: u64) -> Result<(), CoordinatorError> {
+        for height in &[round_height, round_height + 1] {
+            let prefix = format!("{}/", self.resolver.round_directory(*height).trim_start_matches("./"));
+            let results = self
+                .bucket
+                .list(prefix, None)
+                .map_err(|error| CoordinatorError::Error(error.into()))?;
+
+            for key in results.into_iter().flat_map(|result| result.contents).map(|o| o.key) {
+                let locator = match self.to_locator(&self.path(key)) {
+                    Ok(locator) => locator,
+                    Err(e) => {
+                        tracing::error!("Could not turn object key into locator - {:?}", e);
+                        continue;
+                    }
+                };
+
+                if is_cleared_by_round_reset(&locator, round_height) {
+                    if let Err(e) = self.remove(&locator) {
+                        tracing::error!("Could not remove locator - {:?}", e);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Storage for ObjectStore {
+    /// Initializes the location corresponding to the given locator.
+    fn initialize(&mut self, locator: Locator, size: u64) -> Result<(), CoordinatorError> {
+        trace!("Initializing {:?}", locator);
+
+        // Check that the locator does not already exist in storage.
+        if self.exists(&locator)? {
+            error!(
+                "Locator {:?} in call to initialize() already exists in storage.",
+                locator
+            );
+            return Err(CoordinatorError::StorageLocatorAlreadyExists);
+        }
+
+        self.put(&locator, &vec![0u8; size as usize])
+    }
+
+    /// Checks whether the given locator exists in the storage or not.
+    ///
+    /// A missing object is reported as `false`, while any other failure
+    /// of the object store is returned as an error.
+    fn exists(&self, locator: &Locator) -> Result<bool, CoordinatorError> {
+        let key = self.key(locator)?;
+        let (_, status_code) = self
+            .bucket
+            .head_object(&key)
+            .map_err(|error| CoordinatorError::Error(error.into()))?;
+        match status_code {
+            404 => Ok(false),
+            _ if is_success(status_code) => Ok(true),
+            _ => Err(status_error(&key, status_code)),
+        }
+    }
+
+    /// Returns a copy of an object at the given locator in storage, if it exists.
+    fn get(&self, locator: &Locator) -> Result<Object, CoordinatorError> {
+        trace!("Fetching {:?}", locator);
+        Object::from_bytes(&self.environment, locator, self.bytes(locator)?)
+    }
+
+    /// Inserts a new object at the given locator into storage, if it does not exist.
+    fn insert(&mut self, locator: Locator, object: Object) -> Result<(), CoordinatorError> {
+        trace!("Inserting {:?}", locator);
+
+        // Check that the given locator does not exist in storage.
+        if self.exists(&locator)? {
+            error!("Locator in call to insert() already exists in storage.");
+            return Err(CoordinatorError::StorageLocatorAlreadyExists);
+        }
+
+        self.put(&locator, &object.to_bytes())
+    }
+
+    /// Updates an existing object for the given locator in storage, if it exists.
+    fn update(&mut self, locator: &Locator, object: Object) -> Result<(), CoordinatorError> {
+        trace!("Updating {:?}", locator);
+
+        // Check that the given locator exists in storage.
+        if !self.exists(locator)? {
+            error!("Locator missing in call to update() in storage.");
+            return Err(CoordinatorError::StorageLocatorMissing);
+        }
+
+        self.put(locator, &object.to_bytes())
+    }
+
+    /// Copies an object from the given source locator to the given destination locator.
+    fn copy(&mut self, source_locator: &Locator, destination_locator: &Locator) -> Result<(), CoordinatorError> {
+        trace!("Copying from {:?} to {:?}", source_locator, destination_locator);
+
+        // Check that the given destination locator does NOT exist in storage.
+        if self.exists(destination_locator)? {
+            error!("Destination locator in call to copy() already exists in storage.");
+            return Err(CoordinatorError::StorageLocatorAlreadyExists);
+        }
+
+        let (source, destination) = (self.key(source_locator)?, self.key(destination_locator)?);
+        match head_object(&self.bucket, &source)? <= MAX_COPY_SIZE {
+            // Objects which fit in a single request are copied by the object store itself.
+            true => copy_object(&self.bucket, &source, &destination),
+            false => put_object(&self.bucket, &destination, &get_object(&self.bucket, &source)?),
+        }
+    }
+
+    /// Removes the object corresponding to the given locator from storage.
+    fn remove(&mut self, locator: &Locator) -> Result<(), CoordinatorError> {
+        trace!("Removing {:?}", locator);
+
+        // Check that the locator exists in storage.
+        if !self.exists(locator)? {
+            error!("Locator in call to remove() doesn't exist in storage.");
+            return Err(CoordinatorError::StorageLocatorMissing);
+        }
+
+        delete_object(&self.bucket, &self.key(locator)?)
+    }
+
+    /// Returns the size of the object stored at the given locator.
+    fn size(&self, locator: &Locator) -> Result<u64, CoordinatorError> {
+        head_object(&self.bucket, &self.key(locator)?)
+    }
+
+    /// Returns an object reader for the given locator.
+    fn reader(&self, locator: &Locator) -> Result<StorageReader, CoordinatorError> {
+        let data = self.bytes(locator)?;
+
+        // Check that the round or contribution size is correct.
+        Object::check_size(&self.environment, locator, data.len() as u64)?;
+
+        Ok(StorageReader::new(ObjectStoreReader { data }))
+    }
+
+    /// Returns an object writer for the given locator.
+    ///
+    /// The contents of the writer are only uploaded when it is flushed.
+    fn writer(&self, locator: &Locator) -> Result<StorageWriter, CoordinatorError> {
+        let data = self.bytes(locator)?;
+
+        // Check that the round or contribution size is correct.
+        Object::check_size(&self.environment, locator, data.len() as u64)?;
+
+        Ok(StorageWriter::new(ObjectStoreWriter {
+            bucket: self.bucket.clone(),
+            key: self.key(locator)?,
+            data,
+            modified: AtomicBool::new(false),
+        }))
+    }
+
+    /// Process a [StorageAction] which mutates the storage.
+    fn process(&mut self, action: StorageAction) -> anyhow::Result<()> {
+        match action {
+            StorageAction::Remove(remove_action) => {
+                let locator = remove_action.try_into_locator(self)?;
+                Ok(self.remove(&locator)?)
+            }
+            StorageAction::Update(update_action) => Ok(self.update(&update_action.locator, update_action.object)?),
+            StorageAction::ClearRoundFiles(round_height) => Ok(self.clear_round_files(round_height)?),
+        }
+    }
+}
+
+impl StorageLocator for ObjectStore {
+    #[inline]
+    fn to_path(&self, locator: &Locator) -> Result<LocatorPath, CoordinatorError> {
+        self.resolver.to_path(locator)
+    }
+
+    #[inline]
+    fn to_locator(&self, path: &LocatorPath) -> Result<Locator, CoordinatorError> {
+        self.resolver.to_locator(path)
+    }
+}
+
+/// Returns `true` if the given status code is a successful response.
+fn is_success(status_code: u16) -> bool {
+    (200..300).contains(&status_code)
+}
+
+/// Returns the error corresponding to an unsuccessful response.
+fn status_error(key: &str, status_code: u16) -> CoordinatorError {
+    match status_code {
+        404 => {
+            error!("Object {} missing in object store", key);
+            CoordinatorError::StorageLocatorMissing
+        }
+        _ => CoordinatorError::Error(anyhow::anyhow!(
+            "Object store request for {} failed with status {}",
+            key,
+            status_code
+        )),
+    }
+}
+
+/// Downloads the object with the given key, in ranges of `DOWNLOAD_RANGE_SIZE` bytes.
+fn get_object(bucket: &Bucket, key: &str) -> Result<Vec<u8>, CoordinatorError> {
+    let size = head_object(bucket, key)?;
+    let mut bytes = Vec::with_capacity(size as usize);
+    while (bytes.len() as u64) < size {
+        let start = bytes.len() as u64;
+        let end = std::cmp::min(start + DOWNLOAD_RANGE_SIZE, size) - 1;
+        let response = bucket
+            .get_object_range(key, start, Some(end))
+            .map_err(|error| CoordinatorError::Error(error.into()))?;
+        if !is_success(response.status_code()) {
+            return Err(status_error(key, response.status_code()));
+        }
+        if response.bytes().len() as u64 != end - start + 1 {
+            return Err(CoordinatorError::Error(anyhow::anyhow!(
+                "Object store returned {} bytes for the range {}..={} of {}",
+                response.bytes().len(),
+                start,
+                end,
+                key
+            )));
+        }
+        bytes.extend_from_slice(&response.bytes());
+    }
+    Ok(bytes)
+}
+
+/// Uploads the given bytes to the given key, with a multipart upload
+/// if they do not fit in a single part.
+fn put_object(bucket: &Bucket, key: &str, mut bytes: &[u8]) -> Result<(), CoordinatorError> {
+    let status_code = bucket
+        .put_object_stream(&mut bytes, key)
+        .map_err(|error| CoordinatorError::Error(error.into()))?;
+    match is_success(status_code) {
+        true => Ok(()),
+        false => Err(status_error(key, status_code)),
+    }
+}
+
+/// Copies the object with the given key within the bucket, without downloading it.
+fn copy_object(bucket: &Bucket, source: &str, destination: &str) -> Result<(), CoordinatorError> {
+    let status_code = bucket
+        .copy_object_internal(source, destination)
+        .map_err(|error| CoordinatorError::Error(error.into()))?;
+    match is_success(status_code) {
+        true => Ok(()),
+        false => Err(status_error(source, status_code)),
+    }
+}
+
+fn delete_object(bucket: &Bucket, key: &str) -> Result<(), CoordinatorError> {
+    let response = bucket
+        .delete_object(key)
+        .map_err(|error| CoordinatorError::Error(error.into()))?;
+    match is_success(response.status_code()) {
+        true => Ok(()),
+        false => Err(status_error(key, response.status_code())),
+    }
+}
+
+/// Returns the size of the object with the given key, if it exists.
+fn head_object(bucket: &Bucket, key: &str) -> Result<u64, CoordinatorError> {
+    let (head, status_code) = bucket
+        .head_object(key)
+        .map_err(|error| CoordinatorError::Error(error.into()))?;
+    match is_success(status_code) {
+        true => Ok(head.content_length.unwrap_or_default() as u64),
+        false => Err(status_error(key, status_code)),
+    }
+}
+
+pub struct ObjectStoreReader {
+    data: Vec<u8>,
+}
+
+impl Deref for ObjectStoreReader {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &*self.data
+    }
+}
+
+impl AsRef<[u8]> for ObjectStoreReader {
+    fn as_ref(&self) -> &[u8] {
+        self.data.as_ref()
+    }
+}
+
+impl ObjectReader for ObjectStoreReader {}
+
+/// A writer to an object in the object store.
+///
+/// The contents of the writer are only uploaded when it is flushed,
+/// and only if they were modified since the last upload.
+pub struct ObjectStoreWriter {
+    bucket: Bucket,
+    key: String,
+    data: Vec<u8>,
+    modified: AtomicBool,
+}
+
+impl Deref for ObjectStoreWriter {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &*self.data
+    }
+}
+
+impl DerefMut for ObjectStoreWriter {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.modified.store(true, Ordering::SeqCst);
+        &mut *self.data
+    }
+}
+
+impl AsMut<[u8]> for ObjectStoreWriter {
+    fn as_mut(&mut self) -> &mut [u8] {
+        self.modified.store(true, Ordering::SeqCst);
+        self.data.as_mut()
+    }
+}
+
+impl ObjectWriter for ObjectStoreWriter {
+    fn flush(&self) -> std::io::Result<()> {
+        if !self.modified.load(Ordering::SeqCst) {
+            return Ok(());
+        }
+
+        put_object(&self.bucket, &self.key, &self.data)
+            .map_err(|error| std::io::Error::new(std::io::ErrorKind::Other, format!("{:?}", error)))?;
+        self.modified.store(false, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+impl Drop for ObjectStoreWriter {
+    fn drop(&mut self) {
+        if self.modified.load(Ordering::SeqCst) {
+            error!("The writer of {} was dropped without uploading its contents", self.key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{storage::ContributionLocator, testing::prelude::*};
+
+    /// Returns the settings of the MinIO instance used for testing,
+    /// e.g. started with `docker run -p 9090:9000 minio/minio server /data`.
+    ///
+    /// The bucket must exist, and the credentials must be set in
+    /// `AWS_ACCESS_KEY_ID` and `AWS_SECRET_ACCESS_KEY`.
+    fn minio_settings() -> ObjectStoreSettings {
+        ObjectStoreSettings {
+            endpoint: std::env::var("MINIO_ENDPOINT").unwrap_or_else(|_| "http://localhost:9090".to_string()),
+            region: "us-east-1".to_string(),
+            bucket: std::env::var("MINIO_BUCKET").unwrap_or_else(|_| "coordinator".to_string()),
+        }
+    }
+
+    #[test]
+    #[serial]
+    #[ignore]
+    fn test_object_store_against_minio() {
+        let mut storage = ObjectStore::load(&TEST_ENVIRONMENT, &minio_settings()).unwrap();
+        assert!(storage.exists(&Locator::CoordinatorState).unwrap());
+
+        let locator = Locator::ContributionFile(ContributionLocator::new(1, 0, 1, false));
+        if storage.exists(&locator).unwrap() {
+            storage.remove(&locator).unwrap();
+        }
+
+        let size = Object::contribution_file_size(&TEST_ENVIRONMENT, 0, false);
+        storage.initialize(locator, size).unwrap();
+        assert_eq!(size, storage.size(&locator).unwrap());
+
+        let mut writer = storage.writer(&locator).unwrap();
+        writer.as_mut()[0] = 1;
+        assert_eq!(0, storage.reader(&locator).unwrap()[0]);
+        writer.flush().unwrap();
+        assert_eq!(1, storage.reader(&locator).unwrap()[0]);
+
+        let copy_locator = Locator::ContributionFile(ContributionLocator::new(1, 0, 1, true));
+        if storage.exists(&copy_locator).unwrap() {
+            storage.remove(&copy_locator).unwrap();
+        }
+        storage.copy(&locator, &copy_locator).unwrap();
+        assert_eq!(size, storage.size(&copy_locator).unwrap());
+        assert_eq!(1, storage.bytes(&copy_locator).unwrap()[0]);
+
+        storage.process(StorageAction::ClearRoundFiles(1)).unwrap();
+        assert!(!storage.exists(&locator).unwrap());
+        assert!(!storage.exists(&copy_locator).unwrap());
+        assert!(storage.exists(&Locator::CoordinatorState).unwrap());
+    }
+}
@@ -4,7 +4,8 @@ use crate::{
     CoordinatorError,
     CoordinatorState,
 };
-use phase1::helpers::CurveKind;
+use phase1::{check_file_header, helpers::CurveKind, HEADER_SIZE};
+use setup_utils::curves::Bls12_381;
 use snarkvm_curves::{bls12_377::Bls12_377, bw6_761::BW6_761};
 
 use serde::{Deserialize, Serialize};
@@ -13,8 +14,7 @@ use std::{
     ops::{Deref, DerefMut},
     path::{Path, PathBuf},
 };
-
-use super::Disk;
+use tracing::{debug, error};
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub struct ContributionLocator {
@@ -148,6 +148,101 @@ impl Object {
         }
     }
 
+    ///
+    /// Deserializes the given bytes, loaded from the given locator, into an object.
+    ///
+    /// If the size of the bytes does not match the expected size of a
+    /// round file, contribution file, or contribution file signature,
+    /// returns a `CoordinatorError`.
+    ///
+    pub fn from_bytes(environment: &Environment, locator: &Locator, bytes: Vec<u8>) -> Result<Self, CoordinatorError> {
+        match locator {
+            Locator::CoordinatorState => Ok(Object::CoordinatorState(serde_json::from_slice(&bytes)?)),
+            Locator::RoundHeight => Ok(Object::RoundHeight(serde_json::from_slice(&bytes)?)),
+            Locator::RoundState { round_height: _ } => Ok(Object::RoundState(serde_json::from_slice(&bytes)?)),
+            Locator::RoundFile { round_height: _ } => {
+                if bytes.is_empty() {
+                    error!("Round file is empty");
+                    return Err(CoordinatorError::RoundFileSizeMismatch);
+                }
+                Self::check_size(environment, locator, bytes.len() as u64)?;
+                Ok(Object::RoundFile(bytes))
+            }
+            Locator::ContributionFile(_) => {
+                if bytes.is_empty() {
+                    error!("Contribution file is empty");
+                    return Err(CoordinatorError::ContributionFileSizeMismatch);
+                }
+                Self::check_size(environment, locator, bytes.len() as u64)?;
+                Ok(Object::ContributionFile(bytes))
+            }
+            Locator::ContributionFileSignature(contribution_locator) => {
+                // Check that the contribution file signature size is correct.
+                let expected_size = Self::contribution_file_signature_size(contribution_locator.is_verified());
+                let found_size = bytes.len() as u64;
+                debug!(
+                    "Round {} chunk {} contribution {} signature filesize is {}",
+                    contribution_locator.round_height(),
+                    contribution_locator.chunk_id(),
+                    contribution_locator.contribution_id(),
+                    found_size
+                );
+                if found_size == 0 || expected_size != found_size {
+                    error!(
+                        "Contribution signature file size should be {} but found {}",
+                        expected_size, found_size
+                    );
+                    return Err(CoordinatorError::ContributionSignatureFileSizeMismatch);
+                }
+
+                Ok(Object::ContributionFileSignature(serde_json::from_slice(&bytes)?))
+            }
+        }
+    }
+
+    ///
+    /// Checks that the given size matches the expected size of the
+    /// round file or contribution file at the given locator.
+    ///
+    /// The sizes of all other objects are not checked.
+    ///
+    pub fn check_size(environment: &Environment, locator: &Locator, found_size: u64) -> Result<(), CoordinatorError> {
+        match locator {
+            Locator::RoundFile { round_height } => {
+                // Check that the round size is correct.
+                let expected_size = Self::round_file_size(environment);
+                debug!("Round {} filesize is {}", round_height, found_size);
+                if found_size != expected_size {
+                    error!("Round file size should be {} but found {}", expected_size, found_size);
+                    return Err(CoordinatorError::RoundFileSizeMismatch);
+                }
+            }
+            Locator::ContributionFile(contribution_locator) => {
+                // Check that the contribution size is correct.
+                let expected_size = Self::contribution_file_size(
+                    environment,
+                    contribution_locator.chunk_id(),
+                    contribution_locator.is_verified(),
+                );
+                debug!(
+                    "Round {} chunk {} filesize is {}",
+                    contribution_locator.round_height(),
+                    contribution_locator.chunk_id(),
+                    found_size
+                );
+                if found_size != expected_size {
+                    error!(
+                        "Contribution file size should be {} but found {}",
+                        expected_size, found_size
+                    );
+                    return Err(CoordinatorError::ContributionFileSizeMismatch);
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
     /// Returns the expected file size of an aggregated round.
     pub fn round_file_size(environment: &Environment) -> u64 {
         let compressed = environment.compressed_inputs();
@@ -155,6 +250,7 @@ impl Object {
 
         match settings.curve() {
             CurveKind::Bls12_377 => round_filesize!(Bls12_377, settings, compressed),
+            CurveKind::Bls12_381 => round_filesize!(Bls12_381, settings, compressed),
             CurveKind::BW6 => round_filesize!(BW6_761, settings, compressed),
         }
     }
@@ -174,11 +270,56 @@ impl Object {
         match (curve, verified) {
             (CurveKind::Bls12_377, true) => verified_contribution_size!(Bls12_377, settings, chunk_id, compressed),
             (CurveKind::Bls12_377, false) => unverified_contribution_size!(Bls12_377, settings, chunk_id, compressed),
+            (CurveKind::Bls12_381, true) => verified_contribution_size!(Bls12_381, settings, chunk_id, compressed),
+            (CurveKind::Bls12_381, false) => unverified_contribution_size!(Bls12_381, settings, chunk_id, compressed),
             (CurveKind::BW6, true) => verified_contribution_size!(BW6_761, settings, chunk_id, compressed),
             (CurveKind::BW6, false) => unverified_contribution_size!(BW6_761, settings, chunk_id, compressed),
         }
     }
 
+    ///
+    /// Checks and strips the optional header of a contribution file uploaded by a participant.
+    ///
+    /// Contribution files are stored without a header, so that their size matches
+    /// `contribution_file_size`. The header is not part of the hash chain, so stripping
+    /// it leaves the hashes of the contribution unchanged.
+    ///
+    pub fn strip_contribution_header(
+        environment: &Environment,
+        contribution_locator: &ContributionLocator,
+        mut contribution: Vec<u8>,
+    ) -> Result<Vec<u8>, CoordinatorError> {
+        let settings = environment.parameters();
+        let chunk_id = contribution_locator.chunk_id();
+        let compressed = match contribution_locator.is_verified() {
+            true => environment.compressed_inputs(),
+            false => environment.compressed_outputs(),
+        };
+
+        let (header, _) = match settings.curve() {
+            CurveKind::Bls12_377 => check_file_header(
+                &contribution,
+                compressed,
+                &phase1_chunked_parameters!(Bls12_377, settings, chunk_id),
+            )?,
+            CurveKind::Bls12_381 => check_file_header(
+                &contribution,
+                compressed,
+                &phase1_chunked_parameters!(Bls12_381, settings, chunk_id),
+            )?,
+            CurveKind::BW6 => check_file_header(
+                &contribution,
+                compressed,
+                &phase1_chunked_parameters!(BW6_761, settings, chunk_id),
+            )?,
+        };
+        if header.is_some() {
+            contribution.drain(..HEADER_SIZE);
+        }
+
+        Ok(contribution)
+    }
+
     /// Returns the expected file size of a contribution signature.
     pub fn contribution_file_signature_size(verified: bool) -> u64 {
         // TODO (raychu86): Calculate contribution signature file size instead of using hard coded values.
@@ -195,6 +336,70 @@ pub trait ObjectWriter: AsMut<[u8]> + DerefMut<Target = [u8]> {
     fn flush(&self) -> std::io::Result<()>;
 }
 
+/// A reader to an object in [Storage], independent of the storage backend.
+pub struct StorageReader(Box<dyn ObjectReader + Send + Sync>);
+
+impl StorageReader {
+    pub fn new(reader: impl ObjectReader + Send + Sync + 'static) -> Self {
+        Self(Box::new(reader))
+    }
+}
+
+impl Deref for StorageReader {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &**self.0
+    }
+}
+
+impl AsRef<[u8]> for StorageReader {
+    fn as_ref(&self) -> &[u8] {
+        &**self.0
+    }
+}
+
+impl ObjectReader for StorageReader {}
+
+/// A writer to an object in [Storage], independent of the storage backend.
+///
+/// Depending on the backend, the contents of the writer may only be
+/// persisted when the writer is flushed, so callers must flush it
+/// once they are done writing.
+pub struct StorageWriter(Box<dyn ObjectWriter + Send + Sync>);
+
+impl StorageWriter {
+    pub fn new(writer: impl ObjectWriter + Send + Sync + 'static) -> Self {
+        Self(Box::new(writer))
+    }
+}
+
+impl Deref for StorageWriter {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &**self.0
+    }
+}
+
+impl DerefMut for StorageWriter {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut **self.0
+    }
+}
+
+impl AsMut<[u8]> for StorageWriter {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut **self.0
+    }
+}
+
+impl ObjectWriter for StorageWriter {
+    fn flush(&self) -> std::io::Result<()> {
+        self.0.flush()
+    }
+}
+
 /// The path to a resource defined by a [Locator].
 #[derive(Clone, Serialize, Deserialize, Eq, PartialEq, Hash, PartialOrd, Ord)]
 pub struct LocatorPath(String);
@@ -266,14 +471,14 @@ pub enum LocatorOrPath {
 }
 
 impl LocatorOrPath {
-    pub fn try_into_locator(self, storage: &Disk) -> Result<Locator, CoordinatorError> {
+    pub fn try_into_locator<S: StorageLocator + ?Sized>(self, storage: &S) -> Result<Locator, CoordinatorError> {
         match self {
             LocatorOrPath::Path(path) => storage.to_locator(&path),
             LocatorOrPath::Locator(locator) => Ok(locator),
         }
     }
 
-    pub fn try_into_path(self, storage: &Disk) -> Result<LocatorPath, CoordinatorError> {
+    pub fn try_into_path<S: StorageLocator + ?Sized>(self, storage: &S) -> Result<LocatorPath, CoordinatorError> {
         match self {
             LocatorOrPath::Path(path) => Ok(path),
             LocatorOrPath::Locator(locator) => storage.to_path(&locator),
@@ -315,11 +520,11 @@ impl RemoveAction {
 
     /// Obtain the location of the item to be removed from [Storage]
     /// as a [Locator].
-    pub fn try_into_locator(self, storage: &Disk) -> Result<Locator, CoordinatorError> {
+    pub fn try_into_locator<S: StorageLocator + ?Sized>(self, storage: &S) -> Result<Locator, CoordinatorError> {
         self.locator_or_path.try_into_locator(storage)
     }
 
-    pub fn try_into_path(self, storage: &Disk) -> Result<LocatorPath, CoordinatorError> {
+    pub fn try_into_path<S: StorageLocator + ?Sized>(self, storage: &S) -> Result<LocatorPath, CoordinatorError> {
         self.locator_or_path.try_into_path(storage)
     }
 }
@@ -347,13 +552,69 @@ pub trait StorageLocator {
     fn to_locator(&self, path: &LocatorPath) -> Result<Locator, CoordinatorError>;
 }
 
-pub trait StorageObject {
-    type Reader: ObjectReader;
-    type Writer: ObjectWriter;
+/// The storage of the coordinator, containing the coordinator state,
+/// the round states, and the round and contribution files.
+///
+/// Every object is addressed by a [Locator], which each storage backend
+/// resolves to a [LocatorPath] relative to the local base directory of
+/// the [Environment].
+pub trait Storage: StorageLocator + Send + Sync {
+    /// Initializes the location corresponding to the given locator
+    /// with an object of the given size.
+    fn initialize(&mut self, locator: Locator, size: u64) -> Result<(), CoordinatorError>;
+
+    /// Returns `true` if a given locator exists in storage. Otherwise, returns `false`.
+    ///
+    /// Returns an error if the storage failed to check for the locator.
+    fn exists(&self, locator: &Locator) -> Result<bool, CoordinatorError>;
+
+    /// Returns a copy of an object at the given locator in storage, if it exists.
+    fn get(&self, locator: &Locator) -> Result<Object, CoordinatorError>;
+
+    /// Inserts a new object at the given locator into storage, if it does not exist.
+    fn insert(&mut self, locator: Locator, object: Object) -> Result<(), CoordinatorError>;
+
+    /// Updates an existing object for the given locator in storage, if it exists.
+    fn update(&mut self, locator: &Locator, object: Object) -> Result<(), CoordinatorError>;
+
+    /// Copies an object from the given source locator to the given destination locator.
+    fn copy(&mut self, source_locator: &Locator, destination_locator: &Locator) -> Result<(), CoordinatorError>;
+
+    /// Removes the object corresponding to the given locator from storage.
+    fn remove(&mut self, locator: &Locator) -> Result<(), CoordinatorError>;
+
+    /// Returns the size of the object stored at the given locator.
+    fn size(&self, locator: &Locator) -> Result<u64, CoordinatorError>;
 
     /// Returns an object reader for the given locator.
-    fn reader(&self, locator: &Locator) -> Result<Self::Reader, CoordinatorError>;
+    fn reader(&self, locator: &Locator) -> Result<StorageReader, CoordinatorError>;
 
     /// Returns an object writer for the given locator.
-    fn writer(&self, locator: &Locator) -> Result<Self::Writer, CoordinatorError>;
+    fn writer(&self, locator: &Locator) -> Result<StorageWriter, CoordinatorError>;
+
+    /// Process a [StorageAction] which mutates the storage.
+    fn process(&mut self, action: StorageAction) -> anyhow::Result<()>;
+}
+
+///
+/// Returns `true` if the object at the given locator is cleared when
+/// resetting the round at the given round height.
+///
+/// This clears every file of the next round, which are the verifications
+/// that represent the initial challenges, and every contribution of the
+/// given round, except for the initial contribution of each chunk.
+///
+pub(crate) fn is_cleared_by_round_reset(locator: &Locator, round_height: u64) -> bool {
+    match locator {
+        Locator::RoundFile { round_height: height } => *height == round_height || *height == round_height + 1,
+        Locator::ContributionFile(locator) => {
+            locator.round_height() == round_height + 1
+                || (locator.round_height() == round_height && locator.contribution_id() != 0)
+        }
+        Locator::ContributionFileSignature(locator) => {
+            locator.round_height() == round_height + 1
+                || (locator.round_height() == round_height && locator.contribution_id() != 0)
+        }
+        Locator::CoordinatorState | Locator::RoundHeight | Locator::RoundState { .. } => false,
+    }
 }
@@ -2,7 +2,7 @@ use crate::{
     authentication::Dummy,
     environment::{Environment, Parameters, Testing},
     objects::{Participant, Round},
-    storage::Disk,
+    storage::Storage,
     Coordinator,
     CoordinatorError,
 };
@@ -111,7 +111,7 @@ fn clear_test_storage(environment: &Environment) {
 }
 
 /// Initializes a test storage object.
-pub fn test_storage(environment: &Environment) -> Disk {
+pub fn test_storage(environment: &Environment) -> Box<dyn Storage> {
     environment.storage().unwrap()
 }
 
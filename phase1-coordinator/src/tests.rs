@@ -3,7 +3,7 @@ use crate::{
     commands::{Seed, SigningKey, SEED_LENGTH},
     environment::{Environment, Parameters, Settings, Testing},
     objects::Task,
-    storage::{Disk, StorageLocator},
+    storage::{Storage, StorageLocator},
     testing::prelude::*,
     Coordinator,
     CoordinatorError,
@@ -1373,7 +1373,7 @@ fn coordinator_drop_several_contributors() {
     assert_eq!(0, coordinator.number_of_queue_contributors());
 }
 
-fn check_round_matches_storage_files(storage: &Disk, round: &Round) {
+fn check_round_matches_storage_files(storage: &dyn Storage, round: &Round) {
     debug!("Checking round {}", round.round_height());
     for chunk in round.chunks() {
         debug!("Checking chunk {}", chunk.chunk_id());
@@ -1403,19 +1403,19 @@ fn check_round_matches_storage_files(storage: &Disk, round: &Round) {
         for (index, (contribution_id, contribution)) in contributions.iter().enumerate() {
             if let Some(path) = contribution.get_contributed_location() {
                 let locator = storage.to_locator(&path).unwrap();
-                assert!(storage.exists(&locator));
+                assert!(storage.exists(&locator).unwrap());
                 expected_n_files += 1;
             }
 
             if let Some(path) = contribution.get_contributed_signature_location() {
                 let locator = storage.to_locator(&path).unwrap();
-                assert!(storage.exists(&locator));
+                assert!(storage.exists(&locator).unwrap());
                 expected_n_files += 1;
             }
 
             if let Some(path) = contribution.get_verified_location() {
                 let locator = storage.to_locator(&path).unwrap();
-                assert!(storage.exists(&locator));
+                assert!(storage.exists(&locator).unwrap());
 
                 // the final contribution's verification goes in the next round's directory
                 if (!contributions_complete) || last_index != index {
@@ -1429,7 +1429,7 @@ fn check_round_matches_storage_files(storage: &Disk, round: &Round) {
                 // be a bug.
                 if *contribution_id != 0 {
                     let locator = storage.to_locator(&path).unwrap();
-                    assert!(storage.exists(&locator));
+                    assert!(storage.exists(&locator).unwrap());
 
                     // the final contribution's verification goes in the next round's directory
                     if (!contributions_complete) || last_index != index {
@@ -2171,6 +2171,76 @@ fn participant_lock_timeout_drop_test() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Test that a computation run by the coordinator on behalf of a
+/// participant is cancelled once the participant has held the lock
+/// on the chunk for longer than [Environment::participant_lock_timeout].
+#[test]
+#[serial]
+fn participant_lock_timeout_cancels_computation_test() -> anyhow::Result<()> {
+    let start = OffsetDateTime::now_utc();
+    let time = Arc::new(MockTimeSource::new(start));
+
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+
+    let testing_deployment: Testing = Testing::from(parameters)
+        .contributor_seen_timeout(time::Duration::minutes(20))
+        .participant_lock_timeout(time::Duration::minutes(10));
+
+    let environment = initialize_test_environment(&Environment::from(testing_deployment));
+
+    // Instantiate a coordinator.
+    let mut coordinator = Coordinator::new_with_time(environment, Arc::new(Dummy), time.clone())?;
+
+    // Initialize the ceremony to round 0.
+    coordinator.initialize()?;
+
+    let (contributor1, contributor_signing_key1, seed1) = create_contributor("1");
+    let contributor_1_ip = IpAddr::V4(Ipv4Addr::UNSPECIFIED);
+
+    coordinator.add_to_queue(contributor1.clone(), Some(contributor_1_ip), 10)?;
+
+    // Update the ceremony to round 1.
+    coordinator.update()?;
+
+    let (_, locked_locators) = coordinator.try_lock(&contributor1)?;
+    let response_locator = locked_locators.next_contribution();
+    let round_height = response_locator.round_height();
+    let chunk_id = response_locator.chunk_id();
+    let contribution_id = response_locator.contribution_id();
+
+    // push the time past the timeout, without updating the coordinator
+    time.set_time(start + time::Duration::minutes(11));
+    let result = coordinator.run_computation(
+        round_height,
+        chunk_id,
+        contribution_id,
+        &contributor1,
+        &contributor_signing_key1,
+        &seed1,
+    );
+    assert!(result.is_err());
+
+    // The computation succeeds while the lock is within the timeout.
+    time.set_time(start + time::Duration::minutes(1));
+    coordinator.run_computation(
+        round_height,
+        chunk_id,
+        contribution_id,
+        &contributor1,
+        &contributor_signing_key1,
+        &seed1,
+    )?;
+
+    Ok(())
+}
+
 /// Test that a participant who stays in the queue for more
 /// than [Environment::queue_seen_timeout] is dropped from the
 /// queue by the coordinator.
@@ -2384,8 +2454,20 @@ fn round_on_groth16_bw6_761() {
     execute_round(ProvingSystem::Groth16, CurveKind::BW6).unwrap();
 }
 
+#[test]
+#[serial]
+fn round_on_groth16_bls12_381() {
+    execute_round(ProvingSystem::Groth16, CurveKind::Bls12_381).unwrap();
+}
+
 #[test]
 #[serial]
 fn round_on_marlin_bls12_377() {
     execute_round(ProvingSystem::Marlin, CurveKind::Bls12_377).unwrap();
 }
+
+#[test]
+#[serial]
+fn round_on_kzg_bls12_377() {
+    execute_round(ProvingSystem::Kzg, CurveKind::Bls12_377).unwrap();
+}
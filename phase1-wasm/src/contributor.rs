@@ -8,6 +8,7 @@ use anyhow::Context;
 use js_sys::{Function, Promise};
 use rand::{CryptoRng, Rng};
 use setup1_shared::structures::{LockResponse, PublicSettings, SetupKind};
+use setup_utils::Zeroizing;
 use snarkvm_dpc::{parameters::testnet2::Testnet2Parameters, PrivateKey};
 use std::str::FromStr;
 use url::Url;
@@ -47,8 +48,17 @@ extern "C" {
 /// Performs a full ceremony round, and returns only when all chunks have been
 /// contributed to. Takes in a coordinator URL, an Aleo private key and a
 /// hash of the confirmation key.
+///
+/// The optional `on_progress` callback is called with the processed and total
+/// batches of the chunk being contributed to, and cancels the contribution by
+/// returning `false`.
 #[wasm_bindgen]
-pub async fn contribute(server_url: String, private_key: String, confirmation_key: String) -> Result<JsValue, JsValue> {
+pub async fn contribute(
+    server_url: String,
+    private_key: String,
+    confirmation_key: String,
+    on_progress: Option<Function>,
+) -> Result<JsValue, JsValue> {
     console_log::init_with_level(log::Level::Debug).map_err(map_js_err)?;
     let server_url = Url::parse(&server_url).map_err(map_js_err)?;
     let mut rng = rand::thread_rng();
@@ -80,14 +90,23 @@ pub async fn contribute(server_url: String, private_key: String, confirmation_ke
         .map_err(map_js_err)?;
 
     let worker_pool = WorkerProcess::new(DEFAULT_THREAD_COUNT)?;
-    let seed: [u8; 32] = rng.gen();
+    // The seed of every chunk contribution, which is wiped once the contribution is finished.
+    let seed: Zeroizing<[u8; 32]> = Zeroizing::new(rng.gen());
 
     loop {
         send_heartbeat(&private_key, &server_url, &mut rng).await?;
 
-        let is_finished = attempt_contribution(settings, &private_key, &server_url, &seed, &mut rng, &worker_pool)
-            .await
-            .map_err(map_js_err)?;
+        let is_finished = attempt_contribution(
+            settings,
+            &private_key,
+            &server_url,
+            &seed[..],
+            &mut rng,
+            &worker_pool,
+            on_progress.as_ref(),
+        )
+        .await
+        .map_err(map_js_err)?;
 
         if is_finished {
             break;
@@ -104,6 +123,7 @@ async fn attempt_contribution<R: Rng + CryptoRng>(
     seed: &[u8],
     rng: &mut R,
     worker_pool: &WorkerProcess,
+    on_progress: Option<&Function>,
 ) -> anyhow::Result<bool> {
     let tasks_left = match get_tasks_left(private_key, server_url, rng).await {
         Ok(b) => b,
@@ -139,6 +159,17 @@ async fn attempt_contribution<R: Rng + CryptoRng>(
     log::info!("Challenge downloaded ({} bytes).", chunk_bytes.len());
 
     log::info!("Performing contribution calculations...");
+    let report_progress = |processed: usize, total: usize| match on_progress {
+        Some(callback) => callback
+            .call2(
+                &JsValue::NULL,
+                &JsValue::from(processed as u32),
+                &JsValue::from(total as u32),
+            )
+            .map(|result| result.as_bool() != Some(false))
+            .unwrap_or(true),
+        None => true,
+    };
     let result = Phase1WASM::contribute_chunked(
         settings,
         response.chunk_id as usize,
@@ -146,6 +177,7 @@ async fn attempt_contribution<R: Rng + CryptoRng>(
         chunk_bytes.to_vec(),
         &worker_pool,
         DEFAULT_THREAD_COUNT,
+        &report_progress,
     )
     .context("Error while performing contribution calculations")?;
     log::info!("Finished contribution calculations!");
@@ -3,16 +3,23 @@ use phase1::{ContributionMode, Phase1, Phase1Parameters, ProvingSystem};
 #[cfg(not(test))]
 use phase1::helpers::{curve_from_str, proving_system_from_str, CurveKind};
 
-use setup_utils::{calculate_hash, CheckForCorrectness, UseCompression};
+use setup_utils::{calculate_hash, CheckForCorrectness, Progress, UseCompression};
 
 #[cfg(not(test))]
-use setup_utils::{derive_rng_from_seed, get_rng, user_system_randomness};
+use setup_utils::{curves::Bls12_381, derive_rng_from_seed, gather_entropy, get_rng, EntropySource};
 use snarkvm_curves::PairingEngine;
 
 #[cfg(not(test))]
 use snarkvm_curves::{bls12_377::Bls12_377, bw6_761::BW6_761};
 
 use rand::{CryptoRng, Rng};
+#[cfg(not(test))]
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    mpsc::{self, Sender},
+    Arc,
+    Mutex,
+};
 use wasm_bindgen::prelude::*;
 
 pub(crate) const COMPRESSED_INPUT: UseCompression = UseCompression::No;
@@ -62,23 +69,39 @@ impl Phase1WASM {
         batch_size: usize,
         power: usize,
         challenge: &[u8],
+        progress: &dyn Progress,
     ) -> anyhow::Result<ContributionResponse> {
-        let rng = get_rng(&user_system_randomness());
+        // There is no stdin to read random text from in the browser.
+        let (seed, _) = gather_entropy(&EntropySource::non_interactive())
+            .map_err(|e| anyhow::anyhow!("could not gather entropy: {}", e))?;
+        let rng = get_rng(&seed);
         let proving_system = proving_system_from_str(proving_system).expect("invalid proving system");
         match curve_from_str(curve_kind).expect("invalid curve_kind") {
             CurveKind::Bls12_377 => contribute_challenge(
                 &challenge,
                 &get_parameters_full::<Bls12_377>(proving_system, power, batch_size),
                 rng,
+                progress,
+            ),
+            CurveKind::Bls12_381 => contribute_challenge(
+                &challenge,
+                &get_parameters_full::<Bls12_381>(proving_system, power, batch_size),
+                rng,
+                progress,
             ),
             CurveKind::BW6 => contribute_challenge(
                 &challenge,
                 &get_parameters_full::<BW6_761>(proving_system, power, batch_size),
                 rng,
+                progress,
             ),
         }
     }
 
+    /// Contributes to a chunk on the given pool of web workers.
+    ///
+    /// `on_progress` is called on this thread with the processed and total batches of the
+    /// contribution, and cancels it by returning `false`.
     pub fn contribute_chunked(
         settings: &Settings,
         chunk_index: usize,
@@ -86,6 +109,7 @@ impl Phase1WASM {
         challenge: Vec<u8>,
         worker: &crate::pool::WorkerProcess,
         thread_pool_size: usize,
+        on_progress: &dyn Fn(usize, usize) -> bool,
     ) -> anyhow::Result<ContributionResponse> {
         let Settings {
             curve_kind,
@@ -104,24 +128,79 @@ impl Phase1WASM {
         let rng = derive_rng_from_seed(seed);
         let proving_system = proving_system_from_str(proving_system).expect("invalid proving system");
 
-        let (tx, rx) = oneshot::channel();
-        thread_pool.install(|| {
+        // The contribution is spawned on the pool, so that this thread can forward its progress.
+        let (sender, receiver) = mpsc::channel();
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let progress = ForwardedProgress {
+            sender: Mutex::new(sender.clone()),
+            cancelled: cancelled.clone(),
+        };
+        thread_pool.spawn(move || {
             let res = match curve_from_str(curve_kind).expect("invalid curve_kind") {
                 CurveKind::Bls12_377 => contribute_challenge(
                     &challenge,
                     &get_parameters_chunked::<Bls12_377>(proving_system, power, batch_size, chunk_index, chunk_size),
                     rng,
+                    &progress,
+                ),
+                CurveKind::Bls12_381 => contribute_challenge(
+                    &challenge,
+                    &get_parameters_chunked::<Bls12_381>(proving_system, power, batch_size, chunk_index, chunk_size),
+                    rng,
+                    &progress,
                 ),
                 CurveKind::BW6 => contribute_challenge(
                     &challenge,
                     &get_parameters_chunked::<BW6_761>(proving_system, power, batch_size, chunk_index, chunk_size),
                     rng,
+                    &progress,
                 ),
             };
-            drop(tx.send(res));
+            drop(sender.send(ContributionMessage::Done(res)));
         });
 
-        rx.recv().unwrap()
+        for message in receiver {
+            match message {
+                ContributionMessage::Progress(processed, total) => {
+                    if !on_progress(processed, total) {
+                        cancelled.store(true, Ordering::SeqCst);
+                    }
+                }
+                ContributionMessage::Done(res) => return res,
+            }
+        }
+        Err(anyhow::anyhow!("the contribution stopped without a result"))
+    }
+}
+
+/// A message from a contribution running on the thread pool.
+#[cfg(not(test))]
+enum ContributionMessage {
+    Progress(usize, usize),
+    Done(anyhow::Result<ContributionResponse>),
+}
+
+/// Forwards the progress of a contribution on the thread pool to the thread which waits for it,
+/// as the callbacks of the browser can only be called from that thread.
+#[cfg(not(test))]
+struct ForwardedProgress {
+    sender: Mutex<Sender<ContributionMessage>>,
+    cancelled: Arc<AtomicBool>,
+}
+
+#[cfg(not(test))]
+impl Progress for ForwardedProgress {
+    fn on_progress(&self, processed: usize, total: usize) {
+        drop(
+            self.sender
+                .lock()
+                .unwrap()
+                .send(ContributionMessage::Progress(processed, total)),
+        );
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
     }
 }
 
@@ -154,6 +233,7 @@ pub fn contribute_challenge<E: PairingEngine + Sync>(
     challenge: &[u8],
     parameters: &Phase1Parameters<E>,
     mut rng: impl Rng + CryptoRng,
+    progress: &dyn Progress,
 ) -> anyhow::Result<ContributionResponse> {
     let expected_challenge_length = match COMPRESSED_INPUT {
         UseCompression::Yes => parameters.contribution_size,
@@ -188,7 +268,7 @@ pub fn contribute_challenge<E: PairingEngine + Sync>(
         };
 
     // This computes a transformation and writes it
-    match Phase1::computation(
+    match Phase1::computation_with_progress(
         &challenge,
         &mut response,
         COMPRESSED_INPUT,
@@ -196,6 +276,7 @@ pub fn contribute_challenge<E: PairingEngine + Sync>(
         CHECK_INPUT_CORRECTNESS,
         &private_key,
         &parameters,
+        progress,
     ) {
         Ok(_) => match public_key.write(&mut response, COMPRESSED_OUTPUT, &parameters) {
             Ok(_) => {
@@ -211,6 +292,9 @@ pub fn contribute_challenge<E: PairingEngine + Sync>(
                 return Err(e.into());
             }
         },
+        Err(setup_utils::Error::Cancelled) => {
+            return Err(anyhow::anyhow!("the contribution was cancelled"));
+        }
         Err(_) => {
             return Err(anyhow::anyhow!("must contribute with the key"));
         }
@@ -1,6 +1,6 @@
 use crate::phase1::*;
 use phase1::{ContributionMode, Phase1, Phase1Parameters, ProvingSystem};
-use setup_utils::{batch_exp, blank_hash, generate_powers_of_tau, UseCompression};
+use setup_utils::{batch_exp, blank_hash, generate_powers_of_tau, NoProgress, UseCompression};
 use snarkvm_curves::{bls12_377::Bls12_377, bw6_761::BW6_761, PairingEngine};
 use snarkvm_fields::{batch_inversion, Field};
 
@@ -36,7 +36,7 @@ fn contribute_challenge_test<E: PairingEngine + Sync>(parameters: &Phase1Paramet
     let (_, privkey): (phase1::PublicKey<E>, phase1::PrivateKey<E>) =
         Phase1::key_generation(&mut rng, current_accumulator_hash.as_ref()).expect("could not generate keypair");
 
-    let output = contribute_challenge(&input, parameters, ChaChaRng::seed_from_u64(0))
+    let output = contribute_challenge(&input, parameters, ChaChaRng::seed_from_u64(0), &NoProgress)
         .unwrap()
         .response;
 
@@ -45,7 +45,7 @@ fn contribute_challenge_test<E: PairingEngine + Sync>(parameters: &Phase1Paramet
     let (min, max) = match parameters.contribution_mode {
         ContributionMode::Full => match parameters.proving_system {
             ProvingSystem::Groth16 => (0, parameters.powers_g1_length),
-            ProvingSystem::Marlin => (0, parameters.powers_length),
+            ProvingSystem::Marlin | ProvingSystem::Kzg => (0, parameters.powers_length),
         },
         ContributionMode::Chunked => match parameters.proving_system {
             ProvingSystem::Groth16 => (
@@ -55,7 +55,7 @@ fn contribute_challenge_test<E: PairingEngine + Sync>(parameters: &Phase1Paramet
                     (parameters.chunk_index + 1) * parameters.chunk_size,
                 ),
             ),
-            ProvingSystem::Marlin => (
+            ProvingSystem::Marlin | ProvingSystem::Kzg => (
                 parameters.chunk_index * parameters.chunk_size,
                 std::cmp::min(
                     parameters.powers_length,
@@ -136,13 +136,26 @@ fn contribute_challenge_test<E: PairingEngine + Sync>(parameters: &Phase1Paramet
                 .unwrap();
             }
         }
+        ProvingSystem::Kzg => {
+            let tau_powers = generate_powers_of_tau::<E>(&privkey.tau, min, max);
+            batch_exp(
+                &mut before.tau_powers_g1,
+                &tau_powers[0..parameters.g1_chunk_size],
+                None,
+            )
+            .unwrap();
+
+            if parameters.chunk_index == 0 || parameters.contribution_mode == ContributionMode::Full {
+                batch_exp(&mut before.tau_powers_g2, &tau_powers[0..2], None).unwrap();
+            }
+        }
     }
     assert_eq!(deserialized, before);
 }
 
 #[wasm_bindgen_test]
 pub fn test_phase1_contribute_bls12_377_full() {
-    for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin] {
+    for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin, ProvingSystem::Kzg] {
         contribute_challenge_test(&get_parameters_full::<Bls12_377>(*proving_system, 2, 2));
         // Works even when the batch is larger than the powers
         contribute_challenge_test(&get_parameters_full::<Bls12_377>(*proving_system, 6, 128));
@@ -151,7 +164,7 @@ pub fn test_phase1_contribute_bls12_377_full() {
 
 #[wasm_bindgen_test]
 fn test_phase1_contribute_bw6_761_full() {
-    for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin] {
+    for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin, ProvingSystem::Kzg] {
         contribute_challenge_test(&get_parameters_full::<BW6_761>(*proving_system, 2, 2));
         // Works even when the batch is larger than the powers
         contribute_challenge_test(&get_parameters_full::<BW6_761>(*proving_system, 6, 128));
@@ -160,12 +173,12 @@ fn test_phase1_contribute_bw6_761_full() {
 
 #[wasm_bindgen_test]
 pub fn test_phase1_contribute_bls12_377_chunked() {
-    for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin] {
+    for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin, ProvingSystem::Kzg] {
         let powers = 10;
         let chunk_size = 3 + 3 * powers + 1; // to ensure the Marlin extra elements fit in chunk 0
         let num_chunks = match *proving_system {
             ProvingSystem::Groth16 => (((1 << powers) << 1) - 1 + chunk_size - 1) / chunk_size,
-            ProvingSystem::Marlin => ((1 << powers) + chunk_size - 1) / chunk_size,
+            ProvingSystem::Marlin | ProvingSystem::Kzg => ((1 << powers) + chunk_size - 1) / chunk_size,
         };
         for i in 0..num_chunks {
             contribute_challenge_test(&get_parameters_chunked::<Bls12_377>(
@@ -181,12 +194,12 @@ pub fn test_phase1_contribute_bls12_377_chunked() {
 
 #[wasm_bindgen_test]
 fn test_phase1_contribute_bw6_761_chunked() {
-    for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin] {
+    for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin, ProvingSystem::Kzg] {
         let powers = 10;
         let chunk_size = 3 + 3 * powers + 1; // to ensure the Marlin extra elements fit in chunk 0
         let num_chunks = match *proving_system {
             ProvingSystem::Groth16 => (((1 << powers) << 1) - 1 + chunk_size - 1) / chunk_size,
-            ProvingSystem::Marlin => ((1 << powers) + chunk_size - 1) / chunk_size,
+            ProvingSystem::Marlin | ProvingSystem::Kzg => ((1 << powers) + chunk_size - 1) / chunk_size,
         };
         for i in 0..num_chunks {
             contribute_challenge_test(&get_parameters_chunked::<BW6_761>(
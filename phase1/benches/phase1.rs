@@ -16,7 +16,7 @@ use rand::thread_rng;
 fn benchmark_initialization(c: &mut Criterion) {
     // Iterate over all combinations of the following parameters
     let compressions = &[UseCompression::Yes, UseCompression::No];
-    let proving_system = &[ProvingSystem::Groth16, ProvingSystem::Marlin];
+    let proving_system = &[ProvingSystem::Groth16, ProvingSystem::Marlin, ProvingSystem::Kzg];
 
     let mut group = c.benchmark_group("initialization");
 
@@ -49,7 +49,7 @@ fn benchmark_computation(c: &mut Criterion) {
     let compressed_output = UseCompression::Yes;
 
     // Iterate over all combinations of the following parameters
-    let proving_system = &[ProvingSystem::Groth16, ProvingSystem::Marlin];
+    let proving_system = &[ProvingSystem::Groth16, ProvingSystem::Marlin, ProvingSystem::Kzg];
 
     let batch = 256;
     let mut group = c.benchmark_group(format!("computation_{}", batch));
@@ -105,7 +105,7 @@ fn benchmark_verification(c: &mut Criterion) {
         (UseCompression::Yes, UseCompression::No),
         (UseCompression::No, UseCompression::No),
     ];
-    let proving_system = &[ProvingSystem::Groth16, ProvingSystem::Marlin];
+    let proving_system = &[ProvingSystem::Groth16, ProvingSystem::Marlin, ProvingSystem::Kzg];
     let powers = (4..12).map(|i| 2u32.pow(i) as usize);
     let batch = 256;
 
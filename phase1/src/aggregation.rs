@@ -9,9 +9,24 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
     /// the output buffer.
     ///
     pub fn aggregation(
+        inputs: &[(&[u8], UseCompression)],
+        output: (&mut [u8], UseCompression),
+        parameters: &Phase1Parameters<E>,
+    ) -> Result<()> {
+        Self::aggregation_with_progress(inputs, output, parameters, &NoProgress)
+    }
+
+    ///
+    /// Phase 1: Aggregation, which reports the aggregated chunks to `progress`.
+    ///
+    /// Returns `Error::Cancelled` if `progress` cancels the aggregation, in which
+    /// case the output buffer is only partially written.
+    ///
+    pub fn aggregation_with_progress(
         inputs: &[(&[u8], UseCompression)],
         (output, compressed_output): (&mut [u8], UseCompression),
         parameters: &Phase1Parameters<E>,
+        progress: &dyn Progress,
     ) -> Result<()> {
         let span = info_span!("phase1-aggregation");
         let _enter = span.enter();
@@ -19,6 +34,8 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
         info!("starting...");
 
         for (chunk_index, (input, compressed_input)) in inputs.iter().enumerate() {
+            check_cancelled(progress)?;
+
             let chunk_parameters =
                 parameters.into_chunk_parameters(parameters.contribution_mode, chunk_index, parameters.chunk_size);
 
@@ -113,7 +130,7 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
                     });
                 }
 
-                ProvingSystem::Marlin => {
+                ProvingSystem::Marlin | ProvingSystem::Kzg => {
                     rayon::scope(|t| {
                         let _enter = span.enter();
 
@@ -172,6 +189,7 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
             }
 
             debug!("chunk {} processing successful", chunk_index);
+            progress.on_progress(chunk_index + 1, inputs.len());
         }
 
         info!("phase1-aggregation complete");
@@ -196,12 +214,12 @@ mod tests {
     ) {
         let correctness = CheckForCorrectness::Full;
 
-        for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin] {
+        for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin, ProvingSystem::Kzg] {
             let powers_length = 1 << powers;
             let powers_g1_length = (powers_length << 1) - 1;
             let powers_length_for_proving_system = match *proving_system {
                 ProvingSystem::Groth16 => powers_g1_length,
-                ProvingSystem::Marlin => powers_length,
+                ProvingSystem::Marlin | ProvingSystem::Kzg => powers_length,
             };
             let num_chunks = (powers_length_for_proving_system + batch - 1) / batch;
 
@@ -372,7 +390,14 @@ mod tests {
             Phase1::aggregation(&full_contribution, (&mut output, compressed_output), &parameters).unwrap();
 
             let parameters = Phase1Parameters::<E>::new_full(*proving_system, powers, batch);
-            assert!(Phase1::aggregate_verification((&output, compressed_output, correctness), &parameters,).is_ok());
+            for strategy in &[VerificationStrategy::PerBatch, VerificationStrategy::Batched] {
+                assert!(Phase1::aggregate_verification(
+                    (&output, compressed_output, correctness),
+                    *strategy,
+                    &parameters
+                )
+                .is_ok());
+            }
         }
     }
 
@@ -0,0 +1,343 @@
+//! Checkpoints of a resumable computation.
+//!
+//! A resumable computation reports a checkpoint after every batch, which contains the
+//! number of completed batches and a hash of the elements they have written, chained
+//! batch after batch. When the computation is resumed, the hash is recomputed from the
+//! partially written output buffer, so that a buffer which was not fully persisted before
+//! an interruption is detected instead of silently producing an invalid contribution.
+use super::*;
+
+/// The size of an encoded checkpoint in bytes.
+pub const CHECKPOINT_SIZE: usize = 8 + 64;
+
+/// The progress of a resumable computation.
+///
+/// It does not contain any secret: the private key must be regenerated from the same
+/// seed when the computation is resumed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Checkpoint {
+    /// The number of batches which have been written to the output buffer.
+    pub completed_batches: usize,
+    /// The chained hash of the elements written by the completed batches.
+    pub hash: GenericArray<u8, U64>,
+}
+
+impl Checkpoint {
+    /// Encodes the checkpoint.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(CHECKPOINT_SIZE);
+        bytes.extend_from_slice(&(self.completed_batches as u64).to_le_bytes());
+        bytes.extend_from_slice(self.hash.as_slice());
+        bytes
+    }
+
+    /// Decodes a checkpoint.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != CHECKPOINT_SIZE {
+            return Err(Error::InvalidCheckpoint(format!(
+                "the checkpoint should be {} bytes, but it's {}",
+                CHECKPOINT_SIZE,
+                bytes.len()
+            )));
+        }
+        let mut completed_batches = [0u8; 8];
+        completed_batches.copy_from_slice(&bytes[0..8]);
+
+        Ok(Self {
+            completed_batches: u64::from_le_bytes(completed_batches) as usize,
+            hash: GenericArray::clone_from_slice(&bytes[8..]),
+        })
+    }
+}
+
+/// The ranges of the TauG1 elements and of the other elements written by a batch,
+/// relative to the chunk.
+pub(crate) type BatchRanges = ((usize, usize), Option<(usize, usize)>);
+
+/// Returns the ranges of the elements written by the batch `[start, end)`.
+pub(crate) fn batch_ranges<E: PairingEngine>(
+    parameters: &Phase1Parameters<E>,
+    start: usize,
+    end: usize,
+) -> BatchRanges {
+    // Determine the chunk start and end indices based on the contribution mode.
+    let offset = match parameters.contribution_mode {
+        ContributionMode::Chunked => parameters.chunk_index * parameters.chunk_size,
+        ContributionMode::Full => 0,
+    };
+
+    let others = match parameters.proving_system {
+        ProvingSystem::Groth16 if start < parameters.powers_length => {
+            // if the `end` would be out of bounds, then just process until
+            // the end (this is necessary in case the last batch would try to
+            // process more elements than available)
+            let max = match parameters.contribution_mode {
+                ContributionMode::Chunked => std::cmp::min(
+                    (parameters.chunk_index + 1) * parameters.chunk_size,
+                    parameters.powers_length,
+                ),
+                ContributionMode::Full => parameters.powers_length,
+            };
+            let end = if start + parameters.batch_size > max { max } else { end };
+            Some((start - offset, end - offset))
+        }
+        _ => None,
+    };
+
+    ((start - offset, end - offset), others)
+}
+
+/// Returns the hash of the elements written before the first batch, which starts the chain.
+pub(crate) fn initial_hash<E: PairingEngine>(
+    parameters: &Phase1Parameters<E>,
+    (_, tau_g2, alpha_g1, _, beta_g2): (&[u8], &[u8], &[u8], &[u8], &[u8]),
+) -> GenericArray<u8, U64> {
+    match parameters.proving_system {
+        ProvingSystem::Groth16 => chain_hash(&[], &[beta_g2]),
+        ProvingSystem::Marlin | ProvingSystem::Kzg => chain_hash(&[], &[tau_g2, alpha_g1]),
+    }
+}
+
+/// Chains the hash of the elements written by a batch to the previous hash.
+pub(crate) fn batch_hash<E: PairingEngine>(
+    previous: &[u8],
+    (tau_g1, tau_g2, alpha_g1, beta_g1): (&[u8], &[u8], &[u8], &[u8]),
+    (tau_g1_range, others_range): BatchRanges,
+    compressed: UseCompression,
+) -> GenericArray<u8, U64> {
+    let g1_size = buffer_size::<E::G1Affine>(compressed);
+    let g2_size = buffer_size::<E::G2Affine>(compressed);
+
+    let mut parts = vec![&tau_g1[tau_g1_range.0 * g1_size..tau_g1_range.1 * g1_size]];
+    if let Some((start, end)) = others_range {
+        parts.push(&tau_g2[start * g2_size..end * g2_size]);
+        parts.push(&alpha_g1[start * g1_size..end * g1_size]);
+        parts.push(&beta_g1[start * g1_size..end * g1_size]);
+    }
+    chain_hash(previous, &parts)
+}
+
+/// Checks that the batches completed in a partially written output buffer match the checkpoint,
+/// and that they were computed from the input with the given private key.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn check_checkpoint<E: PairingEngine>(
+    input: &[u8],
+    output: &[u8],
+    compressed_input: UseCompression,
+    compressed_output: UseCompression,
+    check_input_for_correctness: CheckForCorrectness,
+    key: &PrivateKey<E>,
+    parameters: &Phase1Parameters<E>,
+    checkpoint: &Checkpoint,
+) -> Result<()> {
+    let (tau_g1, tau_g2, alpha_g1, beta_g1, beta_g2) = split(output, parameters, compressed_output);
+
+    // Replay the hash chain of the completed batches.
+    let mut hash = initial_hash(parameters, (tau_g1, tau_g2, alpha_g1, beta_g1, beta_g2));
+    let mut batches = 0;
+    let mut last_element = None;
+    iter_chunk_from(parameters, 0, &NoProgress, |batch, start, end| {
+        batches += 1;
+        if batch < checkpoint.completed_batches {
+            let ranges = batch_ranges(parameters, start, end);
+            hash = batch_hash::<E>(&hash, (tau_g1, tau_g2, alpha_g1, beta_g1), ranges, compressed_output);
+            // Remember the index of the last TauG1 element in the chunk, and its power.
+            last_element = Some((ranges.0 .1 - 1, end - 1));
+        }
+        Ok(())
+    })?;
+
+    if checkpoint.completed_batches > batches {
+        return Err(Error::InvalidCheckpoint(format!(
+            "{} batches are completed, but the chunk only has {}",
+            checkpoint.completed_batches, batches
+        )));
+    }
+    if hash != checkpoint.hash {
+        return Err(Error::InvalidCheckpoint(
+            "the output does not match the checkpoint".to_string(),
+        ));
+    }
+
+    // Recompute the last completed TauG1 element, which must have been computed with the same key.
+    if let Some((index, power)) = last_element {
+        let (tau_g1_inputs, _, _, _, _) = split(input, parameters, compressed_input);
+        let in_size = buffer_size::<E::G1Affine>(compressed_input);
+        let out_size = buffer_size::<E::G1Affine>(compressed_output);
+
+        let mut element = vec![0; out_size];
+        let mut powers = generate_powers_of_tau::<E>(&key.tau, power, power + 1);
+        let result = apply_powers::<E::G1Affine>(
+            (&mut element, compressed_output),
+            (
+                &tau_g1_inputs[index * in_size..(index + 1) * in_size],
+                compressed_input,
+                check_input_for_correctness,
+            ),
+            (0, 1),
+            &powers,
+            None,
+        );
+        zeroize_fields(&mut powers);
+        result?;
+
+        if element[..] != tau_g1[index * out_size..(index + 1) * out_size] {
+            return Err(Error::InvalidCheckpoint(
+                "the completed batches were computed with another input or private key".to_string(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::testing::generate_input;
+
+    use snarkvm_curves::bls12_377::Bls12_377;
+
+    fn resume_test<E: PairingEngine + Sync>(proving_system: ProvingSystem) {
+        let parameters = Phase1Parameters::<E>::new_full(proving_system, 4, 2);
+        let compressed_input = UseCompression::No;
+        let compressed_output = UseCompression::Yes;
+        let (input, _) = generate_input(&parameters, compressed_input, CheckForCorrectness::No);
+
+        let key = |seed: &[u8]| {
+            let mut rng = derive_rng_from_seed(seed);
+            Phase1::key_generation(&mut rng, blank_hash().as_ref()).unwrap().1
+        };
+        let private_key = key(b"resume_test");
+
+        // The expected output of an uninterrupted computation.
+        let mut expected = vec![0; parameters.get_length(compressed_output)];
+        Phase1::computation(
+            &input,
+            &mut expected,
+            compressed_input,
+            compressed_output,
+            CheckForCorrectness::No,
+            &private_key,
+            &parameters,
+        )
+        .unwrap();
+
+        // Interrupt the computation after 3 batches.
+        let mut output = vec![0; parameters.get_length(compressed_output)];
+        let mut checkpoint = None;
+        let result = Phase1::resumable_computation(
+            &input,
+            &mut output,
+            compressed_input,
+            compressed_output,
+            CheckForCorrectness::No,
+            &private_key,
+            &parameters,
+            &NoProgress,
+            None,
+            &mut |current: &Checkpoint| {
+                checkpoint = Some(current.clone());
+                match current.completed_batches {
+                    3 => Err(Error::Cancelled),
+                    _ => Ok(()),
+                }
+            },
+        );
+        assert!(matches!(result, Err(Error::Cancelled)));
+        let checkpoint = Checkpoint::from_bytes(&checkpoint.unwrap().to_bytes()).unwrap();
+        assert_eq!(checkpoint.completed_batches, 3);
+
+        // Resuming with another key is rejected.
+        let other_key = key(b"another seed");
+        let result = Phase1::resumable_computation(
+            &input,
+            &mut output.clone(),
+            compressed_input,
+            compressed_output,
+            CheckForCorrectness::No,
+            &other_key,
+            &parameters,
+            &NoProgress,
+            Some(&checkpoint),
+            &mut |_: &Checkpoint| Ok(()),
+        );
+        assert!(matches!(result, Err(Error::InvalidCheckpoint(_))));
+
+        // Resuming from an output which was not fully persisted is rejected.
+        let mut lost = output.clone();
+        let g1_size = buffer_size::<E::G1Affine>(compressed_output);
+        lost[parameters.hash_size + g1_size..parameters.hash_size + 2 * g1_size].copy_from_slice(&vec![0; g1_size]);
+        let result = Phase1::resumable_computation(
+            &input,
+            &mut lost,
+            compressed_input,
+            compressed_output,
+            CheckForCorrectness::No,
+            &private_key,
+            &parameters,
+            &NoProgress,
+            Some(&checkpoint),
+            &mut |_: &Checkpoint| Ok(()),
+        );
+        assert!(matches!(result, Err(Error::InvalidCheckpoint(_))));
+
+        // Resuming with the same key, and interrupting the computation again after 5 batches.
+        let mut resumed_batches = vec![];
+        let mut second_checkpoint = None;
+        let result = Phase1::resumable_computation(
+            &input,
+            &mut output,
+            compressed_input,
+            compressed_output,
+            CheckForCorrectness::No,
+            &private_key,
+            &parameters,
+            &NoProgress,
+            Some(&checkpoint),
+            &mut |current: &Checkpoint| {
+                resumed_batches.push(current.completed_batches);
+                second_checkpoint = Some(current.clone());
+                match current.completed_batches {
+                    5 => Err(Error::Cancelled),
+                    _ => Ok(()),
+                }
+            },
+        );
+        assert!(matches!(result, Err(Error::Cancelled)));
+        assert_eq!(resumed_batches, vec![4, 5]);
+        let second_checkpoint = second_checkpoint.unwrap();
+
+        // The checkpoint written after resuming can be resumed from, and produces the output
+        // of an uninterrupted computation.
+        let mut resumed_batches = vec![];
+        Phase1::resumable_computation(
+            &input,
+            &mut output,
+            compressed_input,
+            compressed_output,
+            CheckForCorrectness::No,
+            &private_key,
+            &parameters,
+            &NoProgress,
+            Some(&second_checkpoint),
+            &mut |current: &Checkpoint| {
+                resumed_batches.push(current.completed_batches);
+                Ok(())
+            },
+        )
+        .unwrap();
+        assert_eq!(resumed_batches[0], 6);
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_resume_computation_groth16() {
+        resume_test::<Bls12_377>(ProvingSystem::Groth16);
+    }
+
+    #[test]
+    fn test_resume_computation_marlin() {
+        resume_test::<Bls12_377>(ProvingSystem::Marlin);
+    }
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::checkpoint::{batch_hash, batch_ranges, check_checkpoint, initial_hash};
 use snarkvm_fields::{batch_inversion, Field};
 
 impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
@@ -18,12 +19,126 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
         check_input_for_correctness: CheckForCorrectness,
         key: &PrivateKey<E>,
         parameters: &'a Phase1Parameters<E>,
+    ) -> Result<()> {
+        Self::computation_with_progress(
+            input,
+            output,
+            compressed_input,
+            compressed_output,
+            check_input_for_correctness,
+            key,
+            parameters,
+            &NoProgress,
+        )
+    }
+
+    ///
+    /// Phase 1 - Computation, which reports the processed batches to `progress`.
+    ///
+    /// Returns `Error::Cancelled` if `progress` cancels the computation, in which
+    /// case the output buffer is only partially written.
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub fn computation_with_progress(
+        input: &[u8],
+        output: &mut [u8],
+        compressed_input: UseCompression,
+        compressed_output: UseCompression,
+        check_input_for_correctness: CheckForCorrectness,
+        key: &PrivateKey<E>,
+        parameters: &'a Phase1Parameters<E>,
+        progress: &dyn Progress,
+    ) -> Result<()> {
+        Self::computation_from_checkpoint(
+            input,
+            output,
+            compressed_input,
+            compressed_output,
+            check_input_for_correctness,
+            key,
+            parameters,
+            progress,
+            None,
+            None,
+        )
+    }
+
+    ///
+    /// Phase 1 - Computation, which can be resumed after an interruption.
+    ///
+    /// `on_checkpoint` is called after every batch with a checkpoint of the completed batches,
+    /// which can be persisted next to the output buffer. An interrupted computation is resumed
+    /// by passing its last checkpoint along with the partially written output buffer, the same
+    /// input and the same private key. The checkpoint does not contain the private key, which
+    /// must be regenerated from the same seed, e.g. with `derive_rng_from_seed`.
+    ///
+    /// Returns `Error::InvalidCheckpoint` without modifying the output buffer if the completed
+    /// batches do not match the checkpoint, or were not computed with the same input and key.
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub fn resumable_computation(
+        input: &[u8],
+        output: &mut [u8],
+        compressed_input: UseCompression,
+        compressed_output: UseCompression,
+        check_input_for_correctness: CheckForCorrectness,
+        key: &PrivateKey<E>,
+        parameters: &'a Phase1Parameters<E>,
+        progress: &dyn Progress,
+        checkpoint: Option<&Checkpoint>,
+        on_checkpoint: &mut dyn FnMut(&Checkpoint) -> Result<()>,
+    ) -> Result<()> {
+        Self::computation_from_checkpoint(
+            input,
+            output,
+            compressed_input,
+            compressed_output,
+            check_input_for_correctness,
+            key,
+            parameters,
+            progress,
+            checkpoint,
+            Some(on_checkpoint),
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn computation_from_checkpoint(
+        input: &[u8],
+        output: &mut [u8],
+        compressed_input: UseCompression,
+        compressed_output: UseCompression,
+        check_input_for_correctness: CheckForCorrectness,
+        key: &PrivateKey<E>,
+        parameters: &'a Phase1Parameters<E>,
+        progress: &dyn Progress,
+        checkpoint: Option<&Checkpoint>,
+        mut on_checkpoint: Option<&mut dyn FnMut(&Checkpoint) -> Result<()>>,
     ) -> Result<()> {
         let span = info_span!("phase1-computation");
         let _ = span.enter();
 
         info!("starting...");
 
+        // Skip the completed batches, after checking them against the checkpoint.
+        let first_batch = match checkpoint {
+            Some(checkpoint) => {
+                check_checkpoint(
+                    input,
+                    output,
+                    compressed_input,
+                    compressed_output,
+                    check_input_for_correctness,
+                    key,
+                    parameters,
+                    checkpoint,
+                )?;
+                info!("resuming after {} completed batches", checkpoint.completed_batches);
+                checkpoint.completed_batches
+            }
+            None => 0,
+        };
+
         // Get immutable references of the input chunks.
         let (tau_g1_inputs, tau_g2_inputs, alpha_g1_inputs, beta_g1_inputs, mut beta_g2_inputs) =
             split(&input, parameters, compressed_input);
@@ -44,22 +159,31 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
                     // Write it back.
                     beta_g2_outputs.write_element(&beta_g2_el, compressed_output)?;
                 }
+                // A resumed chain continues from the hash of the checkpoint, which was checked above.
+                let mut hash = match checkpoint {
+                    Some(checkpoint) => checkpoint.hash.clone(),
+                    None => initial_hash(
+                        parameters,
+                        (
+                            tau_g1_outputs,
+                            tau_g2_outputs,
+                            alpha_g1_outputs,
+                            beta_g1_outputs,
+                            beta_g2_outputs,
+                        ),
+                    ),
+                };
 
                 // load `batch_size` chunks on each iteration and perform the transformation
-                iter_chunk(&parameters, |start, end| {
+                iter_chunk_from(&parameters, first_batch, progress, |batch, start, end| {
                     debug!("contributing to chunk from {} to {}", start, end);
 
                     let span = info_span!("batch", start, end);
                     let _ = span.enter();
 
-                    // Determine the chunk start and end indices based on the contribution mode.
-                    let (start_chunk, end_chunk) = match parameters.contribution_mode {
-                        ContributionMode::Chunked => (
-                            start - parameters.chunk_index * parameters.chunk_size,
-                            end - parameters.chunk_index * parameters.chunk_size,
-                        ),
-                        ContributionMode::Full => (start, end),
-                    };
+                    // Determine the chunk start and end indices of the elements written by the batch.
+                    let ranges = batch_ranges(parameters, start, end);
+                    let ((start_chunk, end_chunk), others_range) = ranges;
 
                     rayon_cfg::scope(|t| {
                         let _ = span.enter();
@@ -68,7 +192,7 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
                             let _ = span.enter();
 
                             // Generate powers from `start` to `end` (e.g. [0,4) then [4, 8) etc.)
-                            let powers = generate_powers_of_tau::<E>(&key.tau, start, end);
+                            let mut powers = generate_powers_of_tau::<E>(&key.tau, start, end);
 
                             trace!("generated powers of tau");
 
@@ -95,28 +219,7 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
 
                                     trace!("applied powers to tau_g1 elements");
                                 });
-                                if start < parameters.powers_length {
-                                    // if the `end` would be out of bounds, then just process until
-                                    // the end (this is necessary in case the last batch would try to
-                                    // process more elements than available)
-                                    let max = match parameters.contribution_mode {
-                                        ContributionMode::Chunked => std::cmp::min(
-                                            (parameters.chunk_index + 1) * parameters.chunk_size,
-                                            parameters.powers_length,
-                                        ),
-                                        ContributionMode::Full => parameters.powers_length,
-                                    };
-                                    let end = if start + parameters.batch_size > max { max } else { end };
-
-                                    // Determine the chunk start and end indices based on the contribution mode.
-                                    let (start_chunk, end_chunk) = match parameters.contribution_mode {
-                                        ContributionMode::Chunked => (
-                                            start - parameters.chunk_index * parameters.chunk_size,
-                                            end - parameters.chunk_index * parameters.chunk_size,
-                                        ),
-                                        ContributionMode::Full => (start, end),
-                                    };
-
+                                if let Some((start_chunk, end_chunk)) = others_range {
                                     rayon_cfg::scope(|t| {
                                         let _ = span.enter();
 
@@ -176,63 +279,84 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
                                     });
                                 }
                             });
+
+                            zeroize_fields(&mut powers);
                         });
                     });
 
                     debug!("chunk contribution successful");
 
+                    if let Some(on_checkpoint) = on_checkpoint.as_mut() {
+                        hash = batch_hash::<E>(
+                            &hash,
+                            (tau_g1_outputs, tau_g2_outputs, alpha_g1_outputs, beta_g1_outputs),
+                            ranges,
+                            compressed_output,
+                        );
+                        on_checkpoint(&Checkpoint {
+                            completed_batches: batch + 1,
+                            hash,
+                        })?;
+                    }
+
                     Ok(())
                 })?;
             }
-            ProvingSystem::Marlin => {
+            ProvingSystem::Marlin | ProvingSystem::Kzg => {
                 // we assume batch_size > 3 + 3*total_size_in_log2, allowing all the smaller amounts
                 // of powers in tau G2 and alpha tau G1 to reside there
                 if parameters.chunk_index == 0 {
-                    let degree_bound_powers = (0..parameters.total_size_in_log2)
-                        .map(|i| key.tau.pow([parameters.powers_length as u64 - 1 - (1 << i) + 2]))
-                        .collect::<Vec<_>>();
-
-                    let mut g2_inverse_powers = degree_bound_powers.clone();
-
-                    batch_inversion(&mut g2_inverse_powers);
-
-                    apply_powers::<E::G2Affine>(
-                        (tau_g2_outputs, compressed_output),
-                        (tau_g2_inputs, compressed_input, check_input_for_correctness),
-                        (2, parameters.total_size_in_log2 + 2),
-                        &g2_inverse_powers,
-                        None,
-                    )
-                    .expect("could not apply powers of tau to tau_g2 elements");
-
-                    let g1_degree_powers = degree_bound_powers
-                        .into_iter()
-                        .map(|f| vec![f, f * &key.tau, f * &key.tau.pow([2])])
-                        .flatten()
-                        .collect::<Vec<_>>();
-
-                    apply_powers::<E::G1Affine>(
-                        (alpha_g1_outputs, compressed_output),
-                        (alpha_g1_inputs, compressed_input, check_input_for_correctness),
-                        (3, 3 + 3 * parameters.total_size_in_log2),
-                        &g1_degree_powers,
-                        Some(&key.alpha),
-                    )
-                    .expect("could not apply powers of tau to tau_g2 elements");
-
-                    let num_alpha_powers = 3;
-                    let powers = generate_powers_of_tau::<E>(&key.tau, 0, num_alpha_powers);
-
-                    apply_powers::<E::G1Affine>(
-                        (alpha_g1_outputs, compressed_output),
-                        (alpha_g1_inputs, compressed_input, check_input_for_correctness),
-                        (0, num_alpha_powers),
-                        &powers,
-                        Some(&key.alpha),
-                    )
-                    .expect("could not apply powers of tau alpha to tau_g1 elements");
-
-                    let powers = generate_powers_of_tau::<E>(&key.tau, 0, 2);
+                    if parameters.proving_system == ProvingSystem::Marlin {
+                        let mut degree_bound_powers = (0..parameters.total_size_in_log2)
+                            .map(|i| key.tau.pow([parameters.powers_length as u64 - 1 - (1 << i) + 2]))
+                            .collect::<Vec<_>>();
+
+                        let mut g2_inverse_powers = degree_bound_powers.clone();
+
+                        batch_inversion(&mut g2_inverse_powers);
+
+                        apply_powers::<E::G2Affine>(
+                            (tau_g2_outputs, compressed_output),
+                            (tau_g2_inputs, compressed_input, check_input_for_correctness),
+                            (2, parameters.total_size_in_log2 + 2),
+                            &g2_inverse_powers,
+                            None,
+                        )
+                        .expect("could not apply powers of tau to tau_g2 elements");
+
+                        let mut g1_degree_powers = degree_bound_powers
+                            .iter()
+                            .map(|f| vec![*f, *f * &key.tau, *f * &key.tau.pow([2])])
+                            .flatten()
+                            .collect::<Vec<_>>();
+                        zeroize_fields(&mut degree_bound_powers);
+                        zeroize_fields(&mut g2_inverse_powers);
+
+                        apply_powers::<E::G1Affine>(
+                            (alpha_g1_outputs, compressed_output),
+                            (alpha_g1_inputs, compressed_input, check_input_for_correctness),
+                            (3, 3 + 3 * parameters.total_size_in_log2),
+                            &g1_degree_powers,
+                            Some(&key.alpha),
+                        )
+                        .expect("could not apply powers of tau to tau_g2 elements");
+                        zeroize_fields(&mut g1_degree_powers);
+
+                        let num_alpha_powers = 3;
+                        let mut powers = generate_powers_of_tau::<E>(&key.tau, 0, num_alpha_powers);
+
+                        apply_powers::<E::G1Affine>(
+                            (alpha_g1_outputs, compressed_output),
+                            (alpha_g1_inputs, compressed_input, check_input_for_correctness),
+                            (0, num_alpha_powers),
+                            &powers,
+                            Some(&key.alpha),
+                        )
+                        .expect("could not apply powers of tau alpha to tau_g1 elements");
+                        zeroize_fields(&mut powers);
+                    }
+
+                    let mut powers = generate_powers_of_tau::<E>(&key.tau, 0, 2);
 
                     apply_powers::<E::G2Affine>(
                         (tau_g2_outputs, compressed_output),
@@ -242,23 +366,33 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
                         None,
                     )
                     .expect("could not apply powers of tau to initial tau_g2 elements");
+                    zeroize_fields(&mut powers);
                 }
+                // A resumed chain continues from the hash of the checkpoint, which was checked above.
+                let mut hash = match checkpoint {
+                    Some(checkpoint) => checkpoint.hash.clone(),
+                    None => initial_hash(
+                        parameters,
+                        (
+                            tau_g1_outputs,
+                            tau_g2_outputs,
+                            alpha_g1_outputs,
+                            beta_g1_outputs,
+                            beta_g2_outputs,
+                        ),
+                    ),
+                };
 
                 // load `batch_size` chunks on each iteration and perform the transformation
-                iter_chunk(&parameters, |start, end| {
+                iter_chunk_from(&parameters, first_batch, progress, |batch, start, end| {
                     debug!("contributing to chunk from {} to {}", start, end);
 
                     let span = info_span!("batch", start, end);
                     let _ = span.enter();
 
-                    // Determine the chunk start and end indices based on the contribution mode.
-                    let (start_chunk, end_chunk) = match parameters.contribution_mode {
-                        ContributionMode::Chunked => (
-                            start - parameters.chunk_index * parameters.chunk_size,
-                            end - parameters.chunk_index * parameters.chunk_size,
-                        ),
-                        ContributionMode::Full => (start, end),
-                    };
+                    // Determine the chunk start and end indices of the elements written by the batch.
+                    let ranges = batch_ranges(parameters, start, end);
+                    let ((start_chunk, end_chunk), _) = ranges;
 
                     rayon_cfg::scope(|t| {
                         let _ = span.enter();
@@ -267,7 +401,7 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
                             let _ = span.enter();
 
                             // Generate powers from `start` to `end` (e.g. [0,4) then [4, 8) etc.)
-                            let powers = generate_powers_of_tau::<E>(&key.tau, start, end);
+                            let mut powers = generate_powers_of_tau::<E>(&key.tau, start, end);
 
                             trace!("generated powers of tau");
 
@@ -279,11 +413,26 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
                                 None,
                             )
                             .expect("could not apply powers of tau to tau_g1 elements");
+
+                            zeroize_fields(&mut powers);
                         });
                     });
 
                     debug!("chunk contribution successful");
 
+                    if let Some(on_checkpoint) = on_checkpoint.as_mut() {
+                        hash = batch_hash::<E>(
+                            &hash,
+                            (tau_g1_outputs, tau_g2_outputs, alpha_g1_outputs, beta_g1_outputs),
+                            ranges,
+                            compressed_output,
+                        );
+                        on_checkpoint(&Checkpoint {
+                            completed_batches: batch + 1,
+                            hash,
+                        })?;
+                    }
+
                     Ok(())
                 })?;
             }
@@ -303,6 +452,8 @@ mod tests {
 
     use snarkvm_curves::{bls12_377::Bls12_377, bw6_761::BW6_761};
 
+    use std::sync::Mutex;
+
     fn curve_computation_test<E: PairingEngine>(
         powers: usize,
         batch: usize,
@@ -311,7 +462,7 @@ mod tests {
     ) {
         let input_correctness = CheckForCorrectness::Full;
 
-        for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin] {
+        for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin, ProvingSystem::Kzg] {
             let parameters = Phase1Parameters::<E>::new_full(*proving_system, powers, batch);
             let expected_response_length = parameters.get_length(compressed_output);
 
@@ -408,6 +559,16 @@ mod tests {
                     )
                     .unwrap();
                 }
+                ProvingSystem::Kzg => {
+                    let tau_powers = generate_powers_of_tau::<E>(&privkey.tau, 0, parameters.powers_length);
+                    batch_exp(
+                        &mut before.tau_powers_g1,
+                        &tau_powers[0..parameters.powers_length],
+                        None,
+                    )
+                    .unwrap();
+                    batch_exp(&mut before.tau_powers_g2, &tau_powers[0..2], None).unwrap();
+                }
             }
             assert_eq!(deserialized, before);
         }
@@ -458,4 +619,54 @@ mod tests {
         // Works even if the batch is larger than the powers
         curve_computation_test::<BW6_761>(6, 128, UseCompression::No, UseCompression::No);
     }
+
+    #[test]
+    fn test_computation_with_progress() {
+        let parameters = Phase1Parameters::<Bls12_377>::new_full(ProvingSystem::Groth16, 4, 4);
+        let (input, _) = generate_input(&parameters, UseCompression::No, CheckForCorrectness::No);
+        let mut output = vec![0; parameters.get_length(UseCompression::No)];
+
+        let mut rng = derive_rng_from_seed(b"test_computation_with_progress");
+        let (_, privkey) = Phase1::key_generation(&mut rng, blank_hash().as_ref()).expect("could not generate keypair");
+
+        // Every batch is reported once, in order.
+        let reports = Mutex::new(vec![]);
+        Phase1::computation_with_progress(
+            &input,
+            &mut output,
+            UseCompression::No,
+            UseCompression::No,
+            CheckForCorrectness::No,
+            &privkey,
+            &parameters,
+            &|processed: usize, total: usize| reports.lock().unwrap().push((processed, total)),
+        )
+        .unwrap();
+        let reports = reports.into_inner().unwrap();
+        let total = reports.len();
+        assert!(total > 1);
+        assert_eq!(
+            reports,
+            (1..=total).map(|processed| (processed, total)).collect::<Vec<_>>()
+        );
+
+        struct Cancelled;
+        impl Progress for Cancelled {
+            fn is_cancelled(&self) -> bool {
+                true
+            }
+        }
+
+        let result = Phase1::computation_with_progress(
+            &input,
+            &mut output,
+            UseCompression::No,
+            UseCompression::No,
+            CheckForCorrectness::No,
+            &privkey,
+            &parameters,
+            &Cancelled,
+        );
+        assert!(matches!(result, Err(Error::Cancelled)));
+    }
 }
@@ -0,0 +1,280 @@
+//! An optional header which describes the contents of an accumulator file.
+//!
+//! Challenge and response files are raw concatenations of group elements, whose layout
+//! depends on parameters which are not stored in the file. A file can start with a
+//! fixed-size header which describes these parameters, so that readers can check that
+//! the file matches the parameters they are using:
+//!
+//! | bytes  | content                                      |
+//! |--------|----------------------------------------------|
+//! | 0..8   | the magic bytes `PH1ACCUM`                   |
+//! | 8..10  | the format version, in little-endian order   |
+//! | 10     | the curve                                    |
+//! | 11     | the proving system                           |
+//! | 12     | the power of the ceremony                    |
+//! | 13     | the contribution mode                        |
+//! | 14     | the compression of the elements              |
+//! | 15     | reserved, must be zero                       |
+//! | 16..24 | the chunk index, in little-endian order      |
+//! | 24..32 | the chunk size, in little-endian order       |
+//!
+//! The header is not part of the hash chain: the hash of a file is computed over the
+//! contents following the header, so files with and without a header can be mixed.
+use super::*;
+use crate::helpers::CurveKind;
+
+use snarkvm_fields::PrimeField;
+
+/// The magic bytes at the start of a file with a header.
+pub const HEADER_MAGIC: [u8; 8] = *b"PH1ACCUM";
+/// The version of the header format written by this crate.
+pub const HEADER_VERSION: u16 = 1;
+/// The size of the header in bytes.
+pub const HEADER_SIZE: usize = 32;
+
+/// The parameters of an accumulator file, as described by its header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileHeader {
+    pub version: u16,
+    pub curve: CurveKind,
+    pub proving_system: ProvingSystem,
+    pub total_size_in_log2: usize,
+    pub contribution_mode: ContributionMode,
+    pub chunk_index: usize,
+    pub chunk_size: usize,
+    pub compression: UseCompression,
+}
+
+impl FileHeader {
+    /// Returns the header of a file with the given parameters and compression.
+    pub fn new<E: PairingEngine>(parameters: &Phase1Parameters<E>, compression: UseCompression) -> Result<Self> {
+        Ok(Self {
+            version: HEADER_VERSION,
+            curve: curve_kind::<E>()?,
+            proving_system: parameters.proving_system,
+            total_size_in_log2: parameters.total_size_in_log2,
+            contribution_mode: parameters.contribution_mode,
+            chunk_index: parameters.chunk_index,
+            chunk_size: parameters.chunk_size,
+            compression,
+        })
+    }
+
+    /// Encodes the header.
+    pub fn to_bytes(&self) -> [u8; HEADER_SIZE] {
+        let mut bytes = [0u8; HEADER_SIZE];
+        bytes[0..8].copy_from_slice(&HEADER_MAGIC);
+        bytes[8..10].copy_from_slice(&self.version.to_le_bytes());
+        bytes[10] = match self.curve {
+            CurveKind::Bls12_377 => 0,
+            CurveKind::Bls12_381 => 1,
+            CurveKind::BW6 => 2,
+        };
+        bytes[11] = match self.proving_system {
+            ProvingSystem::Groth16 => 0,
+            ProvingSystem::Marlin => 1,
+            ProvingSystem::Kzg => 2,
+        };
+        bytes[12] = self.total_size_in_log2 as u8;
+        bytes[13] = match self.contribution_mode {
+            ContributionMode::Full => 0,
+            ContributionMode::Chunked => 1,
+        };
+        bytes[14] = match self.compression {
+            UseCompression::No => 0,
+            UseCompression::Yes => 1,
+        };
+        bytes[16..24].copy_from_slice(&(self.chunk_index as u64).to_le_bytes());
+        bytes[24..32].copy_from_slice(&(self.chunk_size as u64).to_le_bytes());
+        bytes
+    }
+
+    /// Decodes the header at the start of a file, or returns `None` if the file has no header.
+    pub fn read(file: &[u8]) -> Result<Option<Self>> {
+        if file.get(0..8) != Some(&HEADER_MAGIC[..]) {
+            return Ok(None);
+        }
+        let bytes = file
+            .get(0..HEADER_SIZE)
+            .ok_or_else(|| invalid("the header is truncated".to_string()))?;
+
+        let version = u16::from_le_bytes([bytes[8], bytes[9]]);
+        if version != HEADER_VERSION {
+            return Err(invalid(format!("unsupported version {}", version)));
+        }
+        let curve = match bytes[10] {
+            0 => CurveKind::Bls12_377,
+            1 => CurveKind::Bls12_381,
+            2 => CurveKind::BW6,
+            curve => return Err(invalid(format!("unknown curve {}", curve))),
+        };
+        let proving_system = match bytes[11] {
+            0 => ProvingSystem::Groth16,
+            1 => ProvingSystem::Marlin,
+            2 => ProvingSystem::Kzg,
+            proving_system => return Err(invalid(format!("unknown proving system {}", proving_system))),
+        };
+        let contribution_mode = match bytes[13] {
+            0 => ContributionMode::Full,
+            1 => ContributionMode::Chunked,
+            mode => return Err(invalid(format!("unknown contribution mode {}", mode))),
+        };
+        let compression = match bytes[14] {
+            0 => UseCompression::No,
+            1 => UseCompression::Yes,
+            compression => return Err(invalid(format!("unknown compression {}", compression))),
+        };
+        if bytes[15] != 0 {
+            return Err(invalid("the reserved byte is not zero".to_string()));
+        }
+        let read_u64 = |position: usize| {
+            let mut word = [0u8; 8];
+            word.copy_from_slice(&bytes[position..position + 8]);
+            u64::from_le_bytes(word) as usize
+        };
+
+        Ok(Some(Self {
+            version,
+            curve,
+            proving_system,
+            total_size_in_log2: bytes[12] as usize,
+            contribution_mode,
+            chunk_index: read_u64(16),
+            chunk_size: read_u64(24),
+            compression,
+        }))
+    }
+
+    /// Checks that the header describes a file with the given parameters and compression.
+    pub fn check<E: PairingEngine>(&self, parameters: &Phase1Parameters<E>, compression: UseCompression) -> Result<()> {
+        let expected = Self::new(parameters, compression)?;
+        let mismatch = |field: &'static str, expected: &dyn std::fmt::Debug, found: &dyn std::fmt::Debug| {
+            Err(Error::FileHeaderMismatch {
+                field,
+                expected: format!("{:?}", expected),
+                found: format!("{:?}", found),
+            })
+        };
+
+        if self.curve != expected.curve {
+            return mismatch("curve", &expected.curve, &self.curve);
+        }
+        if self.proving_system != expected.proving_system {
+            return mismatch("proving system", &expected.proving_system, &self.proving_system);
+        }
+        if self.total_size_in_log2 != expected.total_size_in_log2 {
+            return mismatch("power", &expected.total_size_in_log2, &self.total_size_in_log2);
+        }
+        if self.contribution_mode != expected.contribution_mode {
+            return mismatch(
+                "contribution mode",
+                &expected.contribution_mode,
+                &self.contribution_mode,
+            );
+        }
+        if self.chunk_index != expected.chunk_index {
+            return mismatch("chunk index", &expected.chunk_index, &self.chunk_index);
+        }
+        if self.chunk_size != expected.chunk_size {
+            return mismatch("chunk size", &expected.chunk_size, &self.chunk_size);
+        }
+        if self.compression != expected.compression {
+            return mismatch("compression", &expected.compression, &self.compression);
+        }
+        Ok(())
+    }
+}
+
+/// Splits the header from a file, and checks that it describes the given parameters
+/// and compression. A file without a header is returned as is.
+pub fn check_file_header<'b, E: PairingEngine>(
+    file: &'b [u8],
+    compression: UseCompression,
+    parameters: &Phase1Parameters<E>,
+) -> Result<(Option<FileHeader>, &'b [u8])> {
+    match FileHeader::read(file)? {
+        Some(header) => {
+            header.check(parameters, compression)?;
+            Ok((Some(header), &file[HEADER_SIZE..]))
+        }
+        None => Ok((None, file)),
+    }
+}
+
+/// Identifies the curve of a pairing engine by the size of its base field.
+fn curve_kind<E: PairingEngine>() -> Result<CurveKind> {
+    match <E::Fq as PrimeField>::size_in_bits() {
+        377 => Ok(CurveKind::Bls12_377),
+        381 => Ok(CurveKind::Bls12_381),
+        761 => Ok(CurveKind::BW6),
+        _ => Err(invalid("the curve can not be described by a header".to_string())),
+    }
+}
+
+fn invalid(message: String) -> Error {
+    Error::InvalidFileHeader(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::testing::generate_random_accumulator;
+
+    use snarkvm_curves::{bls12_377::Bls12_377, bw6_761::BW6_761};
+
+    #[test]
+    fn test_header_round_trip() {
+        let parameters =
+            Phase1Parameters::<BW6_761>::new_chunk(ContributionMode::Chunked, 3, 8, ProvingSystem::Marlin, 4, 4);
+        let header = FileHeader::new(&parameters, UseCompression::Yes).unwrap();
+        assert_eq!(header.curve, CurveKind::BW6);
+        assert_eq!(FileHeader::read(&header.to_bytes()).unwrap(), Some(header));
+        assert!(header.check(&parameters, UseCompression::Yes).is_ok());
+    }
+
+    #[test]
+    fn test_check_file_header() {
+        let parameters = Phase1Parameters::<Bls12_377>::new_full(ProvingSystem::Groth16, 4, 4);
+        let (accumulator, _) = generate_random_accumulator(&parameters, UseCompression::No);
+
+        // Files without a header are returned as is.
+        let (header, body) = check_file_header(&accumulator, UseCompression::No, &parameters).unwrap();
+        assert_eq!(header, None);
+        assert_eq!(body, &accumulator[..]);
+
+        let mut file = FileHeader::new(&parameters, UseCompression::No)
+            .unwrap()
+            .to_bytes()
+            .to_vec();
+        file.extend_from_slice(&accumulator);
+        let (header, body) = check_file_header(&file, UseCompression::No, &parameters).unwrap();
+        assert_eq!(header.unwrap().total_size_in_log2, 4);
+        assert_eq!(body, &accumulator[..]);
+
+        // The header must describe the expected parameters.
+        assert!(matches!(
+            check_file_header(&file, UseCompression::Yes, &parameters),
+            Err(Error::FileHeaderMismatch {
+                field: "compression",
+                ..
+            })
+        ));
+        let other_parameters = Phase1Parameters::<Bls12_377>::new_full(ProvingSystem::Groth16, 5, 4);
+        assert!(matches!(
+            check_file_header(&file, UseCompression::No, &other_parameters),
+            Err(Error::FileHeaderMismatch { field: "power", .. })
+        ));
+        let other_parameters = Phase1Parameters::<BW6_761>::new_full(ProvingSystem::Groth16, 4, 4);
+        assert!(matches!(
+            check_file_header(&file, UseCompression::No, &other_parameters),
+            Err(Error::FileHeaderMismatch { field: "curve", .. })
+        ));
+
+        // Unknown versions are rejected.
+        file[8] = 2;
+        assert!(matches!(
+            check_file_header(&file, UseCompression::No, &parameters),
+            Err(Error::InvalidFileHeader(_))
+        ));
+    }
+}
@@ -9,9 +9,13 @@ use snarkvm_curves::{AffineCurve, PairingEngine};
 #[cfg(not(feature = "wasm"))]
 use crate::ContributionMode;
 #[cfg(not(feature = "wasm"))]
+use snarkvm_curves::ProjectiveCurve;
+#[cfg(not(feature = "wasm"))]
 use snarkvm_fields::{FieldParameters, PrimeField, Zero};
 #[cfg(not(feature = "wasm"))]
 use snarkvm_utilities::BitIteratorBE;
+#[cfg(not(feature = "wasm"))]
+use std::sync::Mutex;
 
 #[allow(type_alias_bounds)]
 type AccumulatorElements<E: PairingEngine> = (
@@ -46,14 +50,64 @@ cfg_if! {
             ])
         }
 
+        /// Collects the power pairs of all batches of an accumulator, so that
+        /// their ratios can be checked with a single multi-pairing.
+        pub(crate) struct BatchedRatios<E: PairingEngine> {
+            g1: Mutex<(E::G1Projective, E::G1Projective)>,
+            g2: Mutex<(E::G2Projective, E::G2Projective)>,
+        }
+
+        impl<E: PairingEngine> BatchedRatios<E> {
+            pub(crate) fn new() -> Self {
+                Self {
+                    g1: Mutex::new((E::G1Projective::zero(), E::G1Projective::zero())),
+                    g2: Mutex::new((E::G2Projective::zero(), E::G2Projective::zero())),
+                }
+            }
+
+            /// Adds a pair of G1 elements, which must have the same ratio as tau in G2.
+            pub(crate) fn add_g1(&self, pair: &(E::G1Affine, E::G1Affine)) {
+                let mut g1 = self.g1.lock().expect("should have locked the G1 pairs");
+                g1.0.add_assign_mixed(&pair.0);
+                g1.1.add_assign_mixed(&pair.1);
+            }
+
+            /// Adds a pair of G2 elements, which must have the same ratio as tau in G1.
+            pub(crate) fn add_g2(&self, pair: &(E::G2Affine, E::G2Affine)) {
+                let mut g2 = self.g2.lock().expect("should have locked the G2 pairs");
+                g2.0.add_assign_mixed(&pair.0);
+                g2.1.add_assign_mixed(&pair.1);
+            }
+
+            /// Checks the collected pairs against the given pairs of tau in G2 and in G1.
+            pub(crate) fn check(
+                self,
+                g2_check: &(E::G2Affine, E::G2Affine),
+                g1_check: &(E::G1Affine, E::G1Affine),
+            ) -> Result<()> {
+                let g1 = self.g1.into_inner().expect("should have unlocked the G1 pairs");
+                let g2 = self.g2.into_inner().expect("should have unlocked the G2 pairs");
+                check_same_ratios::<E>(
+                    &[
+                        (&(g1.0.into_affine(), g1.1.into_affine()), g2_check),
+                        (g1_check, &(g2.0.into_affine(), g2.1.into_affine())),
+                    ],
+                    "Batched power pairs",
+                )
+            }
+        }
+
         /// Reads a list of G1 elements from the buffer to the provided `elements` slice
         /// and then checks that their powers pairs ratio matches the one from the
-        /// provided `check` pair
+        /// provided `check` pair. If the ratio does not match, the error is located
+        /// at the first power which does not have the ratio of `check` with its predecessor.
         pub(crate) fn check_power_ratios<E: PairingEngine>(
             (buffer, compression, check_for_correctness): (&[u8], UseCompression, CheckForCorrectness),
             (start, end): (usize, usize),
             elements: &mut [E::G1Affine],
             check: &(E::G2Affine, E::G2Affine),
+            (element_type, chunk_index): (ElementType, usize),
+            batched: Option<&BatchedRatios<E>>,
         ) -> Result<()> {
             let size = buffer_size::<E::G1Affine>(compression);
             buffer[start * size..end * size].read_batch_preallocated(
@@ -61,18 +115,42 @@ cfg_if! {
                 compression,
                 check_for_correctness,
             )?;
-            check_same_ratio::<E>(&power_pairs(&elements[..end - start]), check, "Power pairs")?;
+            let elements = &elements[..end - start];
+            let pairs = power_pairs(elements);
+            match batched {
+                Some(batched) => batched.add_g1(&pairs),
+                None => {
+                    if !same_ratio::<E>(&pairs, check) {
+                        let location = RatioLocation::default()
+                            .with_element_type(element_type)
+                            .with_chunk_index(chunk_index)
+                            .with_batch(start, end);
+                        let power = elements.windows(2).position(|pair| !same_ratio::<E>(&(pair[0], pair[1]), check));
+                        return Err(VerificationError::InvalidRatio {
+                            context: "Power pairs",
+                            location: match power {
+                                Some(power) => location.with_power(start + power + 1),
+                                None => location,
+                            },
+                        }
+                        .into());
+                    }
+                }
+            }
             Ok(())
         }
 
         /// Reads a list of G2 elements from the buffer to the provided `elements` slice
         /// and then checks that their powers pairs ratio matches the one from the
-        /// provided `check` pair
+        /// provided `check` pair. If the ratio does not match, the error is located
+        /// at the first power which does not have the ratio of `check` with its predecessor.
         pub(crate) fn check_power_ratios_g2<E: PairingEngine>(
             (buffer, compression, check_for_correctness): (&[u8], UseCompression, CheckForCorrectness),
             (start, end): (usize, usize),
             elements: &mut [E::G2Affine],
             check: &(E::G1Affine, E::G1Affine),
+            (element_type, chunk_index): (ElementType, usize),
+            batched: Option<&BatchedRatios<E>>,
         ) -> Result<()> {
             let size = buffer_size::<E::G2Affine>(compression);
             buffer[start * size..end * size].read_batch_preallocated(
@@ -80,7 +158,28 @@ cfg_if! {
                 compression,
                 check_for_correctness,
             )?;
-            check_same_ratio::<E>(check, &power_pairs(&elements[..end - start]), "Power pairs")?;
+            let elements = &elements[..end - start];
+            let pairs = power_pairs(elements);
+            match batched {
+                Some(batched) => batched.add_g2(&pairs),
+                None => {
+                    if !same_ratio::<E>(check, &pairs) {
+                        let location = RatioLocation::default()
+                            .with_element_type(element_type)
+                            .with_chunk_index(chunk_index)
+                            .with_batch(start, end);
+                        let power = elements.windows(2).position(|pair| !same_ratio::<E>(check, &(pair[0], pair[1])));
+                        return Err(VerificationError::InvalidRatio {
+                            context: "Power pairs",
+                            location: match power {
+                                Some(power) => location.with_power(start + power + 1),
+                                None => location,
+                            },
+                        }
+                        .into());
+                    }
+                }
+            }
             Ok(())
         }
 
@@ -136,20 +235,22 @@ cfg_if! {
             Ok(result)
         }
 
-        /// Takes a compressed input buffer and decompresses it.
-        fn decompress_buffer<C: AffineCurve>(
+        /// Takes an input buffer and writes its elements with the output compression.
+        fn convert_buffer<C: AffineCurve>(
             output: &mut [u8],
             input: &[u8],
+            compressed_input: UseCompression,
+            compressed_output: UseCompression,
             check_input_for_correctness: CheckForCorrectness,
             (start, end): (usize, usize),
         ) -> Result<()> {
-            let in_size = buffer_size::<C>(UseCompression::Yes);
-            let out_size = buffer_size::<C>(UseCompression::No);
-            // read the compressed input
+            let in_size = buffer_size::<C>(compressed_input);
+            let out_size = buffer_size::<C>(compressed_output);
+            // read the input
             let elements =
-                input[start * in_size..end * in_size].read_batch::<C>(UseCompression::Yes, check_input_for_correctness)?;
-            // write it back uncompressed
-            output[start * out_size..end * out_size].write_batch(&elements, UseCompression::No)?;
+                input[start * in_size..end * in_size].read_batch::<C>(compressed_input, check_input_for_correctness)?;
+            // write it back with the output compression
+            output[start * out_size..end * out_size].write_batch(&elements, compressed_output)?;
 
             Ok(())
         }
@@ -161,96 +262,132 @@ cfg_if! {
             check_input_for_correctness: CheckForCorrectness,
             parameters: &Phase1Parameters<E>,
         ) -> Result<()> {
-            let compressed_input = UseCompression::Yes;
-            let compressed_output = UseCompression::No;
+            convert(
+                input,
+                output,
+                UseCompression::Yes,
+                UseCompression::No,
+                check_input_for_correctness,
+                parameters,
+            )
+        }
 
+        /// Takes an input buffer and writes its elements into the output buffer with the output compression.
+        /// The hash at the start of the buffers is left untouched.
+        pub fn convert<E: PairingEngine>(
+            input: &[u8],
+            output: &mut [u8],
+            compressed_input: UseCompression,
+            compressed_output: UseCompression,
+            check_input_for_correctness: CheckForCorrectness,
+            parameters: &Phase1Parameters<E>,
+        ) -> Result<()> {
             match parameters.proving_system {
                 ProvingSystem::Groth16 => {
-                    // Get an immutable reference to the compressed input chunks
+                    // Get an immutable reference to the input chunks
                     let (in_tau_g1, in_tau_g2, in_alpha_g1, in_beta_g1, mut in_beta_g2) = split(&input, parameters, compressed_input);
-                    // Get mutable refs to the decompressed outputs
+                    // Get mutable refs to the outputs
                     let (tau_g1, tau_g2, alpha_g1, beta_g1, beta_g2) = split_mut(output, parameters, compressed_output);
 
-                    // Decompress beta_g2
+                    // Convert beta_g2
                     {
-                        // Get the compressed element
+                        // Get the input element
                         let beta_g2_el =
                             in_beta_g2.read_element::<E::G2Affine>(compressed_input, check_input_for_correctness)?;
-                        // Write it back decompressed
+                        // Write it back with the output compression
                         beta_g2.write_element(&beta_g2_el, compressed_output)?;
                     }
 
-                    // Load `batch_size` chunks on each iteration and decompress them
+                    // Load `batch_size` chunks on each iteration and convert them
                     rayon::scope(|t| {
                         t.spawn(|_| {
-                            decompress_buffer::<E::G1Affine>(
+                            convert_buffer::<E::G1Affine>(
                                 tau_g1,
                                 in_tau_g1,
+                                compressed_input,
+                                compressed_output,
                                 check_input_for_correctness,
                                 (0, parameters.g1_chunk_size),
                             )
-                            .expect("could not decompress the tau_g1 elements")
+                            .expect("could not convert the tau_g1 elements")
                         });
                         if parameters.other_chunk_size > 0 {
                             rayon::scope(|t| {
                                 t.spawn(|_| {
-                                    decompress_buffer::<E::G2Affine>(
+                                    convert_buffer::<E::G2Affine>(
                                         tau_g2,
                                         in_tau_g2,
+                                        compressed_input,
+                                        compressed_output,
                                         check_input_for_correctness,
                                         (0, parameters.other_chunk_size),
                                     )
-                                    .expect("could not decompress the tau_g2 elements")
+                                    .expect("could not convert the tau_g2 elements")
                                 });
                                 t.spawn(|_| {
-                                    decompress_buffer::<E::G1Affine>(
+                                    convert_buffer::<E::G1Affine>(
                                         alpha_g1,
                                         in_alpha_g1,
+                                        compressed_input,
+                                        compressed_output,
                                         check_input_for_correctness,
                                         (0, parameters.other_chunk_size),
                                     )
-                                    .expect("could not decompress the alpha_g1 elements")
+                                    .expect("could not convert the alpha_g1 elements")
                                 });
                                 t.spawn(|_| {
-                                    decompress_buffer::<E::G1Affine>(
+                                    convert_buffer::<E::G1Affine>(
                                         beta_g1,
                                         in_beta_g1,
+                                        compressed_input,
+                                        compressed_output,
                                         check_input_for_correctness,
                                         (0, parameters.other_chunk_size),
                                     )
-                                    .expect("could not decompress the beta_g1 elements")
+                                    .expect("could not convert the beta_g1 elements")
                                 });
                             });
                         }
                     });
                 }
-                ProvingSystem::Marlin => {
-                    // Get an immutable reference to the compressed input chunks
+                ProvingSystem::Marlin | ProvingSystem::Kzg => {
+                    // Get an immutable reference to the input chunks
                     let (in_tau_g1, in_tau_g2, in_alpha_g1, _, _) = split(&input, parameters, compressed_input);
-                    // Get mutable refs to the decompressed outputs
+                    // Get mutable refs to the outputs
                     let (tau_g1, tau_g2, alpha_g1, _, _) = split_mut(output, parameters, compressed_output);
 
                     if parameters.chunk_index == 0 || parameters.contribution_mode == ContributionMode::Full {
-                        // Load `batch_size` chunks on each iteration and decompress them
-                        let num_alpha_powers = 3;
-                        decompress_buffer::<E::G1Affine>(
+                        // Load `batch_size` chunks on each iteration and convert them
+                        let (g2_chunk_size, alpha_chunk_size) = first_chunk_sizes(parameters);
+                        convert_buffer::<E::G1Affine>(
                             alpha_g1,
                             in_alpha_g1,
+                            compressed_input,
+                            compressed_output,
                             check_input_for_correctness,
-                            (0, num_alpha_powers + 3*parameters.total_size_in_log2),
+                            (0, alpha_chunk_size),
+                        )?;
+                        convert_buffer::<E::G2Affine>(
+                            tau_g2,
+                            in_tau_g2,
+                            compressed_input,
+                            compressed_output,
+                            check_input_for_correctness,
+                            (0, g2_chunk_size),
                         )?;
-                        decompress_buffer::<E::G2Affine>(tau_g2, in_tau_g2, check_input_for_correctness, (0, parameters.total_size_in_log2 + 2))?;
                     }
 
                     rayon::scope(|t| {
                          t.spawn(|_| {
-                            decompress_buffer::<E::G1Affine>(
+                            convert_buffer::<E::G1Affine>(
                                 tau_g1,
                                 in_tau_g1,
+                                compressed_input,
+                                compressed_output,
                                 check_input_for_correctness,
                                 (0, parameters.g1_chunk_size),
                             )
-                            .expect("could not decompress the tau_g1 elements")
+                            .expect("could not convert the tau_g1 elements")
                         });
                     });
                 }
@@ -277,7 +414,7 @@ pub fn serialize<E: PairingEngine>(
     beta_g1.write_batch(&in_beta_g1, compressed)?;
     match parameters.proving_system {
         ProvingSystem::Groth16 => beta_g2.write_element(in_beta_g2, compressed)?,
-        ProvingSystem::Marlin => {}
+        ProvingSystem::Marlin | ProvingSystem::Kzg => {}
     }
 
     Ok(())
@@ -301,7 +438,7 @@ pub fn deserialize<E: PairingEngine>(
     let beta_g1 = in_beta_g1.read_batch(compressed, check_input_for_correctness)?;
     let beta_g2 = match parameters.proving_system {
         ProvingSystem::Groth16 => (&*in_beta_g2).read_element(compressed, check_input_for_correctness)?,
-        ProvingSystem::Marlin => E::G2Affine::prime_subgroup_generator(),
+        ProvingSystem::Marlin | ProvingSystem::Kzg => E::G2Affine::prime_subgroup_generator(),
     };
 
     Ok((tau_g1, tau_g2, alpha_g1, beta_g1, beta_g2))
@@ -310,9 +447,10 @@ pub fn deserialize<E: PairingEngine>(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::helpers::testing::random_point_vec;
+    use crate::helpers::testing::{random_point, random_point_vec};
 
-    use snarkvm_curves::bls12_377::Bls12_377;
+    use snarkvm_curves::bls12_377::{Bls12_377, Fr, G1Affine, G2Affine};
+    use snarkvm_utilities::UniformRand;
 
     use rand::thread_rng;
 
@@ -330,7 +468,15 @@ mod tests {
         let len = num_els * buffer_size::<C>(UseCompression::No);
         let mut out = vec![0; len];
         // Perform the decompression.
-        decompress_buffer::<C>(&mut out, &input, CheckForCorrectness::Full, (0, num_els)).unwrap();
+        convert_buffer::<C>(
+            &mut out,
+            &input,
+            UseCompression::Yes,
+            UseCompression::No,
+            CheckForCorrectness::Full,
+            (0, num_els),
+        )
+        .unwrap();
         let deserialized = out
             .read_batch::<C>(UseCompression::No, CheckForCorrectness::Full)
             .unwrap();
@@ -343,4 +489,57 @@ mod tests {
         decompress_buffer_curve_test::<<Bls12_377 as PairingEngine>::G1Affine>();
         decompress_buffer_curve_test::<<Bls12_377 as PairingEngine>::G2Affine>();
     }
+
+    #[test]
+    fn test_check_power_ratios_locates_invalid_power() {
+        let mut rng = thread_rng();
+        let tau = Fr::rand(&mut rng);
+        let check = (
+            G2Affine::prime_subgroup_generator(),
+            G2Affine::prime_subgroup_generator().mul(tau),
+        );
+
+        // Compute the powers of tau, and replace one of them with a random element.
+        let mut powers = vec![G1Affine::prime_subgroup_generator()];
+        for i in 1..8 {
+            powers.push(powers[i - 1].mul(tau));
+        }
+        powers[5] = random_point(&mut rng);
+
+        let mut buffer = vec![0; powers.len() * buffer_size::<G1Affine>(UseCompression::No)];
+        buffer.write_batch(&powers, UseCompression::No).unwrap();
+
+        let mut elements = vec![G1Affine::zero(); powers.len()];
+        let result = check_power_ratios::<Bls12_377>(
+            (&buffer, UseCompression::No, CheckForCorrectness::Full),
+            (0, powers.len()),
+            &mut elements,
+            &check,
+            (ElementType::TauG1, 3),
+            None,
+        );
+        match result {
+            Err(Error::VerificationError(error)) => assert_eq!(
+                error.location(),
+                Some(&RatioLocation {
+                    element_type: Some(ElementType::TauG1),
+                    chunk_index: Some(3),
+                    batch: Some((0, 8)),
+                    power: Some(5),
+                })
+            ),
+            _ => panic!("expected an invalid ratio"),
+        }
+
+        // The ratios before the invalid power are still valid.
+        assert!(check_power_ratios::<Bls12_377>(
+            (&buffer, UseCompression::No, CheckForCorrectness::Full),
+            (0, 5),
+            &mut elements,
+            &check,
+            (ElementType::TauG1, 3),
+            None,
+        )
+        .is_ok());
+    }
 }
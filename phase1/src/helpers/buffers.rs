@@ -18,17 +18,30 @@ type SplitBufMut<'a> = (&'a mut [u8], &'a mut [u8], &'a mut [u8], &'a mut [u8],
 type SplitBuf<'a> = (&'a [u8], &'a [u8], &'a [u8], &'a [u8], &'a [u8]);
 
 /// Helper function to iterate over the accumulator in chunks.
-/// `action` will perform an action on the chunk
+/// `action` will perform an action on the chunk, and `progress` is notified
+/// after every batch and can cancel the iteration between batches.
 pub(crate) fn iter_chunk(
     parameters: &Phase1Parameters<impl PairingEngine>,
+    progress: &dyn Progress,
     mut action: impl FnMut(usize, usize) -> Result<()>,
+) -> Result<()> {
+    iter_chunk_from(parameters, 0, progress, |_, start, end| action(start, end))
+}
+
+/// Helper function to iterate over the accumulator in chunks, starting at the batch
+/// `first_batch`. `action` also receives the index of the batch.
+pub(crate) fn iter_chunk_from(
+    parameters: &Phase1Parameters<impl PairingEngine>,
+    first_batch: usize,
+    progress: &dyn Progress,
+    mut action: impl FnMut(usize, usize, usize) -> Result<()>,
 ) -> Result<()> {
     // Determine the range to iterate over.
     let (min, max) = {
         // Determine the number of elements to process based on the proof system's requirement.
         let upper_bound = match parameters.proving_system {
             ProvingSystem::Groth16 => parameters.powers_g1_length,
-            ProvingSystem::Marlin => parameters.powers_length,
+            ProvingSystem::Marlin | ProvingSystem::Kzg => parameters.powers_length,
         };
 
         // In chunked contribution mode, select the chunk to iterate over.
@@ -45,16 +58,22 @@ pub(crate) fn iter_chunk(
     };
 
     // Iterate over the range, processing each element with the given input.
+    let batches = (max - min + parameters.batch_size - 2) / (parameters.batch_size - 1);
     (min..max)
         .chunks(parameters.batch_size - 1)
         .into_iter()
-        .map(|chunk| {
+        .enumerate()
+        .skip(first_batch)
+        .map(|(batch, chunk)| {
+            check_cancelled(progress)?;
             let (start, end) = match chunk.minmax() {
                 MinMaxResult::MinMax(start, end) => (start, if end >= max - 1 { end + 1 } else { end + 2 }), // ensure there's overlap between chunks
                 MinMaxResult::OneElement(start) => (start, if start >= max - 1 { start + 1 } else { start + 2 }),
                 _ => return Err(Error::InvalidChunk),
             };
-            action(start, end)
+            action(batch, start, end)?;
+            progress.on_progress(batch + 1, batches);
+            Ok(())
         })
         .collect::<Result<_>>()
 }
@@ -82,6 +101,16 @@ pub(crate) fn apply_powers<C: AffineCurve>(
     Ok(())
 }
 
+/// Returns the number of TauG2 and AlphaG1 elements of a Marlin or KZG accumulator, which
+/// are all stored in the first chunk, or zero for the other chunks and proving systems.
+pub(crate) fn first_chunk_sizes<E: PairingEngine>(parameters: &Phase1Parameters<E>) -> (usize, usize) {
+    match (parameters.proving_system, parameters.chunk_index) {
+        (ProvingSystem::Marlin, 0) => (parameters.total_size_in_log2 + 2, 3 + 3 * parameters.total_size_in_log2),
+        (ProvingSystem::Kzg, 0) => (2, 0),
+        _ => (0, 0),
+    }
+}
+
 #[cfg(not(feature = "wasm"))]
 /// Splits the full buffer in 5 non overlapping mutable slice for a given chunk and batch size.
 /// Each slice corresponds to the group elements in the following order
@@ -104,8 +133,8 @@ pub(crate) fn split_at_chunk_mut<'a, E: PairingEngine>(
         let chunk_size = match (parameters.proving_system, is_other) {
             (ProvingSystem::Groth16, true) => parameters.other_chunk_size,
             (ProvingSystem::Groth16, false) => parameters.g1_chunk_size,
-            (ProvingSystem::Marlin, true) => return &mut [],
-            (ProvingSystem::Marlin, false) => parameters.g1_chunk_size,
+            (ProvingSystem::Marlin, true) | (ProvingSystem::Kzg, true) => return &mut [],
+            (ProvingSystem::Marlin, false) | (ProvingSystem::Kzg, false) => parameters.g1_chunk_size,
         };
 
         let start = parameters.chunk_index * parameters.chunk_size * element_size;
@@ -133,12 +162,8 @@ pub(crate) fn split_at_chunk_mut<'a, E: PairingEngine>(
                 &mut beta_g2[0..g2_size],
             )
         }
-        ProvingSystem::Marlin => {
-            let (g2_chunk_size, alpha_chunk_size) = if parameters.chunk_index == 0 {
-                (parameters.total_size_in_log2 + 2, 3 + 3 * parameters.total_size_in_log2)
-            } else {
-                (0, 0)
-            };
+        ProvingSystem::Marlin | ProvingSystem::Kzg => {
+            let (g2_chunk_size, alpha_chunk_size) = first_chunk_sizes(parameters);
 
             // leave the first 64 bytes for the hash
             let (_, others) = buffer.split_at_mut(parameters.hash_size);
@@ -157,6 +182,71 @@ pub(crate) fn split_at_chunk_mut<'a, E: PairingEngine>(
     }
 }
 
+#[cfg(not(feature = "wasm"))]
+/// Splits the full buffer in 5 non overlapping immutable slice for a given chunk and batch size.
+/// Each slice corresponds to the group elements in the following order
+/// [TauG1, TauG2, AlphaG1, BetaG1, BetaG2]
+pub(crate) fn split_at_chunk<'a, E: PairingEngine>(
+    buffer: &'a [u8],
+    parameters: &Phase1Parameters<E>,
+    compressed: UseCompression,
+) -> SplitBuf<'a> {
+    let g1_size = buffer_size::<E::G1Affine>(compressed);
+    let g2_size = buffer_size::<E::G2Affine>(compressed);
+
+    let buffer_to_chunk = |buffer: &'a [u8], element_size: usize, is_other: bool| -> &'a [u8] {
+        // Determine whether to return an empty chunk based on the size of 'other'.
+        if is_other && parameters.other_chunk_size == 0 {
+            return &[];
+        }
+
+        // Determine the chunk size based on the proof system.
+        let chunk_size = match (parameters.proving_system, is_other) {
+            (ProvingSystem::Groth16, true) => parameters.other_chunk_size,
+            (ProvingSystem::Groth16, false) => parameters.g1_chunk_size,
+            (ProvingSystem::Marlin, true) | (ProvingSystem::Kzg, true) => return &[],
+            (ProvingSystem::Marlin, false) | (ProvingSystem::Kzg, false) => parameters.g1_chunk_size,
+        };
+
+        let start = parameters.chunk_index * parameters.chunk_size * element_size;
+        let end = start + chunk_size * element_size;
+
+        &buffer[start..end]
+    };
+
+    match parameters.proving_system {
+        ProvingSystem::Groth16 => {
+            // skip the first 64 bytes of the hash
+            let (_, others) = buffer.split_at(parameters.hash_size);
+            let (tau_g1, others) = others.split_at(g1_size * parameters.powers_g1_length);
+            let (tau_g2, others) = others.split_at(g2_size * parameters.powers_length);
+            let (alpha_g1, others) = others.split_at(g1_size * parameters.powers_length);
+            let (beta_g1, beta_g2) = others.split_at(g1_size * parameters.powers_length);
+
+            // We take up to g2_size for beta_g2, since there might be other
+            // elements after it at the end of the buffer.
+            (
+                buffer_to_chunk(tau_g1, g1_size, false),
+                buffer_to_chunk(tau_g2, g2_size, true),
+                buffer_to_chunk(alpha_g1, g1_size, true),
+                buffer_to_chunk(beta_g1, g1_size, true),
+                &beta_g2[0..g2_size],
+            )
+        }
+        ProvingSystem::Marlin | ProvingSystem::Kzg => {
+            let (g2_chunk_size, alpha_chunk_size) = first_chunk_sizes(parameters);
+
+            // skip the first 64 bytes of the hash
+            let (_, others) = buffer.split_at(parameters.hash_size);
+            let (tau_g1, others) = others.split_at(g1_size * parameters.powers_length);
+            let (tau_g2, others) = others.split_at(g2_size * g2_chunk_size);
+            let (alpha_g1, _) = others.split_at(g1_size * alpha_chunk_size);
+
+            (buffer_to_chunk(tau_g1, g1_size, false), tau_g2, alpha_g1, &[], &[])
+        }
+    }
+}
+
 /// Splits the full buffer in 5 non overlapping mutable slice.
 /// Each slice corresponds to the group elements in the following order
 /// [TauG1, TauG2, AlphaG1, BetaG1, BetaG2]
@@ -183,16 +273,12 @@ pub(crate) fn split_mut<'a, E: PairingEngine>(
             // elements after it at the end of the buffer.
             (tau_g1, tau_g2, alpha_g1, beta_g1, &mut beta_g2[0..g2_size])
         }
-        ProvingSystem::Marlin => {
+        ProvingSystem::Marlin | ProvingSystem::Kzg => {
             let g1_size = buffer_size::<E::G1Affine>(compressed);
             let g2_size = buffer_size::<E::G2Affine>(compressed);
 
             let g1_chunk_size = parameters.g1_chunk_size;
-            let (g2_chunk_size, alpha_chunk_size) = if parameters.chunk_index == 0 {
-                (parameters.total_size_in_log2 + 2, 3 + 3 * parameters.total_size_in_log2)
-            } else {
-                (0, 0)
-            };
+            let (g2_chunk_size, alpha_chunk_size) = first_chunk_sizes(parameters);
 
             let (_, others) = buffer.split_at_mut(parameters.hash_size);
             let (tau_g1, others) = others.split_at_mut(g1_size * g1_chunk_size);
@@ -233,16 +319,12 @@ pub(crate) fn split<'a, E: PairingEngine>(
             // elements after it at the end of the buffer.
             (tau_g1, tau_g2, alpha_g1, beta_g1, &beta_g2[0..g2_size])
         }
-        ProvingSystem::Marlin => {
+        ProvingSystem::Marlin | ProvingSystem::Kzg => {
             let g1_size = buffer_size::<E::G1Affine>(compressed);
             let g2_size = buffer_size::<E::G2Affine>(compressed);
 
             let g1_chunk_size = parameters.g1_chunk_size;
-            let (g2_chunk_size, alpha_chunk_size) = if parameters.chunk_index == 0 {
-                (parameters.total_size_in_log2 + 2, 3 + 3 * parameters.total_size_in_log2)
-            } else {
-                (0, 0)
-            };
+            let (g2_chunk_size, alpha_chunk_size) = first_chunk_sizes(parameters);
 
             let (_, others) = buffer.split_at(parameters.hash_size);
             let (tau_g1, others) = others.split_at(g1_size * g1_chunk_size);
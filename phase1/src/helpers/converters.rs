@@ -1,17 +1,22 @@
 use crate::{ContributionMode, ProvingSystem};
 use serde::{Deserialize, Serialize};
+use setup_utils::VerificationStrategy;
 
-#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CurveKind {
     Bls12_377,
+    Bls12_381,
     BW6,
 }
 
 pub fn curve_from_str(src: &str) -> Result<CurveKind, String> {
     let curve = match src.to_lowercase().as_str() {
         "bls12_377" => CurveKind::Bls12_377,
+        "bls12_381" => CurveKind::Bls12_381,
         "bw6" => CurveKind::BW6,
-        _ => return Err("unsupported curve".to_string()),
+        // snarkvm-curves has no BN pairing template, so BN254 can not be expressed as a `PairingEngine` yet.
+        "bn254" => return Err("BN254 is not supported yet. Currently supported: bls12_377, bls12_381, bw6".to_string()),
+        _ => return Err("unsupported curve. Currently supported: bls12_377, bls12_381, bw6".to_string()),
     };
     Ok(curve)
 }
@@ -29,7 +34,17 @@ pub fn proving_system_from_str(src: &str) -> Result<ProvingSystem, String> {
     let system = match src.to_lowercase().as_str() {
         "groth16" => ProvingSystem::Groth16,
         "marlin" => ProvingSystem::Marlin,
-        _ => return Err("unsupported proving system. Currently supported: groth16, marlin".to_string()),
+        "kzg" => ProvingSystem::Kzg,
+        _ => return Err("unsupported proving system. Currently supported: groth16, marlin, kzg".to_string()),
     };
     Ok(system)
 }
+
+pub fn verification_strategy_from_str(src: &str) -> Result<VerificationStrategy, String> {
+    let strategy = match src.to_lowercase().as_str() {
+        "per_batch" => VerificationStrategy::PerBatch,
+        "batched" => VerificationStrategy::Batched,
+        _ => return Err("unsupported verification strategy. Currently supported: per_batch, batched".to_string()),
+    };
+    Ok(strategy)
+}
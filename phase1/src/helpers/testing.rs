@@ -99,12 +99,13 @@ pub fn generate_random_accumulator<E: PairingEngine>(
             acc.serialize(&mut buf, compressed, parameters).unwrap();
             (buf, acc)
         }
-        crate::ProvingSystem::Marlin => {
+        crate::ProvingSystem::Marlin | crate::ProvingSystem::Kzg => {
+            let (g2_size, alpha_size) = crate::helpers::buffers::first_chunk_sizes(parameters);
             let rng = &mut thread_rng();
             let acc = Phase1 {
                 tau_powers_g1: random_point_vec(parameters.powers_length, rng),
-                tau_powers_g2: random_point_vec(parameters.total_size_in_log2 + 2, rng),
-                alpha_tau_powers_g1: random_point_vec(3 + 3 * parameters.total_size_in_log2, rng),
+                tau_powers_g2: random_point_vec(g2_size, rng),
+                alpha_tau_powers_g1: random_point_vec(alpha_size, rng),
                 beta_tau_powers_g1: random_point_vec(0, rng),
                 beta_g2: E::G2Affine::prime_subgroup_generator(),
                 hash: blank_hash(),
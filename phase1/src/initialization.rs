@@ -63,7 +63,7 @@ mod tests {
     use snarkvm_curves::{bls12_377::Bls12_377, bw6_761::BW6_761, AffineCurve};
 
     fn curve_initialization_test<E: PairingEngine>(powers: usize, batch: usize, compression: UseCompression) {
-        for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin] {
+        for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin, ProvingSystem::Kzg] {
             let parameters = Phase1Parameters::<E>::new_full(*proving_system, powers, batch);
             let expected_challenge_length = match compression {
                 UseCompression::Yes => parameters.contribution_size - parameters.public_key_size,
@@ -102,6 +102,11 @@ mod tests {
                             .total_size_in_log2
                     ]);
                 }
+                ProvingSystem::Kzg => {
+                    assert_eq!(deserialized.tau_powers_g1, vec![g1_zero; parameters.powers_length]);
+                    assert_eq!(deserialized.tau_powers_g2, vec![g2_zero; 2]);
+                    assert!(deserialized.alpha_tau_powers_g1.is_empty());
+                }
             }
         }
     }
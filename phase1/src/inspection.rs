@@ -0,0 +1,247 @@
+//! Inspection of the sections of an accumulator, to investigate a misbehaving file
+//! without deserializing (and rejecting) it as a whole.
+use super::*;
+
+use snarkvm_fields::Zero;
+
+/// The summary of a section of an accumulator.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SectionReport {
+    /// The section of the accumulator.
+    pub element_type: ElementType,
+    /// The number of elements in the section.
+    pub len: usize,
+    /// The first element of the section, if it could be decoded.
+    pub first: Option<String>,
+    /// The last element of the section, if it could be decoded.
+    pub last: Option<String>,
+    /// The number of elements which could not be decoded.
+    pub undecodable: usize,
+    /// The number of elements which are the point at infinity.
+    pub at_infinity: usize,
+    /// The number of elements which are not on the curve.
+    pub not_on_curve: usize,
+    /// The number of elements which are on the curve, but not in the prime order subgroup.
+    pub not_in_subgroup: usize,
+    /// The index of the first element which failed any of the checks above.
+    pub first_invalid: Option<usize>,
+}
+
+impl SectionReport {
+    /// Returns true if every element of the section is a valid, non-zero point.
+    pub fn is_valid(&self) -> bool {
+        self.first_invalid.is_none()
+    }
+}
+
+/// The first differing element of a section of two accumulators.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SectionDiff {
+    /// The section of the accumulators.
+    pub element_type: ElementType,
+    /// The index of the first element which differs, if any.
+    pub first_difference: Option<usize>,
+}
+
+impl<'a, E: PairingEngine> Phase1<'a, E> {
+    /// Decodes every element of the non-empty sections of an accumulator, and reports
+    /// its first and last elements and the elements which are not valid points.
+    pub fn inspect_sections(
+        input: &[u8],
+        compressed: UseCompression,
+        parameters: &Phase1Parameters<E>,
+    ) -> Result<Vec<SectionReport>> {
+        if input.len() < parameters.get_length(compressed) {
+            return Err(Error::InvalidLength {
+                expected: parameters.get_length(compressed),
+                got: input.len(),
+            });
+        }
+
+        let (tau_g1, tau_g2, alpha_g1, beta_g1, beta_g2) = split(input, parameters, compressed);
+        let mut reports = vec![
+            inspect_section::<E::G1Affine>(ElementType::TauG1, tau_g1, compressed),
+            inspect_section::<E::G2Affine>(ElementType::TauG2, tau_g2, compressed),
+            inspect_section::<E::G1Affine>(ElementType::AlphaG1, alpha_g1, compressed),
+            inspect_section::<E::G1Affine>(ElementType::BetaG1, beta_g1, compressed),
+            inspect_section::<E::G2Affine>(ElementType::BetaG2, beta_g2, compressed),
+        ];
+        reports.retain(|report| report.len > 0);
+
+        Ok(reports)
+    }
+
+    /// Compares the non-empty sections of two accumulators with the same parameters,
+    /// and reports the index of the first element which differs in each of them.
+    pub fn diff_sections(
+        first: &[u8],
+        second: &[u8],
+        compressed: UseCompression,
+        parameters: &Phase1Parameters<E>,
+    ) -> Result<Vec<SectionDiff>> {
+        for input in &[first, second] {
+            if input.len() < parameters.get_length(compressed) {
+                return Err(Error::InvalidLength {
+                    expected: parameters.get_length(compressed),
+                    got: input.len(),
+                });
+            }
+        }
+
+        let g1_size = buffer_size::<E::G1Affine>(compressed);
+        let g2_size = buffer_size::<E::G2Affine>(compressed);
+        let element_sizes = [g1_size, g2_size, g1_size, g1_size, g2_size];
+        let element_types = [
+            ElementType::TauG1,
+            ElementType::TauG2,
+            ElementType::AlphaG1,
+            ElementType::BetaG1,
+            ElementType::BetaG2,
+        ];
+
+        let (tau_g1, tau_g2, alpha_g1, beta_g1, beta_g2) = split(first, parameters, compressed);
+        let first_sections = [tau_g1, tau_g2, alpha_g1, beta_g1, beta_g2];
+        let (tau_g1, tau_g2, alpha_g1, beta_g1, beta_g2) = split(second, parameters, compressed);
+        let second_sections = [tau_g1, tau_g2, alpha_g1, beta_g1, beta_g2];
+
+        let diffs = element_types
+            .iter()
+            .zip(&element_sizes)
+            .zip(first_sections.iter().zip(&second_sections))
+            .filter(|(_, (first, _))| !first.is_empty())
+            .map(|((element_type, element_size), (first, second))| SectionDiff {
+                element_type: *element_type,
+                first_difference: first
+                    .chunks(*element_size)
+                    .zip(second.chunks(*element_size))
+                    .position(|(a, b)| a != b),
+            })
+            .collect();
+
+        Ok(diffs)
+    }
+}
+
+/// Decodes every element of a section without rejecting it, and checks it separately.
+fn inspect_section<G: AffineCurve>(
+    element_type: ElementType,
+    buffer: &[u8],
+    compressed: UseCompression,
+) -> SectionReport {
+    let element_size = buffer_size::<G>(compressed);
+    let len = buffer.len() / element_size;
+    let mut report = SectionReport {
+        element_type,
+        len,
+        first: None,
+        last: None,
+        undecodable: 0,
+        at_infinity: 0,
+        not_on_curve: 0,
+        not_in_subgroup: 0,
+        first_invalid: None,
+    };
+
+    for (i, mut element) in buffer.chunks(element_size).enumerate() {
+        let valid = match element.read_element::<G>(compressed, CheckForCorrectness::No) {
+            Ok(point) => {
+                if i == 0 {
+                    report.first = Some(format!("{:?}", point));
+                }
+                if i == len - 1 {
+                    report.last = Some(format!("{:?}", point));
+                }
+
+                if point.is_zero() {
+                    report.at_infinity += 1;
+                    false
+                } else if !point.is_on_curve() {
+                    report.not_on_curve += 1;
+                    false
+                } else if !point.is_in_correct_subgroup_assuming_on_curve() {
+                    report.not_in_subgroup += 1;
+                    false
+                } else {
+                    true
+                }
+            }
+            Err(_) => {
+                report.undecodable += 1;
+                false
+            }
+        };
+        if !valid && report.first_invalid.is_none() {
+            report.first_invalid = Some(i);
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::testing::generate_input;
+
+    use snarkvm_curves::bls12_377::Bls12_377;
+
+    fn inspection_test<E: PairingEngine>(parameters: &Phase1Parameters<E>, compressed: UseCompression) {
+        let (mut input, accumulator) = generate_input(parameters, compressed, CheckForCorrectness::No);
+
+        let reports = Phase1::inspect_sections(&input, compressed, parameters).unwrap();
+        let expected_sections = match parameters.proving_system {
+            ProvingSystem::Groth16 => 5,
+            ProvingSystem::Marlin => 3,
+            ProvingSystem::Kzg => 2,
+        };
+        assert_eq!(reports.len(), expected_sections);
+        assert!(reports.iter().all(|report| report.is_valid()));
+        assert_eq!(reports[0].len, accumulator.tau_powers_g1.len());
+        assert_eq!(reports[0].first, Some(format!("{:?}", accumulator.tau_powers_g1[0])));
+        assert_eq!(reports[1].len, accumulator.tau_powers_g2.len());
+
+        // Replace the second power of tau in G1 with the point at infinity.
+        let g1_size = buffer_size::<E::G1Affine>(compressed);
+        let position = parameters.hash_size + g1_size;
+        input[position..position + g1_size]
+            .write_element(&E::G1Affine::zero(), compressed)
+            .unwrap();
+
+        let reports = Phase1::inspect_sections(&input, compressed, parameters).unwrap();
+        assert_eq!(reports[0].at_infinity, 1);
+        assert_eq!(reports[0].first_invalid, Some(1));
+        assert!(reports[1..].iter().all(|report| report.is_valid()));
+
+        let (original, _) = generate_input(parameters, compressed, CheckForCorrectness::No);
+        let diffs = Phase1::diff_sections(&original, &input, compressed, parameters).unwrap();
+        assert_eq!(diffs.len(), expected_sections);
+        assert_eq!(diffs[0].element_type, ElementType::TauG1);
+        assert_eq!(diffs[0].first_difference, Some(1));
+        assert!(diffs[1..].iter().all(|diff| diff.first_difference.is_none()));
+
+        let diffs = Phase1::diff_sections(&original, &original, compressed, parameters).unwrap();
+        assert!(diffs.iter().all(|diff| diff.first_difference.is_none()));
+    }
+
+    #[test]
+    fn test_inspection() {
+        for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin, ProvingSystem::Kzg] {
+            for compressed in &[UseCompression::Yes, UseCompression::No] {
+                let parameters = Phase1Parameters::<Bls12_377>::new_full(*proving_system, 4, 4);
+                inspection_test(&parameters, *compressed);
+
+                let parameters =
+                    Phase1Parameters::<Bls12_377>::new_chunk(ContributionMode::Chunked, 0, 4, *proving_system, 4, 4);
+                inspection_test(&parameters, *compressed);
+            }
+        }
+    }
+
+    #[test]
+    fn test_inspection_rejects_short_input() {
+        let parameters = Phase1Parameters::<Bls12_377>::new_full(ProvingSystem::Groth16, 4, 4);
+        let (input, _) = generate_input(&parameters, UseCompression::No, CheckForCorrectness::No);
+        assert!(Phase1::inspect_sections(&input[..input.len() - 1], UseCompression::No, &parameters).is_err());
+        assert!(Phase1::diff_sections(&input, &input[1..], UseCompression::No, &parameters).is_err());
+    }
+}
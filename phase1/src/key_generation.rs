@@ -10,31 +10,34 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
             });
         }
 
-        // tau is a contribution to the "powers of tau", in a set of points of the form "tau^i * G"
-        let tau = E::Fr::rand(rng);
-        // alpha and beta are a set of contributions in a form "alpha * tau^i * G" and that are required
-        // for construction of the polynomials
-        let alpha = E::Fr::rand(rng);
-        let beta = E::Fr::rand(rng);
+        // The secrets are sampled directly into the private key, which wipes them when it is dropped.
+        let private_key = PrivateKey {
+            // tau is a contribution to the "powers of tau", in a set of points of the form "tau^i * G"
+            tau: E::Fr::rand(rng),
+            // alpha and beta are a set of contributions in a form "alpha * tau^i * G" and that are required
+            // for construction of the polynomials
+            alpha: E::Fr::rand(rng),
+            beta: E::Fr::rand(rng),
+        };
 
-        let mut op = |x: E::Fr, personalization: u8| -> Result<_> {
+        let mut op = |x: &E::Fr, personalization: u8| -> Result<_> {
             // Sample random g^s
             let g1_s = E::G1Projective::rand(rng).into_affine();
             // Compute g^{s*x}
-            let g1_s_x = g1_s.mul(x);
+            let g1_s_x = g1_s.mul(*x);
             // Hash into G2 as g^{s'}
             let g2_s: E::G2Affine = compute_g2_s::<E>(&digest, &g1_s, &g1_s_x, personalization)?;
             // Compute g^{s'*x}
-            let g2_s_x = g2_s.mul(x);
+            let g2_s_x = g2_s.mul(*x);
 
             Ok(((g1_s, g1_s_x), g2_s_x))
         };
 
         // These "public keys" are required for the next participants to check that points are in fact
         // sequential powers
-        let pk_tau = op(tau, 0)?;
-        let pk_alpha = op(alpha, 1)?;
-        let pk_beta = op(beta, 2)?;
+        let pk_tau = op(&private_key.tau, 0)?;
+        let pk_alpha = op(&private_key.alpha, 1)?;
+        let pk_beta = op(&private_key.beta, 2)?;
 
         Ok((
             PublicKey {
@@ -45,7 +48,7 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
                 alpha_g2: pk_alpha.1,
                 beta_g2: pk_beta.1,
             },
-            PrivateKey { tau, alpha, beta },
+            private_key,
         ))
     }
 }
@@ -11,8 +11,27 @@ mod initialization;
 mod key_generation;
 mod serialization;
 #[cfg(not(feature = "wasm"))]
+mod splitting;
+mod streaming;
+#[cfg(feature = "universal-params")]
+mod universal_params;
+#[cfg(not(feature = "wasm"))]
 mod verification;
 
+pub mod checkpoint;
+pub use checkpoint::{Checkpoint, CHECKPOINT_SIZE};
+
+pub mod header;
+pub use header::{check_file_header, FileHeader, HEADER_SIZE};
+
+pub mod inspection;
+pub use inspection::{SectionDiff, SectionReport};
+
+pub mod ptau;
+pub use ptau::{PtauContribution, PTAU_CHALLENGE_HASH_SIZE, PTAU_PARTIAL_HASH_SIZE};
+
+pub mod zcash;
+
 use crate::helpers::{
     accumulator::{self},
     buffers::*,
@@ -8,7 +8,7 @@ macro_rules! total_size_in_g1 {
 
         match $proving_system {
             ProvingSystem::Groth16 => ((1 << ($power + 1)) - 1),
-            ProvingSystem::Marlin => (1 << $power),
+            ProvingSystem::Marlin | ProvingSystem::Kzg => (1 << $power),
         }
     }};
 }
@@ -16,6 +16,9 @@ pub enum ContributionMode {
 pub enum ProvingSystem {
     Groth16,
     Marlin,
+    /// Plain KZG commitments, as used by PLONK-style provers, which only need the powers
+    /// of tau in G1 and the first two powers of tau in G2.
+    Kzg,
 }
 
 /// The sizes of the group elements of a curve
@@ -166,6 +169,18 @@ impl<E: PairingEngine> Phase1Parameters<E> {
                     // Hash of the previous contribution
                     + hash_size
             }
+            ProvingSystem::Kzg => {
+                // G1 Tau powers
+                g1_chunk_size * curve.g1_size
+                    + if chunk_index == 0 {
+                        // G2 Tau powers
+                        2 * curve.g2_size
+                    } else {
+                        0
+                    }
+                    // Hash of the previous contribution
+                    + hash_size
+            }
         };
 
         let public_key_size =
@@ -203,6 +218,20 @@ impl<E: PairingEngine> Phase1Parameters<E> {
                     // The public key of the previous contributor
                     public_key_size
             }
+            ProvingSystem::Kzg => {
+                // G1 Tau powers (compressed)
+                g1_chunk_size * curve.g1_compressed_size +
+                    if chunk_index == 0 {
+                        // G2 Tau powers
+                        2 * curve.g2_compressed_size
+                    } else {
+                        0
+                    } +
+                    // Hash of the previous contribution
+                    hash_size +
+                    // The public key of the previous contributor
+                    public_key_size
+            }
         };
 
         // TODO (howardwu): Remove this.
@@ -270,7 +299,7 @@ impl<E: PairingEngine> Phase1Parameters<E> {
         // Determine the number of elements to process based on the proof system's requirement.
         let upper_bound = match proving_system {
             ProvingSystem::Groth16 => powers_g1_length,
-            ProvingSystem::Marlin => powers_length,
+            ProvingSystem::Marlin | ProvingSystem::Kzg => powers_length,
         };
 
         // In chunked contribution mode, select the chunk to iterate over.
@@ -297,7 +326,7 @@ impl<E: PairingEngine> Phase1Parameters<E> {
                     end - start
                 }
             }
-            ProvingSystem::Marlin => 0,
+            ProvingSystem::Marlin | ProvingSystem::Kzg => 0,
         };
 
         (g1_chunk_size, other_chunk_size)
@@ -322,4 +351,21 @@ mod tests {
         curve_parameters_test::<Bls12_377>(96, 192, 48, 96);
         curve_parameters_test::<BW6_761>(192, 192, 96, 96);
     }
+
+    #[test]
+    fn test_kzg_accumulator_size() {
+        let curve = CurveParameters::<Bls12_377>::new();
+        let kzg = Phase1Parameters::<Bls12_377>::new_full(ProvingSystem::Kzg, 4, 4);
+        assert_eq!(kzg.g1_chunk_size, 16);
+        assert_eq!(kzg.other_chunk_size, 0);
+        assert_eq!(kzg.accumulator_size, 64 + 16 * curve.g1_size + 2 * curve.g2_size);
+
+        // Only the first chunk holds the powers of tau in G2.
+        let chunk = Phase1Parameters::<Bls12_377>::new_chunk(ContributionMode::Chunked, 1, 8, ProvingSystem::Kzg, 4, 4);
+        assert_eq!(chunk.accumulator_size, 64 + 8 * curve.g1_size);
+
+        let marlin = Phase1Parameters::<Bls12_377>::new_full(ProvingSystem::Marlin, 4, 4);
+        assert!(kzg.accumulator_size < marlin.accumulator_size);
+        assert!(kzg.contribution_size < marlin.contribution_size);
+    }
 }
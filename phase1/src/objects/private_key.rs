@@ -1,9 +1,20 @@
+use setup_utils::zeroize_field;
+
 use snarkvm_curves::PairingEngine;
 
 /// Contains the secrets τ, α and β that the participant of the ceremony must destroy.
+/// The secrets are overwritten with zeros when the key is dropped.
 #[derive(PartialEq, Debug)]
 pub struct PrivateKey<E: PairingEngine> {
     pub tau: E::Fr,
     pub alpha: E::Fr,
     pub beta: E::Fr,
 }
+
+impl<E: PairingEngine> Drop for PrivateKey<E> {
+    fn drop(&mut self) {
+        zeroize_field(&mut self.tau);
+        zeroize_field(&mut self.alpha);
+        zeroize_field(&mut self.beta);
+    }
+}
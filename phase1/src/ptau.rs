@@ -0,0 +1,640 @@
+//! Conversion between the accumulator and the `.ptau` format of snarkjs.
+//!
+//! A `.ptau` file is a sequence of sections, each one prefixed with its type and size.
+//! The sections which are relevant to the accumulator are:
+//!
+//! 1. the header, containing the size of a base field element, the base field modulus and the power
+//! 2. the tau powers in G1
+//! 3. the tau powers in G2
+//! 4. the alpha tau powers in G1
+//! 5. the beta tau powers in G1
+//! 6. beta in G2
+//! 7. the contributions made so far
+//!
+//! Group elements are stored uncompressed, with each coordinate in little-endian Montgomery form.
+//! The point at infinity is stored as all zeros. The format is only defined for BN254 and BLS12-381,
+//! and only BLS12-381 can be converted, so the BN254 files of the Hermez ceremony can not be imported
+//! until BN254 is supported.
+//!
+//! Contribution records carry a BLAKE2b state over the layout of snarkjs, which this crate does not
+//! compute. Records can be read and written back, but not created for contributions made here.
+use super::*;
+
+use snarkvm_algorithms::cfg_chunks;
+use snarkvm_fields::{Field, One, Zero};
+use snarkvm_utilities::{CanonicalDeserialize, CanonicalSerialize, ConstantSerializedSize};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::{collections::BTreeMap, convert::TryFrom, io::Write};
+
+const PTAU_MAGIC: &[u8; 4] = b"ptau";
+const PTAU_VERSION: u32 = 1;
+const PTAU_NUM_SECTIONS: u32 = 7;
+
+const HEADER_SECTION: u32 = 1;
+const TAU_G1_SECTION: u32 = 2;
+const TAU_G2_SECTION: u32 = 3;
+const ALPHA_TAU_G1_SECTION: u32 = 4;
+const BETA_TAU_G1_SECTION: u32 = 5;
+const BETA_G2_SECTION: u32 = 6;
+const CONTRIBUTIONS_SECTION: u32 = 7;
+
+/// The size of the intermediate BLAKE2b state which snarkjs stores for each contribution.
+pub const PTAU_PARTIAL_HASH_SIZE: usize = 216;
+/// The size of the challenge hash which snarkjs stores for each contribution.
+pub const PTAU_CHALLENGE_HASH_SIZE: usize = 64;
+
+/// The base field moduli of the curves for which snarkjs defines the `.ptau` format.
+const PTAU_MODULI: [&[u64]; 2] = [
+    // BN254
+    &[
+        0x3c208c16d87cfd47,
+        0x97816a916871ca8d,
+        0xb85045b68181585d,
+        0x30644e72e131a029,
+    ],
+    // BLS12-381
+    &[
+        0xb9feffffffffaaab,
+        0x1eabfffeb153ffff,
+        0x6730d2a0f6b0f624,
+        0x64774b84f38512bf,
+        0x4b1ba7b6434bacd7,
+        0x1a0111ea397fe69a,
+    ],
+];
+
+/// A contribution record of a `.ptau` file.
+///
+/// snarkjs uses these records to verify the history of the ceremony. The partial hash and the
+/// challenge hash are computed by snarkjs over its own file layout, so records can only come
+/// from a `.ptau` file written by snarkjs.
+#[derive(Debug)]
+pub struct PtauContribution<E: PairingEngine> {
+    /// tau^1 in G1 after this contribution
+    pub tau_g1: E::G1Affine,
+    /// tau^1 in G2 after this contribution
+    pub tau_g2: E::G2Affine,
+    /// alpha in G1 after this contribution
+    pub alpha_g1: E::G1Affine,
+    /// beta in G1 after this contribution
+    pub beta_g1: E::G1Affine,
+    /// beta in G2 after this contribution
+    pub beta_g2: E::G2Affine,
+    /// The public key of the contributor
+    pub public_key: PublicKey<E>,
+    /// The intermediate BLAKE2b state over the response, as stored by snarkjs
+    pub partial_hash: Vec<u8>,
+    /// The hash of the challenge which follows this contribution
+    pub next_challenge_hash: Vec<u8>,
+    /// 0 for a regular contribution and 1 for a beacon
+    pub contribution_type: u32,
+    /// The encoded parameters of the contribution (name, beacon hash and iterations)
+    pub parameters: Vec<u8>,
+}
+
+impl<E: PairingEngine> PartialEq for PtauContribution<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.tau_g1 == other.tau_g1
+            && self.tau_g2 == other.tau_g2
+            && self.alpha_g1 == other.alpha_g1
+            && self.beta_g1 == other.beta_g1
+            && self.beta_g2 == other.beta_g2
+            && self.public_key == other.public_key
+            && self.partial_hash == other.partial_hash
+            && self.next_challenge_hash == other.next_challenge_hash
+            && self.contribution_type == other.contribution_type
+            && self.parameters == other.parameters
+    }
+}
+
+impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
+    /// Reads an accumulator and its contribution history from a snarkjs `.ptau` file.
+    ///
+    /// The file may contain more powers than the given parameters, in which case
+    /// only the powers up to `parameters.total_size_in_log2` are read.
+    pub fn read_ptau(
+        input: &[u8],
+        check_input_for_correctness: CheckForCorrectness,
+        parameters: &'a Phase1Parameters<E>,
+    ) -> Result<(Phase1<'a, E>, Vec<PtauContribution<E>>)> {
+        check_ptau_parameters(parameters)?;
+        let encoding = PtauEncoding::<E>::new()?;
+        let sections = read_sections(input)?;
+
+        // Check that the header matches the curve and the requested size.
+        let header = section(&sections, HEADER_SECTION)?;
+        let n8 = read_u32(header, 0)? as usize;
+        if n8 != encoding.n8 {
+            return Err(invalid(format!(
+                "base field elements have {} bytes, expected {}",
+                n8, encoding.n8
+            )));
+        }
+        if header.get(4..4 + n8) != Some(&encoding.modulus()[..]) {
+            return Err(Error::UnsupportedPtauCurve);
+        }
+        let power = read_u32(header, 4 + n8)? as usize;
+        if power < parameters.total_size_in_log2 {
+            return Err(invalid(format!(
+                "the file contains 2^{} powers, expected at least 2^{}",
+                power, parameters.total_size_in_log2
+            )));
+        }
+
+        let check = check_input_for_correctness;
+        let (g1_length, length) = (parameters.powers_g1_length, parameters.powers_length);
+        let accumulator = Phase1 {
+            tau_powers_g1: encoding.read_section(&sections, TAU_G1_SECTION, g1_length, check)?,
+            tau_powers_g2: encoding.read_section(&sections, TAU_G2_SECTION, length, check)?,
+            alpha_tau_powers_g1: encoding.read_section(&sections, ALPHA_TAU_G1_SECTION, length, check)?,
+            beta_tau_powers_g1: encoding.read_section(&sections, BETA_TAU_G1_SECTION, length, check)?,
+            beta_g2: encoding.read_section(&sections, BETA_G2_SECTION, 1, check)?[0],
+            hash: blank_hash(),
+            parameters,
+        };
+
+        let contributions = match sections.get(&CONTRIBUTIONS_SECTION) {
+            Some(buffer) => encoding.read_contributions(buffer)?,
+            None => vec![],
+        };
+
+        Ok((accumulator, contributions))
+    }
+
+    /// Writes the accumulator and the given contribution history as a snarkjs `.ptau` file.
+    ///
+    /// The history must end with the contribution which produced the accumulator, as snarkjs
+    /// checks, so it can only be written back unchanged for an accumulator which has had no
+    /// contribution since it was read.
+    pub fn write_ptau<W: Write>(&self, contributions: &[PtauContribution<E>], output: &mut W) -> Result<()> {
+        check_ptau_parameters(self.parameters)?;
+        let encoding = PtauEncoding::<E>::new()?;
+        if let Some(last) = contributions.last() {
+            if last.tau_g1 != self.tau_powers_g1[1]
+                || last.tau_g2 != self.tau_powers_g2[1]
+                || last.alpha_g1 != self.alpha_tau_powers_g1[0]
+                || last.beta_g1 != self.beta_tau_powers_g1[0]
+                || last.beta_g2 != self.beta_g2
+            {
+                return Err(invalid(
+                    "the accumulator has contributions which the contribution records do not describe".to_string(),
+                ));
+            }
+        }
+
+        output.write_all(PTAU_MAGIC)?;
+        output.write_all(&PTAU_VERSION.to_le_bytes())?;
+        output.write_all(&PTAU_NUM_SECTIONS.to_le_bytes())?;
+
+        let power = self.parameters.total_size_in_log2 as u32;
+        let mut header = vec![];
+        header.extend_from_slice(&(encoding.n8 as u32).to_le_bytes());
+        header.extend_from_slice(&encoding.modulus());
+        header.extend_from_slice(&power.to_le_bytes());
+        // The ceremony power, which is the largest power the ceremony is able to produce.
+        header.extend_from_slice(&power.to_le_bytes());
+        write_section(output, HEADER_SECTION, &header)?;
+
+        write_section(output, TAU_G1_SECTION, &encoding.write_batch(&self.tau_powers_g1)?)?;
+        write_section(output, TAU_G2_SECTION, &encoding.write_batch(&self.tau_powers_g2)?)?;
+        write_section(
+            output,
+            ALPHA_TAU_G1_SECTION,
+            &encoding.write_batch(&self.alpha_tau_powers_g1)?,
+        )?;
+        write_section(
+            output,
+            BETA_TAU_G1_SECTION,
+            &encoding.write_batch(&self.beta_tau_powers_g1)?,
+        )?;
+        write_section(output, BETA_G2_SECTION, &encoding.write_batch(&[self.beta_g2])?)?;
+        write_section(
+            output,
+            CONTRIBUTIONS_SECTION,
+            &encoding.write_contributions(contributions)?,
+        )?;
+
+        Ok(())
+    }
+}
+
+/// Converts group elements between the uncompressed encoding of snarkVM, which stores
+/// canonical coordinates, and the encoding of snarkjs, which stores Montgomery coordinates.
+struct PtauEncoding<E: PairingEngine> {
+    /// The size in bytes of a base field element
+    n8: usize,
+    /// The Montgomery constant R = 2^{8 * n8} of the base field
+    r: E::Fq,
+    /// The inverse of the Montgomery constant
+    r_inv: E::Fq,
+}
+
+impl<E: PairingEngine> PtauEncoding<E> {
+    fn new() -> Result<Self> {
+        if !PTAU_MODULI.iter().any(|modulus| *modulus == E::Fq::characteristic()) {
+            return Err(Error::UnsupportedPtauCurve);
+        }
+
+        let n8 = E::Fq::zero().serialized_size();
+        let r = E::Fq::one().double().pow(&[(n8 * 8) as u64]);
+        let r_inv = r.inverse().expect("R is invertible");
+
+        Ok(Self { n8, r, r_inv })
+    }
+
+    /// Returns the base field modulus encoded in `n8` little-endian bytes.
+    fn modulus(&self) -> Vec<u8> {
+        let mut modulus = E::Fq::characteristic()
+            .iter()
+            .flat_map(|limb| limb.to_le_bytes())
+            .collect::<Vec<u8>>();
+        modulus.resize(self.n8, 0);
+        modulus
+    }
+
+    fn write<G: AffineCurve>(&self, element: &G, output: &mut [u8]) -> Result<()> {
+        if element.is_zero() {
+            output.iter_mut().for_each(|byte| *byte = 0);
+            return Ok(());
+        }
+
+        let mut canonical = vec![0u8; G::UNCOMPRESSED_SIZE];
+        element.serialize_uncompressed(&mut &mut canonical[..])?;
+        // Clear the flags which snarkVM stores in the top bits of the last coordinate.
+        canonical[G::UNCOMPRESSED_SIZE - 1] &= 0x3f;
+
+        for (canonical, montgomery) in canonical.chunks(self.n8).zip(output.chunks_mut(self.n8)) {
+            let coordinate = E::Fq::deserialize(&mut &canonical[..])? * self.r;
+            coordinate.serialize(&mut &mut montgomery[..])?;
+        }
+        Ok(())
+    }
+
+    fn read<G: AffineCurve>(&self, input: &[u8], check_input_for_correctness: CheckForCorrectness) -> Result<G> {
+        let element = match input.iter().all(|byte| *byte == 0) {
+            true => G::zero(),
+            false => {
+                let mut canonical = vec![0u8; G::UNCOMPRESSED_SIZE];
+                for (montgomery, canonical) in input.chunks(self.n8).zip(canonical.chunks_mut(self.n8)) {
+                    let coordinate = E::Fq::deserialize(&mut &montgomery[..])? * self.r_inv;
+                    coordinate.serialize(&mut &mut canonical[..])?;
+                }
+                G::deserialize_uncompressed(&mut &canonical[..])?
+            }
+        };
+
+        if (check_input_for_correctness == CheckForCorrectness::Full
+            || check_input_for_correctness == CheckForCorrectness::OnlyNonZero)
+            && element.is_zero()
+        {
+            return Err(Error::PointAtInfinity);
+        }
+
+        Ok(element)
+    }
+
+    /// Reads the first `length` elements of a section.
+    fn read_section<G: AffineCurve>(
+        &self,
+        sections: &BTreeMap<u32, &[u8]>,
+        section_id: u32,
+        length: usize,
+        check_input_for_correctness: CheckForCorrectness,
+    ) -> Result<Vec<G>> {
+        let size = G::UNCOMPRESSED_SIZE;
+        let buffer = section(sections, section_id)?
+            .get(0..length * size)
+            .ok_or_else(|| too_short(section_id))?;
+        cfg_chunks!(buffer, size)
+            .map(|buffer| self.read(buffer, check_input_for_correctness))
+            .collect()
+    }
+
+    fn write_batch<G: AffineCurve>(&self, elements: &[G]) -> Result<Vec<u8>> {
+        let mut output = vec![0u8; elements.len() * G::UNCOMPRESSED_SIZE];
+        output
+            .chunks_mut(G::UNCOMPRESSED_SIZE)
+            .zip(elements)
+            .try_for_each(|(output, element)| self.write(element, output))?;
+        Ok(output)
+    }
+
+    fn read_contributions(&self, mut input: &[u8]) -> Result<Vec<PtauContribution<E>>> {
+        let count = read_u32(input, 0)?;
+        input = &input[4..];
+
+        let mut take = |size: usize| -> Result<&[u8]> {
+            if input.len() < size {
+                return Err(too_short(CONTRIBUTIONS_SECTION));
+            }
+            let (head, tail) = input.split_at(size);
+            input = tail;
+            Ok(head)
+        };
+
+        let mut contributions = vec![];
+        for _ in 0..count {
+            let g1 = E::G1Affine::UNCOMPRESSED_SIZE;
+            let g2 = E::G2Affine::UNCOMPRESSED_SIZE;
+            let check = CheckForCorrectness::No;
+
+            let tau_g1 = self.read(take(g1)?, check)?;
+            let tau_g2 = self.read(take(g2)?, check)?;
+            let alpha_g1 = self.read(take(g1)?, check)?;
+            let beta_g1 = self.read(take(g1)?, check)?;
+            let beta_g2 = self.read(take(g2)?, check)?;
+            let public_key = PublicKey {
+                tau_g1: (self.read(take(g1)?, check)?, self.read(take(g1)?, check)?),
+                alpha_g1: (self.read(take(g1)?, check)?, self.read(take(g1)?, check)?),
+                beta_g1: (self.read(take(g1)?, check)?, self.read(take(g1)?, check)?),
+                tau_g2: self.read(take(g2)?, check)?,
+                alpha_g2: self.read(take(g2)?, check)?,
+                beta_g2: self.read(take(g2)?, check)?,
+            };
+            let partial_hash = take(PTAU_PARTIAL_HASH_SIZE)?.to_vec();
+            let next_challenge_hash = take(PTAU_CHALLENGE_HASH_SIZE)?.to_vec();
+            let contribution_type = read_u32(take(4)?, 0)?;
+            let parameters_length = read_u32(take(4)?, 0)? as usize;
+            let parameters = take(parameters_length)?.to_vec();
+
+            contributions.push(PtauContribution {
+                tau_g1,
+                tau_g2,
+                alpha_g1,
+                beta_g1,
+                beta_g2,
+                public_key,
+                partial_hash,
+                next_challenge_hash,
+                contribution_type,
+                parameters,
+            });
+        }
+
+        Ok(contributions)
+    }
+
+    fn write_contributions(&self, contributions: &[PtauContribution<E>]) -> Result<Vec<u8>> {
+        let mut output = vec![];
+        output.extend_from_slice(&(contributions.len() as u32).to_le_bytes());
+
+        for contribution in contributions {
+            if contribution.partial_hash.len() != PTAU_PARTIAL_HASH_SIZE {
+                return Err(Error::InvalidLength {
+                    expected: PTAU_PARTIAL_HASH_SIZE,
+                    got: contribution.partial_hash.len(),
+                });
+            }
+            if contribution.partial_hash.iter().all(|byte| *byte == 0) {
+                return Err(invalid(
+                    "contribution records must carry the partial hash computed by snarkjs".to_string(),
+                ));
+            }
+            if contribution.next_challenge_hash.len() != PTAU_CHALLENGE_HASH_SIZE {
+                return Err(Error::InvalidLength {
+                    expected: PTAU_CHALLENGE_HASH_SIZE,
+                    got: contribution.next_challenge_hash.len(),
+                });
+            }
+
+            let key = &contribution.public_key;
+            output.extend(self.write_batch(&[contribution.tau_g1])?);
+            output.extend(self.write_batch(&[contribution.tau_g2])?);
+            output.extend(self.write_batch(&[contribution.alpha_g1, contribution.beta_g1])?);
+            output.extend(self.write_batch(&[contribution.beta_g2])?);
+            output.extend(self.write_batch(&[
+                key.tau_g1.0,
+                key.tau_g1.1,
+                key.alpha_g1.0,
+                key.alpha_g1.1,
+                key.beta_g1.0,
+                key.beta_g1.1,
+            ])?);
+            output.extend(self.write_batch(&[key.tau_g2, key.alpha_g2, key.beta_g2])?);
+            output.extend_from_slice(&contribution.partial_hash);
+            output.extend_from_slice(&contribution.next_challenge_hash);
+            output.extend_from_slice(&contribution.contribution_type.to_le_bytes());
+            output.extend_from_slice(&(contribution.parameters.len() as u32).to_le_bytes());
+            output.extend_from_slice(&contribution.parameters);
+        }
+
+        Ok(output)
+    }
+}
+
+/// The `.ptau` format only describes full Groth16 accumulators.
+fn check_ptau_parameters<E: PairingEngine>(parameters: &Phase1Parameters<E>) -> Result<()> {
+    match (parameters.proving_system, parameters.contribution_mode) {
+        (ProvingSystem::Groth16, ContributionMode::Full) => Ok(()),
+        _ => Err(invalid("only full Groth16 accumulators can be converted".to_string())),
+    }
+}
+
+/// Splits the file into its sections, indexed by section type.
+fn read_sections(input: &[u8]) -> Result<BTreeMap<u32, &[u8]>> {
+    if input.get(0..4) != Some(&PTAU_MAGIC[..]) {
+        return Err(invalid("missing the ptau magic bytes".to_string()));
+    }
+    let version = read_u32(input, 4)?;
+    if version != PTAU_VERSION {
+        return Err(invalid(format!("unsupported version {}", version)));
+    }
+    let num_sections = read_u32(input, 8)?;
+
+    let mut sections = BTreeMap::new();
+    let mut position = 12;
+    for _ in 0..num_sections {
+        let section_id = read_u32(input, position)?;
+        let size = read_u64(input, position + 4)?;
+        position += 12;
+
+        // The section size is untrusted, so it must not overflow the end of the section.
+        let end = usize::try_from(size)
+            .ok()
+            .and_then(|size| position.checked_add(size))
+            .ok_or_else(|| too_short(section_id))?;
+        let section = input.get(position..end).ok_or_else(|| too_short(section_id))?;
+        if sections.insert(section_id, section).is_some() {
+            return Err(invalid(format!("section {} appears more than once", section_id)));
+        }
+        position = end;
+    }
+
+    Ok(sections)
+}
+
+fn section<'b>(sections: &BTreeMap<u32, &'b [u8]>, section_id: u32) -> Result<&'b [u8]> {
+    sections
+        .get(&section_id)
+        .copied()
+        .ok_or_else(|| invalid(format!("missing section {}", section_id)))
+}
+
+fn write_section<W: Write>(output: &mut W, section_id: u32, section: &[u8]) -> Result<()> {
+    output.write_all(&section_id.to_le_bytes())?;
+    output.write_all(&(section.len() as u64).to_le_bytes())?;
+    output.write_all(section)?;
+    Ok(())
+}
+
+fn read_u32(input: &[u8], position: usize) -> Result<u32> {
+    let mut bytes = [0u8; 4];
+    bytes.copy_from_slice(
+        input
+            .get(position..position + 4)
+            .ok_or_else(|| invalid("unexpected end of file".to_string()))?,
+    );
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_u64(input: &[u8], position: usize) -> Result<u64> {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(
+        input
+            .get(position..position + 8)
+            .ok_or_else(|| invalid("unexpected end of file".to_string()))?,
+    );
+    Ok(u64::from_le_bytes(bytes))
+}
+
+fn invalid(message: String) -> Error {
+    Error::InvalidPtauFile(message)
+}
+
+fn too_short(section_id: u32) -> Error {
+    invalid(format!("section {} is too short", section_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::testing::generate_random_accumulator;
+    use setup_utils::curves::{bls12_381::G1Affine, Bls12_381};
+
+    use snarkvm_curves::bls12_377::Bls12_377;
+
+    use rand::thread_rng;
+
+    // A record of the contribution which produced the accumulator, as snarkjs would write it.
+    fn random_contribution<E: PairingEngine + Sync>(accumulator: &Phase1<E>) -> PtauContribution<E> {
+        let (public_key, _) = Phase1::<E>::key_generation(&mut thread_rng(), &[7u8; 64]).unwrap();
+        PtauContribution {
+            tau_g1: accumulator.tau_powers_g1[1],
+            tau_g2: accumulator.tau_powers_g2[1],
+            alpha_g1: accumulator.alpha_tau_powers_g1[0],
+            beta_g1: accumulator.beta_tau_powers_g1[0],
+            beta_g2: accumulator.beta_g2,
+            public_key,
+            partial_hash: vec![5u8; PTAU_PARTIAL_HASH_SIZE],
+            next_challenge_hash: vec![3u8; PTAU_CHALLENGE_HASH_SIZE],
+            contribution_type: 1,
+            parameters: vec![1, 4, b'a', b'l', b'e', b'o'],
+        }
+    }
+
+    #[test]
+    fn test_ptau_round_trip_bls12_381() {
+        let parameters = Phase1Parameters::<Bls12_381>::new_full(ProvingSystem::Groth16, 3, 4);
+        let (_, accumulator) = generate_random_accumulator(&parameters, UseCompression::No);
+        let contributions = vec![random_contribution(&accumulator)];
+
+        let mut output = vec![];
+        accumulator.write_ptau(&contributions, &mut output).unwrap();
+
+        let (deserialized, deserialized_contributions) =
+            Phase1::read_ptau(&output, CheckForCorrectness::Full, &parameters).unwrap();
+        assert_eq!(deserialized, accumulator);
+        assert_eq!(deserialized_contributions, contributions);
+
+        // A file with more powers can be read into a smaller accumulator.
+        let smaller = Phase1Parameters::<Bls12_381>::new_full(ProvingSystem::Groth16, 2, 4);
+        let (truncated, _) = Phase1::read_ptau(&output, CheckForCorrectness::Full, &smaller).unwrap();
+        assert_eq!(
+            truncated.tau_powers_g1[..],
+            accumulator.tau_powers_g1[..smaller.powers_g1_length]
+        );
+        assert_eq!(truncated.beta_g2, accumulator.beta_g2);
+
+        // A file with fewer powers cannot.
+        let larger = Phase1Parameters::<Bls12_381>::new_full(ProvingSystem::Groth16, 4, 4);
+        assert!(Phase1::read_ptau(&output, CheckForCorrectness::Full, &larger).is_err());
+    }
+
+    #[test]
+    fn test_ptau_rejects_records_not_written_by_snarkjs() {
+        let parameters = Phase1Parameters::<Bls12_381>::new_full(ProvingSystem::Groth16, 2, 4);
+        let (_, accumulator) = generate_random_accumulator(&parameters, UseCompression::No);
+
+        // A record without the partial hash of snarkjs.
+        let mut contribution = random_contribution(&accumulator);
+        contribution.partial_hash = vec![0u8; PTAU_PARTIAL_HASH_SIZE];
+        assert!(accumulator.write_ptau(&[contribution], &mut vec![]).is_err());
+
+        // A history which does not end with the contribution which produced the accumulator.
+        let (_, other) = generate_random_accumulator(&parameters, UseCompression::No);
+        let contribution = random_contribution(&other);
+        assert!(accumulator.write_ptau(&[contribution], &mut vec![]).is_err());
+    }
+
+    #[test]
+    fn test_ptau_montgomery_encoding_bls12_381() {
+        let encoding = PtauEncoding::<Bls12_381>::new().unwrap();
+        let generator = G1Affine::prime_subgroup_generator();
+
+        let mut output = vec![0u8; G1Affine::UNCOMPRESSED_SIZE];
+        encoding.write(&generator, &mut output).unwrap();
+
+        // The coordinates are stored in Montgomery form, not in the canonical form of snarkVM.
+        let mut canonical = vec![];
+        generator.serialize_uncompressed(&mut canonical).unwrap();
+        assert_ne!(output, canonical);
+
+        let decoded: G1Affine = encoding.read(&output, CheckForCorrectness::Full).unwrap();
+        assert_eq!(decoded, generator);
+
+        // The point at infinity is stored as all zeros.
+        encoding.write(&G1Affine::zero(), &mut output).unwrap();
+        assert!(output.iter().all(|byte| *byte == 0));
+        assert!(encoding.read::<G1Affine>(&output, CheckForCorrectness::Full).is_err());
+        assert!(encoding
+            .read::<G1Affine>(&output, CheckForCorrectness::No)
+            .unwrap()
+            .is_zero());
+    }
+
+    #[test]
+    fn test_ptau_rejects_unsupported_accumulators() {
+        let parameters = Phase1Parameters::<Bls12_377>::new_full(ProvingSystem::Groth16, 2, 4);
+        let (_, accumulator) = generate_random_accumulator(&parameters, UseCompression::No);
+        assert!(matches!(
+            accumulator.write_ptau(&[], &mut vec![]),
+            Err(Error::UnsupportedPtauCurve)
+        ));
+
+        let parameters = Phase1Parameters::<Bls12_381>::new_full(ProvingSystem::Marlin, 2, 4);
+        let (_, accumulator) = generate_random_accumulator(&parameters, UseCompression::No);
+        assert!(accumulator.write_ptau(&[], &mut vec![]).is_err());
+    }
+
+    #[test]
+    fn test_ptau_rejects_malformed_files() {
+        let parameters = Phase1Parameters::<Bls12_381>::new_full(ProvingSystem::Groth16, 2, 4);
+        let (_, accumulator) = generate_random_accumulator(&parameters, UseCompression::No);
+        let mut output = vec![];
+        accumulator.write_ptau(&[], &mut output).unwrap();
+
+        let truncated = &output[..output.len() - 1];
+        assert!(Phase1::read_ptau(truncated, CheckForCorrectness::Full, &parameters).is_err());
+
+        let mut wrong_magic = output.clone();
+        wrong_magic[0] = b'x';
+        assert!(Phase1::read_ptau(&wrong_magic, CheckForCorrectness::Full, &parameters).is_err());
+
+        // The size of the first section is at offset 16, after the header and the section id.
+        let mut oversized_section = output.clone();
+        oversized_section[16..24].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(Phase1::read_ptau(&oversized_section, CheckForCorrectness::Full, &parameters).is_err());
+    }
+}
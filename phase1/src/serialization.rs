@@ -20,12 +20,14 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
         Ok(())
     }
 
+    /// Reads an accumulator, whose header is checked against the given parameters if it has one.
     pub fn deserialize(
         input: &[u8],
         compression: UseCompression,
         check_input_for_correctness: CheckForCorrectness,
         parameters: &'a Phase1Parameters<E>,
     ) -> Result<Phase1<'a, E>> {
+        let (_, input) = check_file_header(input, compression, parameters)?;
         let (tau_powers_g1, tau_powers_g2, alpha_tau_powers_g1, beta_tau_powers_g1, beta_g2) =
             accumulator::deserialize(input, compression, check_input_for_correctness, parameters)?;
         Ok(Phase1 {
@@ -39,6 +41,29 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
         })
     }
 
+    /// Returns the serialized sections of an accumulator without deserializing them, e.g. to
+    /// prepare Phase 2 with `Groth16Params::write_streaming`. The header of the accumulator
+    /// is checked against the given parameters if it has one.
+    pub fn serialized_powers<'b>(
+        input: &'b [u8],
+        compression: UseCompression,
+        parameters: &Phase1Parameters<E>,
+    ) -> Result<SerializedPowersOfTau<'b>> {
+        let (_, input) = check_file_header(input, compression, parameters)?;
+        let (tau_powers_g1, tau_powers_g2, alpha_tau_powers_g1, beta_tau_powers_g1, beta_g2) =
+            split(input, parameters, compression);
+        Ok(SerializedPowersOfTau {
+            tau_powers_g1,
+            tau_powers_g2,
+            alpha_tau_powers_g1,
+            beta_tau_powers_g1,
+            beta_g2,
+            compression,
+        })
+    }
+
+    /// Decompresses a response into a challenge. The header of the response is checked
+    /// against the given parameters if it has one, and the challenge is written without a header.
     #[cfg(not(feature = "wasm"))]
     pub fn decompress(
         input: &[u8],
@@ -46,20 +71,73 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
         check_input_for_correctness: CheckForCorrectness,
         parameters: &'a Phase1Parameters<E>,
     ) -> Result<()> {
+        let (_, input) = check_file_header(input, UseCompression::Yes, parameters)?;
         accumulator::decompress(input, output, check_input_for_correctness, parameters)?;
         Ok(())
     }
+
+    /// Converts an accumulator between compressed and uncompressed elements. If `with_public_key`
+    /// is set, the input is a response and its public key is copied after the output accumulator.
+    /// The hash is copied as is. The header of the input is checked against the given parameters
+    /// if it has one, and the output is written without a header.
+    #[cfg(not(feature = "wasm"))]
+    pub fn convert_compression(
+        input: &[u8],
+        output: &mut [u8],
+        compressed_input: UseCompression,
+        compressed_output: UseCompression,
+        with_public_key: bool,
+        check_input_for_correctness: CheckForCorrectness,
+        parameters: &'a Phase1Parameters<E>,
+    ) -> Result<()> {
+        let (_, input) = check_file_header(input, compressed_input, parameters)?;
+
+        let public_key_size = match with_public_key {
+            true => parameters.public_key_size,
+            false => 0,
+        };
+        let input_length = parameters.get_length(compressed_input) + public_key_size;
+        let output_length = parameters.get_length(compressed_output) + public_key_size;
+        if input.len() != input_length {
+            return Err(Error::InvalidLength {
+                expected: input_length,
+                got: input.len(),
+            });
+        }
+        if output.len() != output_length {
+            return Err(Error::InvalidLength {
+                expected: output_length,
+                got: output.len(),
+            });
+        }
+
+        output[..parameters.hash_size].copy_from_slice(&input[..parameters.hash_size]);
+        accumulator::convert(
+            input,
+            output,
+            compressed_input,
+            compressed_output,
+            check_input_for_correctness,
+            parameters,
+        )?;
+        if with_public_key {
+            let public_key = PublicKey::<E>::read(input, compressed_input, parameters)?;
+            public_key.write(output, compressed_output, parameters)?;
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::helpers::testing::{generate_output, generate_random_accumulator};
+    use crate::helpers::testing::{generate_output, generate_random_accumulator, setup_verify};
 
     use snarkvm_curves::{bls12_377::Bls12_377, bw6_761::BW6_761};
 
     fn serialize_curve_test<E: PairingEngine + Sync>(compress: UseCompression, size: usize, batch: usize) {
-        for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin] {
+        for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin, ProvingSystem::Kzg] {
             // Create a small accumulator with some random state.
             let parameters = Phase1Parameters::<E>::new_full(*proving_system, size, batch);
             let (buffer, accumulator) = generate_random_accumulator(&parameters, compress);
@@ -69,7 +147,7 @@ mod tests {
     }
 
     fn decompress_curve_test<E: PairingEngine>() {
-        for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin] {
+        for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin, ProvingSystem::Kzg] {
             let parameters = Phase1Parameters::<E>::new_full(*proving_system, 2, 2);
             // generate a random input compressed accumulator
             let (input, before) = generate_random_accumulator(&parameters, UseCompression::Yes);
@@ -88,6 +166,100 @@ mod tests {
         }
     }
 
+    fn convert_compression_test<E: PairingEngine + Sync>(parameters: &Phase1Parameters<E>) {
+        let (challenge, mut response, public_key, _) = setup_verify(
+            UseCompression::No,
+            CheckForCorrectness::No,
+            UseCompression::Yes,
+            parameters,
+        );
+        response.resize(parameters.contribution_size, 0);
+        public_key
+            .write(&mut response, UseCompression::Yes, parameters)
+            .unwrap();
+
+        // Compress the challenge, and decompress it back.
+        let mut compressed = generate_output(parameters, UseCompression::Yes);
+        Phase1::convert_compression(
+            &challenge,
+            &mut compressed,
+            UseCompression::No,
+            UseCompression::Yes,
+            false,
+            CheckForCorrectness::Full,
+            parameters,
+        )
+        .unwrap();
+        let mut decompressed = generate_output(parameters, UseCompression::No);
+        Phase1::convert_compression(
+            &compressed,
+            &mut decompressed,
+            UseCompression::Yes,
+            UseCompression::No,
+            false,
+            CheckForCorrectness::Full,
+            parameters,
+        )
+        .unwrap();
+        assert_eq!(decompressed, challenge);
+
+        // Decompress the response, and compress it back, keeping its public key.
+        let mut decompressed = vec![0; parameters.accumulator_size + parameters.public_key_size];
+        Phase1::convert_compression(
+            &response,
+            &mut decompressed,
+            UseCompression::Yes,
+            UseCompression::No,
+            true,
+            CheckForCorrectness::Full,
+            parameters,
+        )
+        .unwrap();
+        assert_eq!(
+            PublicKey::read(&decompressed, UseCompression::No, parameters).unwrap(),
+            public_key
+        );
+        let mut compressed = vec![0; parameters.contribution_size];
+        Phase1::convert_compression(
+            &decompressed,
+            &mut compressed,
+            UseCompression::No,
+            UseCompression::Yes,
+            true,
+            CheckForCorrectness::Full,
+            parameters,
+        )
+        .unwrap();
+        assert_eq!(compressed, response);
+
+        // The output must have room for the public key.
+        Phase1::convert_compression(
+            &response,
+            &mut generate_output(parameters, UseCompression::No),
+            UseCompression::Yes,
+            UseCompression::No,
+            true,
+            CheckForCorrectness::Full,
+            parameters,
+        )
+        .unwrap_err();
+    }
+
+    #[test]
+    fn test_convert_compression_bls12_377() {
+        for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin, ProvingSystem::Kzg] {
+            convert_compression_test(&Phase1Parameters::<Bls12_377>::new_full(*proving_system, 3, 4));
+            convert_compression_test(&Phase1Parameters::<Bls12_377>::new_chunk(
+                ContributionMode::Chunked,
+                1,
+                4,
+                *proving_system,
+                3,
+                4,
+            ));
+        }
+    }
+
     #[test]
     fn test_serialization_bls12_377() {
         serialize_curve_test::<Bls12_377>(UseCompression::Yes, 2, 2);
@@ -0,0 +1,199 @@
+use super::*;
+
+impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
+    ///
+    /// Phase 1: Splitting
+    ///
+    /// Takes as input a full accumulator in serialized form, and writes
+    /// the elements of each chunk to the corresponding output buffer, which
+    /// is the challenge of this chunk. This is the opposite of the aggregation.
+    ///
+    /// The chunks are given by the chunk size of `parameters`, and each output
+    /// starts with the hash of the full accumulator, which is copied as is.
+    ///
+    pub fn splitting(
+        (input, compressed_input): (&[u8], UseCompression),
+        outputs: &mut [(&mut [u8], UseCompression)],
+        parameters: &Phase1Parameters<E>,
+    ) -> Result<()> {
+        let span = info_span!("phase1-splitting");
+        let _enter = span.enter();
+
+        info!("starting...");
+
+        let full_parameters = parameters.into_chunk_parameters(ContributionMode::Full, 0, 0);
+        if input.len() < full_parameters.get_length(compressed_input) {
+            return Err(Error::InvalidLength {
+                expected: full_parameters.get_length(compressed_input),
+                got: input.len(),
+            });
+        }
+        let total_size = match parameters.proving_system {
+            ProvingSystem::Groth16 => parameters.powers_g1_length,
+            ProvingSystem::Marlin | ProvingSystem::Kzg => parameters.powers_length,
+        };
+        let num_chunks = (total_size + parameters.chunk_size - 1) / parameters.chunk_size;
+        if outputs.len() != num_chunks {
+            return Err(Error::InvalidLength {
+                expected: num_chunks,
+                got: outputs.len(),
+            });
+        }
+
+        for (chunk_index, (output, compressed_output)) in outputs.iter_mut().enumerate() {
+            let chunk_parameters =
+                parameters.into_chunk_parameters(ContributionMode::Chunked, chunk_index, parameters.chunk_size);
+            let compressed_output = *compressed_output;
+            if output.len() != chunk_parameters.get_length(compressed_output) {
+                return Err(Error::InvalidLength {
+                    expected: chunk_parameters.get_length(compressed_output),
+                    got: output.len(),
+                });
+            }
+
+            debug!("splitting chunk {}", chunk_index);
+
+            output[..parameters.hash_size].copy_from_slice(&input[..parameters.hash_size]);
+
+            let (in_tau_g1, in_tau_g2, in_alpha_g1, in_beta_g1, in_beta_g2) =
+                split_at_chunk(input, &chunk_parameters, compressed_input);
+            let (tau_g1, tau_g2, alpha_g1, beta_g1, beta_g2) = split_mut(output, &chunk_parameters, compressed_output);
+
+            copy_elements::<E::G1Affine>(in_tau_g1, compressed_input, tau_g1, compressed_output)?;
+            copy_elements::<E::G2Affine>(in_tau_g2, compressed_input, tau_g2, compressed_output)?;
+            copy_elements::<E::G1Affine>(in_alpha_g1, compressed_input, alpha_g1, compressed_output)?;
+            copy_elements::<E::G1Affine>(in_beta_g1, compressed_input, beta_g1, compressed_output)?;
+            if parameters.proving_system == ProvingSystem::Groth16 {
+                copy_elements::<E::G2Affine>(in_beta_g2, compressed_input, beta_g2, compressed_output)?;
+            }
+
+            debug!("chunk {} processing successful", chunk_index);
+        }
+
+        info!("phase1-splitting complete");
+
+        Ok(())
+    }
+}
+
+/// Reads the elements of an input section and writes them to the output section.
+fn copy_elements<C: AffineCurve>(
+    input: &[u8],
+    compressed_input: UseCompression,
+    output: &mut [u8],
+    compressed_output: UseCompression,
+) -> Result<()> {
+    let elements: Vec<C> = input.read_batch(compressed_input, CheckForCorrectness::No)?;
+    output.write_batch(&elements, compressed_output)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::testing::generate_random_accumulator;
+
+    use snarkvm_curves::bls12_377::Bls12_377;
+
+    fn splitting_test<E: PairingEngine + Sync>(
+        proving_system: ProvingSystem,
+        powers: usize,
+        num_chunks: usize,
+        compressed_input: UseCompression,
+        compressed_output: UseCompression,
+    ) {
+        let full_parameters = Phase1Parameters::<E>::new_full(proving_system, powers, 4);
+        let (mut input, _) = generate_random_accumulator(&full_parameters, compressed_input);
+        input[..full_parameters.hash_size].copy_from_slice(&[7; 64]);
+        let full = Phase1::deserialize(&input, compressed_input, CheckForCorrectness::No, &full_parameters).unwrap();
+
+        let chunk_size = match proving_system {
+            ProvingSystem::Groth16 => (full_parameters.powers_g1_length + num_chunks - 1) / num_chunks,
+            ProvingSystem::Marlin | ProvingSystem::Kzg => (full_parameters.powers_length + num_chunks - 1) / num_chunks,
+        };
+        let parameters = full_parameters.into_chunk_parameters(ContributionMode::Chunked, 0, chunk_size);
+        let mut outputs: Vec<Vec<u8>> = (0..num_chunks)
+            .map(|chunk_index| {
+                let chunk_parameters =
+                    parameters.into_chunk_parameters(ContributionMode::Chunked, chunk_index, chunk_size);
+                vec![0; chunk_parameters.get_length(compressed_output)]
+            })
+            .collect();
+
+        Phase1::splitting(
+            (&input, compressed_input),
+            &mut outputs
+                .iter_mut()
+                .map(|output| (output.as_mut_slice(), compressed_output))
+                .collect::<Vec<_>>(),
+            &parameters,
+        )
+        .unwrap();
+
+        // Each chunk contains its part of the full accumulator.
+        for (chunk_index, output) in outputs.iter().enumerate() {
+            assert_eq!(&output[..64], &[7; 64][..]);
+            let chunk_parameters = parameters.into_chunk_parameters(ContributionMode::Chunked, chunk_index, chunk_size);
+            let chunk =
+                Phase1::deserialize(output, compressed_output, CheckForCorrectness::No, &chunk_parameters).unwrap();
+
+            let start = chunk_index * chunk_size;
+            let end = start + chunk_parameters.g1_chunk_size;
+            assert_eq!(chunk.tau_powers_g1, full.tau_powers_g1[start..end]);
+            match proving_system {
+                ProvingSystem::Groth16 => {
+                    let others = match chunk_parameters.other_chunk_size {
+                        0 => 0..0,
+                        other_chunk_size => start..start + other_chunk_size,
+                    };
+                    assert_eq!(chunk.tau_powers_g2, full.tau_powers_g2[others.clone()]);
+                    assert_eq!(chunk.alpha_tau_powers_g1, full.alpha_tau_powers_g1[others.clone()]);
+                    assert_eq!(chunk.beta_tau_powers_g1, full.beta_tau_powers_g1[others]);
+                    assert_eq!(chunk.beta_g2, full.beta_g2);
+                }
+                ProvingSystem::Marlin | ProvingSystem::Kzg if chunk_index == 0 => {
+                    assert_eq!(chunk.tau_powers_g2, full.tau_powers_g2);
+                    assert_eq!(chunk.alpha_tau_powers_g1, full.alpha_tau_powers_g1);
+                }
+                ProvingSystem::Marlin | ProvingSystem::Kzg => {
+                    assert!(chunk.tau_powers_g2.is_empty());
+                    assert!(chunk.alpha_tau_powers_g1.is_empty());
+                }
+            }
+        }
+
+        // Aggregating the chunks gives back the full accumulator.
+        let mut aggregated = vec![0; full_parameters.get_length(compressed_input)];
+        Phase1::aggregation(
+            &outputs
+                .iter()
+                .map(|output| (output.as_slice(), compressed_output))
+                .collect::<Vec<_>>(),
+            (&mut aggregated, compressed_input),
+            &parameters,
+        )
+        .unwrap();
+        assert_eq!(aggregated[64..], input[64..]);
+
+        // The number of outputs must match the number of chunks.
+        Phase1::splitting(
+            (&input, compressed_input),
+            &mut outputs[1..]
+                .iter_mut()
+                .map(|output| (output.as_mut_slice(), compressed_output))
+                .collect::<Vec<_>>(),
+            &parameters,
+        )
+        .unwrap_err();
+    }
+
+    #[test]
+    fn test_splitting_bls12_377() {
+        for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin, ProvingSystem::Kzg] {
+            for num_chunks in &[1, 2, 3, 4] {
+                splitting_test::<Bls12_377>(*proving_system, 3, *num_chunks, UseCompression::No, UseCompression::No);
+                splitting_test::<Bls12_377>(*proving_system, 3, *num_chunks, UseCompression::Yes, UseCompression::No);
+            }
+        }
+    }
+}
@@ -0,0 +1,446 @@
+//! Variants of the phase1 operations which read and write their buffers through
+//! `Read + Seek` and `Write + Seek` sources instead of in-memory slices.
+//!
+//! The accumulator is processed in windows of at most `batch_size` elements, which are aligned
+//! with both the chunk and the batches of the accumulator. Each window is read into memory
+//! with the layout of a chunk of that size, processed by the in-memory operation for the
+//! chunk, and its sections are written back at their offsets in the output. Only a window
+//! of each buffer is held in memory at any time.
+use super::*;
+
+use std::{
+    io::{Read, Seek, SeekFrom, Write},
+    ops::Range,
+};
+
+impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
+    ///
+    /// Phase 1 - Computation, over an input source and an output sink.
+    ///
+    /// The output must already have the length of the response. Its hash and public key
+    /// are not written, like with `computation`.
+    ///
+    pub fn computation_streaming<R: Read + Seek, W: Write + Seek>(
+        input: &mut R,
+        output: &mut W,
+        compressed_input: UseCompression,
+        compressed_output: UseCompression,
+        check_input_for_correctness: CheckForCorrectness,
+        key: &PrivateKey<E>,
+        parameters: &Phase1Parameters<E>,
+    ) -> Result<()> {
+        for window in windows(parameters) {
+            let input_window = read_window(input, &window, parameters, compressed_input)?;
+            let mut output_window = vec![0; window.get_length(compressed_output)];
+
+            Phase1::computation(
+                &input_window,
+                &mut output_window,
+                compressed_input,
+                compressed_output,
+                check_input_for_correctness,
+                key,
+                &window,
+            )?;
+
+            write_window(output, &output_window, &window, parameters, compressed_output)?;
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Phase 1 - Verification, over an input source and an output source.
+    ///
+    #[cfg(not(feature = "wasm"))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn verification_streaming<R: Read + Seek, S: Read + Seek>(
+        input: &mut R,
+        output: &mut S,
+        key: &PublicKey<E>,
+        digest: &[u8],
+        compressed_input: UseCompression,
+        compressed_output: UseCompression,
+        check_input_for_correctness: CheckForCorrectness,
+        check_output_for_correctness: CheckForCorrectness,
+        parameters: &Phase1Parameters<E>,
+    ) -> Result<()> {
+        for window in windows(parameters) {
+            let input_window = read_window(input, &window, parameters, compressed_input)?;
+            let output_window = read_window(output, &window, parameters, compressed_output)?;
+
+            Phase1::verification(
+                &input_window,
+                &output_window,
+                key,
+                digest,
+                compressed_input,
+                compressed_output,
+                check_input_for_correctness,
+                check_output_for_correctness,
+                &window,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Phase 1: Aggregation, from chunk sources into a full accumulator sink.
+    ///
+    /// The output must already have the length of the full accumulator.
+    ///
+    #[cfg(not(feature = "wasm"))]
+    pub fn aggregation_streaming<R: Read + Seek, W: Write + Seek>(
+        inputs: &mut [(R, UseCompression)],
+        (output, compressed_output): (&mut W, UseCompression),
+        parameters: &Phase1Parameters<E>,
+    ) -> Result<()> {
+        let full_parameters = parameters.into_chunk_parameters(ContributionMode::Full, 0, 0);
+
+        for (chunk_index, (input, compressed_input)) in inputs.iter_mut().enumerate() {
+            let chunk_parameters =
+                parameters.into_chunk_parameters(parameters.contribution_mode, chunk_index, parameters.chunk_size);
+            debug!("combining chunk {}", chunk_index);
+
+            for window in windows(&chunk_parameters) {
+                let input_ranges = window_ranges(&window, &chunk_parameters, *compressed_input);
+                let output_ranges = window_ranges(&window, &full_parameters, compressed_output);
+
+                for (section, (input_range, output_range)) in input_ranges.iter().zip(&output_ranges).enumerate() {
+                    let mut elements = vec![0; input_range.len()];
+                    input.seek(SeekFrom::Start(input_range.start as u64))?;
+                    input.read_exact(&mut elements)?;
+
+                    let elements = match section {
+                        1 | 4 => recompress::<E::G2Affine>(elements, *compressed_input, compressed_output)?,
+                        _ => recompress::<E::G1Affine>(elements, *compressed_input, compressed_output)?,
+                    };
+                    output.seek(SeekFrom::Start(output_range.start as u64))?;
+                    output.write_all(&elements)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the parameters of the windows which cover the elements of the given parameters.
+fn windows<E: PairingEngine>(parameters: &Phase1Parameters<E>) -> Vec<Phase1Parameters<E>> {
+    let upper_bound = match parameters.proving_system {
+        ProvingSystem::Groth16 => parameters.powers_g1_length,
+        ProvingSystem::Marlin | ProvingSystem::Kzg => parameters.powers_length,
+    };
+    let (start, end, window_size) = match parameters.contribution_mode {
+        ContributionMode::Chunked => {
+            let start = parameters.chunk_index * parameters.chunk_size;
+            let end = std::cmp::min(start + parameters.chunk_size, upper_bound);
+            // Windows must be aligned with the chunk and the batches, and hold at most a batch.
+            let window_size = match parameters.chunk_size <= parameters.batch_size {
+                true => parameters.chunk_size,
+                false => gcd(parameters.chunk_size, parameters.batch_size),
+            };
+            (start, end, window_size)
+        }
+        ContributionMode::Full => (0, upper_bound, parameters.batch_size),
+    };
+
+    (start..end)
+        .step_by(window_size)
+        .map(|window_start| {
+            parameters.into_chunk_parameters(ContributionMode::Chunked, window_start / window_size, window_size)
+        })
+        .collect()
+}
+
+/// Returns the greatest common divisor of `a` and `b`.
+fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        let remainder = a % b;
+        a = b;
+        b = remainder;
+    }
+    a
+}
+
+/// Returns the number of elements in each section of a buffer with the given parameters,
+/// in the order [TauG1, TauG2, AlphaG1, BetaG1, BetaG2].
+fn section_lengths<E: PairingEngine>(parameters: &Phase1Parameters<E>) -> [usize; 5] {
+    match parameters.proving_system {
+        ProvingSystem::Groth16 => [
+            parameters.g1_chunk_size,
+            parameters.other_chunk_size,
+            parameters.other_chunk_size,
+            parameters.other_chunk_size,
+            1,
+        ],
+        ProvingSystem::Marlin | ProvingSystem::Kzg => {
+            let (g2_chunk_size, alpha_chunk_size) = first_chunk_sizes(parameters);
+            [parameters.g1_chunk_size, g2_chunk_size, alpha_chunk_size, 0, 0]
+        }
+    }
+}
+
+/// Returns the byte ranges of the sections of a window in a buffer with the given parameters.
+fn window_ranges<E: PairingEngine>(
+    window: &Phase1Parameters<E>,
+    parameters: &Phase1Parameters<E>,
+    compressed: UseCompression,
+) -> [Range<usize>; 5] {
+    let g1_size = buffer_size::<E::G1Affine>(compressed);
+    let g2_size = buffer_size::<E::G2Affine>(compressed);
+    let element_sizes = [g1_size, g2_size, g1_size, g1_size, g2_size];
+
+    // The powers of tau are located by the index of the window in the buffer, while
+    // the other sections are either fully contained in the window or not at all.
+    let located = match parameters.proving_system {
+        ProvingSystem::Groth16 => [true, true, true, true, false],
+        ProvingSystem::Marlin | ProvingSystem::Kzg => [true, false, false, false, false],
+    };
+    let buffer_start = match parameters.contribution_mode {
+        ContributionMode::Chunked => parameters.chunk_index * parameters.chunk_size,
+        ContributionMode::Full => 0,
+    };
+    let window_offset = window.chunk_index * window.chunk_size - buffer_start;
+
+    let buffer_lengths = section_lengths(parameters);
+    let window_lengths = section_lengths(window);
+
+    let mut ranges: [Range<usize>; 5] = Default::default();
+    let mut section_start = parameters.hash_size;
+    for (i, range) in ranges.iter_mut().enumerate() {
+        let start = match located[i] {
+            true => section_start + window_offset * element_sizes[i],
+            false => section_start,
+        };
+        *range = start..start + window_lengths[i] * element_sizes[i];
+        section_start += buffer_lengths[i] * element_sizes[i];
+    }
+    ranges
+}
+
+/// Reads a window from a source, with the layout of a buffer with the window parameters.
+fn read_window<E: PairingEngine, R: Read + Seek>(
+    source: &mut R,
+    window: &Phase1Parameters<E>,
+    parameters: &Phase1Parameters<E>,
+    compressed: UseCompression,
+) -> Result<Vec<u8>> {
+    let mut buffer = vec![0; window.get_length(compressed)];
+
+    // Keep the hash of the source, which is the start of both layouts.
+    source.seek(SeekFrom::Start(0))?;
+    source.read_exact(&mut buffer[..window.hash_size])?;
+
+    let mut position = window.hash_size;
+    for range in window_ranges(window, parameters, compressed).iter() {
+        source.seek(SeekFrom::Start(range.start as u64))?;
+        source.read_exact(&mut buffer[position..position + range.len()])?;
+        position += range.len();
+    }
+
+    Ok(buffer)
+}
+
+/// Writes the sections of a buffer with the window parameters to a sink.
+fn write_window<E: PairingEngine, W: Write + Seek>(
+    sink: &mut W,
+    buffer: &[u8],
+    window: &Phase1Parameters<E>,
+    parameters: &Phase1Parameters<E>,
+    compressed: UseCompression,
+) -> Result<()> {
+    let mut position = window.hash_size;
+    for range in window_ranges(window, parameters, compressed).iter() {
+        sink.seek(SeekFrom::Start(range.start as u64))?;
+        sink.write_all(&buffer[position..position + range.len()])?;
+        position += range.len();
+    }
+
+    Ok(())
+}
+
+/// Converts serialized elements from one compression to another.
+#[cfg(not(feature = "wasm"))]
+fn recompress<C: AffineCurve>(
+    elements: Vec<u8>,
+    compressed_input: UseCompression,
+    compressed_output: UseCompression,
+) -> Result<Vec<u8>> {
+    if compressed_input == compressed_output {
+        return Ok(elements);
+    }
+
+    let elements: Vec<C> = elements.read_batch(compressed_input, CheckForCorrectness::No)?;
+    let mut output = vec![0; elements.len() * buffer_size::<C>(compressed_output)];
+    output.write_batch(&elements, compressed_output)?;
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::testing::{generate_input, generate_output};
+
+    use snarkvm_curves::bls12_377::Bls12_377;
+
+    use std::io::Cursor;
+
+    fn streaming_test<E: PairingEngine + Sync>(parameters: &Phase1Parameters<E>) {
+        let compressed_input = UseCompression::No;
+        let compressed_output = UseCompression::Yes;
+        let digest = blank_hash();
+        let (input, _) = generate_input(parameters, compressed_input, CheckForCorrectness::No);
+
+        let mut rng = derive_rng_from_seed(b"streaming_test");
+        let (public_key, private_key) = Phase1::key_generation(&mut rng, digest.as_ref()).unwrap();
+
+        let mut expected = generate_output(parameters, compressed_output);
+        Phase1::computation(
+            &input,
+            &mut expected,
+            compressed_input,
+            compressed_output,
+            CheckForCorrectness::No,
+            &private_key,
+            parameters,
+        )
+        .unwrap();
+
+        let mut output = Cursor::new(generate_output(parameters, compressed_output));
+        Phase1::computation_streaming(
+            &mut Cursor::new(&input),
+            &mut output,
+            compressed_input,
+            compressed_output,
+            CheckForCorrectness::No,
+            &private_key,
+            parameters,
+        )
+        .unwrap();
+        assert_eq!(output.get_ref(), &expected);
+
+        Phase1::verification_streaming(
+            &mut Cursor::new(&input),
+            &mut Cursor::new(&expected),
+            &public_key,
+            &digest,
+            compressed_input,
+            compressed_output,
+            CheckForCorrectness::Full,
+            CheckForCorrectness::Full,
+            parameters,
+        )
+        .unwrap();
+
+        // The proofs of knowledge are checked in the first chunk, so the key must match there.
+        if parameters.chunk_index == 0 {
+            let (other_public_key, _) = Phase1::<E>::key_generation(&mut rng, digest.as_ref()).unwrap();
+            assert!(Phase1::verification_streaming(
+                &mut Cursor::new(&input),
+                &mut Cursor::new(&expected),
+                &other_public_key,
+                &digest,
+                compressed_input,
+                compressed_output,
+                CheckForCorrectness::Full,
+                CheckForCorrectness::Full,
+                parameters,
+            )
+            .is_err());
+        }
+    }
+
+    #[test]
+    fn test_streaming_full() {
+        for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin, ProvingSystem::Kzg] {
+            streaming_test(&Phase1Parameters::<Bls12_377>::new_full(*proving_system, 4, 4));
+        }
+    }
+
+    #[test]
+    fn test_streaming_chunked() {
+        for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin, ProvingSystem::Kzg] {
+            for chunk_index in 0..2 {
+                streaming_test(&Phase1Parameters::<Bls12_377>::new_chunk(
+                    ContributionMode::Chunked,
+                    chunk_index,
+                    8,
+                    *proving_system,
+                    4,
+                    4,
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn test_streaming_chunk_not_multiple_of_batch() {
+        for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin, ProvingSystem::Kzg] {
+            for chunk_index in 0..3 {
+                let parameters = Phase1Parameters::<Bls12_377>::new_chunk(
+                    ContributionMode::Chunked,
+                    chunk_index,
+                    6,
+                    *proving_system,
+                    4,
+                    4,
+                );
+
+                // The windows hold at most a batch, and end at every batch boundary of the chunk.
+                let windows = windows(&parameters);
+                assert!(windows.iter().all(|window| window.chunk_size == 2));
+                let boundaries = windows
+                    .iter()
+                    .map(|window| (window.chunk_index + 1) * window.chunk_size)
+                    .collect::<Vec<_>>();
+                let start = chunk_index * 6;
+                assert!((start + 1..start + 6)
+                    .filter(|boundary| boundary % 4 == 0)
+                    .all(|boundary| boundaries.contains(&boundary)));
+
+                streaming_test(&parameters);
+            }
+        }
+    }
+
+    #[test]
+    fn test_aggregation_streaming() {
+        let compressed_input = UseCompression::Yes;
+        let compressed_output = UseCompression::No;
+
+        for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin, ProvingSystem::Kzg] {
+            let parameters =
+                Phase1Parameters::<Bls12_377>::new_chunk(ContributionMode::Chunked, 0, 8, *proving_system, 4, 4);
+            let full_parameters = parameters.into_chunk_parameters(ContributionMode::Full, 0, 0);
+            let num_chunks = match proving_system {
+                ProvingSystem::Groth16 => (parameters.powers_g1_length + 7) / 8,
+                ProvingSystem::Marlin | ProvingSystem::Kzg => (parameters.powers_length + 7) / 8,
+            };
+
+            let chunks = (0..num_chunks)
+                .map(|chunk_index| {
+                    let chunk_parameters = parameters.into_chunk_parameters(ContributionMode::Chunked, chunk_index, 8);
+                    generate_input(&chunk_parameters, compressed_input, CheckForCorrectness::No).0
+                })
+                .collect::<Vec<_>>();
+
+            let mut expected = generate_output(&full_parameters, compressed_output);
+            let inputs = chunks
+                .iter()
+                .map(|chunk| (&chunk[..], compressed_input))
+                .collect::<Vec<_>>();
+            Phase1::aggregation(&inputs, (&mut expected, compressed_output), &parameters).unwrap();
+
+            let mut output = Cursor::new(generate_output(&full_parameters, compressed_output));
+            let mut inputs = chunks
+                .iter()
+                .map(|chunk| (Cursor::new(chunk), compressed_input))
+                .collect::<Vec<_>>();
+            Phase1::aggregation_streaming(&mut inputs, (&mut output, compressed_output), &parameters).unwrap();
+
+            assert_eq!(output.get_ref(), &expected);
+        }
+    }
+}
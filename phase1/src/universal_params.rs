@@ -0,0 +1,135 @@
+//! Export of the universal parameters of the KZG10 and SonicKZG10 polynomial commitments,
+//! which Marlin uses, from a full Marlin accumulator.
+use super::*;
+
+use snarkvm_curves::PairingCurve;
+use snarkvm_polycommit::kzg10::UniversalParams;
+use snarkvm_utilities::CanonicalSerialize;
+
+use std::{collections::BTreeMap, io::Write};
+
+impl<'a, E: PairingEngine> Phase1<'a, E> {
+    /// Returns the universal parameters of a full Marlin accumulator.
+    ///
+    /// The accumulator only contains the powers of alpha and the negative powers of tau
+    /// in G2 for the degree bounds `2^i - 2`, which are the supported degree bounds.
+    pub fn universal_params(&self) -> Result<UniversalParams<E>> {
+        let parameters = self.parameters;
+        if parameters.proving_system != ProvingSystem::Marlin || parameters.contribution_mode != ContributionMode::Full
+        {
+            return Err(Error::UnsupportedUniversalParams);
+        }
+        let powers_length = parameters.powers_length;
+
+        // alpha * tau^i for i < 3, followed by the triples of powers of alpha for each degree bound.
+        let mut powers_of_gamma_g = BTreeMap::new();
+        for (i, element) in self.alpha_tau_powers_g1[..3].iter().enumerate() {
+            powers_of_gamma_g.insert(i, *element);
+        }
+        for (i, triple) in self.alpha_tau_powers_g1[3..].chunks(3).enumerate() {
+            for (j, element) in triple.iter().enumerate() {
+                powers_of_gamma_g.insert(powers_length - 1 - (1 << i) + 2 + j, *element);
+            }
+        }
+
+        // h and beta * h, followed by the negative powers of tau in G2 for each degree bound.
+        let h = self.tau_powers_g2[0];
+        let beta_h = self.tau_powers_g2[1];
+        let mut supported_degree_bounds = vec![];
+        let mut inverse_powers_of_g = BTreeMap::new();
+        let mut inverse_neg_powers_of_h = BTreeMap::new();
+        for (i, element) in self.tau_powers_g2[2..].iter().enumerate().skip(1) {
+            let degree_bound = (1 << i) - 2;
+            supported_degree_bounds.push(degree_bound);
+            inverse_neg_powers_of_h.insert(degree_bound, *element);
+            inverse_powers_of_g.insert(degree_bound, self.tau_powers_g1[powers_length - 1 - (1 << i) + 2]);
+        }
+
+        Ok(UniversalParams {
+            powers_of_g: self.tau_powers_g1.clone(),
+            powers_of_gamma_g,
+            h,
+            beta_h,
+            supported_degree_bounds,
+            inverse_powers_of_g,
+            inverse_neg_powers_of_h,
+            prepared_h: h.prepare(),
+            prepared_beta_h: beta_h.prepare(),
+        })
+    }
+
+    /// Writes the universal parameters of a full Marlin accumulator, serialized like snarkVM does.
+    pub fn write_universal_params<W: Write>(&self, output: &mut W) -> Result<()> {
+        self.universal_params()?.serialize(output)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::testing::generate_input;
+
+    use snarkvm_curves::bls12_377::Bls12_377;
+    use snarkvm_utilities::CanonicalDeserialize;
+
+    #[test]
+    fn test_universal_params() {
+        let parameters = Phase1Parameters::<Bls12_377>::new_full(ProvingSystem::Marlin, 4, 16);
+        let (input, _) = generate_input(&parameters, UseCompression::No, CheckForCorrectness::No);
+        let mut output = vec![0; parameters.get_length(UseCompression::No)];
+
+        let mut rng = derive_rng_from_seed(b"test_universal_params");
+        let (_, private_key) = Phase1::key_generation(&mut rng, blank_hash().as_ref()).unwrap();
+        Phase1::computation(
+            &input,
+            &mut output,
+            UseCompression::No,
+            UseCompression::No,
+            CheckForCorrectness::No,
+            &private_key,
+            &parameters,
+        )
+        .unwrap();
+        let accumulator =
+            Phase1::deserialize(&output, UseCompression::No, CheckForCorrectness::No, &parameters).unwrap();
+
+        let universal_params = accumulator.universal_params().unwrap();
+        assert_eq!(universal_params.powers_of_g.len(), parameters.powers_length);
+        assert_eq!(universal_params.supported_degree_bounds, vec![0, 2, 6]);
+
+        // beta_h is h to the power of tau, and the negative powers of tau in G2 undo
+        // the shift of the powers of tau in G1 for every degree bound.
+        let g = universal_params.powers_of_g[0];
+        assert_eq!(
+            Bls12_377::pairing(universal_params.powers_of_g[1], universal_params.h),
+            Bls12_377::pairing(g, universal_params.beta_h)
+        );
+        for degree_bound in &universal_params.supported_degree_bounds {
+            assert_eq!(
+                Bls12_377::pairing(
+                    universal_params.inverse_powers_of_g[degree_bound],
+                    universal_params.inverse_neg_powers_of_h[degree_bound]
+                ),
+                Bls12_377::pairing(g, universal_params.h)
+            );
+        }
+
+        let mut serialized = vec![];
+        accumulator.write_universal_params(&mut serialized).unwrap();
+        let deserialized = UniversalParams::<Bls12_377>::deserialize(&mut &serialized[..]).unwrap();
+        assert_eq!(deserialized.powers_of_g, universal_params.powers_of_g);
+        assert_eq!(
+            deserialized.inverse_neg_powers_of_h,
+            universal_params.inverse_neg_powers_of_h
+        );
+
+        // Groth16 accumulators do not have universal parameters.
+        let parameters = Phase1Parameters::<Bls12_377>::new_full(ProvingSystem::Groth16, 4, 16);
+        let (_, accumulator) = generate_input(&parameters, UseCompression::No, CheckForCorrectness::No);
+        assert!(matches!(
+            accumulator.universal_params(),
+            Err(Error::UnsupportedUniversalParams)
+        ));
+    }
+}
@@ -20,7 +20,7 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
     /// that they're in the prime order subgroup. In the first chunk, it also checks
     /// the proofs of knowledge and that the elements were correctly multiplied.
     ///
-    #[allow(clippy::too_many_arguments, clippy::cognitive_complexity)]
+    #[allow(clippy::too_many_arguments)]
     pub fn verification(
         input: &[u8],
         output: &[u8],
@@ -31,6 +31,38 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
         check_input_for_correctness: CheckForCorrectness,
         check_output_for_correctness: CheckForCorrectness,
         parameters: &'a Phase1Parameters<E>,
+    ) -> Result<()> {
+        Self::verification_with_progress(
+            input,
+            output,
+            key,
+            digest,
+            compressed_input,
+            compressed_output,
+            check_input_for_correctness,
+            check_output_for_correctness,
+            parameters,
+            &NoProgress,
+        )
+    }
+
+    ///
+    /// Phase 1 - Verification, which reports the verified batches to `progress`.
+    ///
+    /// Returns `Error::Cancelled` if `progress` cancels the verification.
+    ///
+    #[allow(clippy::too_many_arguments, clippy::cognitive_complexity)]
+    pub fn verification_with_progress(
+        input: &[u8],
+        output: &[u8],
+        key: &PublicKey<E>,
+        digest: &[u8],
+        compressed_input: UseCompression,
+        compressed_output: UseCompression,
+        check_input_for_correctness: CheckForCorrectness,
+        check_output_for_correctness: CheckForCorrectness,
+        parameters: &'a Phase1Parameters<E>,
+        progress: &dyn Progress,
     ) -> Result<()> {
         let span = info_span!("phase1-verification");
         let _ = span.enter();
@@ -48,6 +80,9 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
 
             let [tau_g2_s, alpha_g2_s, beta_g2_s] = compute_g2_s_key(&key, &digest)?;
 
+            // Failed ratio checks are located in the chunk which is verified.
+            let location = RatioLocation::default().with_chunk_index(parameters.chunk_index);
+
             // Compose into tuple form for convenience.
             let tau_single_g1_check = &(key.tau_g1.0, key.tau_g1.1);
             let tau_single_g2_check = &(tau_g2_s, key.tau_g2);
@@ -74,7 +109,7 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
                 ];
 
                 for (a, b, err) in check_ratios {
-                    check_same_ratio::<E>(a, b, err)?;
+                    check_same_ratio_at::<E>(a, b, err, location.clone())?;
                 }
                 debug!("key ratios were correctly produced");
             }
@@ -98,10 +133,11 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
                 }
 
                 // Check that tau^1 was multiplied correctly.
-                check_same_ratio::<E>(
+                check_same_ratio_at::<E>(
                     &(before_g1[1], after_g1[1]),
                     tau_single_g2_check,
                     "Before-After: tau_g1",
+                    location.clone().with_element_type(ElementType::TauG1).with_power(1),
                 )?;
 
                 (before_g1, after_g1)
@@ -122,10 +158,11 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
                 }
 
                 // Check that tau^1 was multiplied correctly.
-                check_same_ratio::<E>(
+                check_same_ratio_at::<E>(
                     tau_single_g1_check,
                     &(before_g2[1], after_g2[1]),
                     "Before-After: tau_g2",
+                    location.clone().with_element_type(ElementType::TauG2).with_power(1),
                 )?;
             }
 
@@ -134,20 +171,22 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
                 // Determine the check based on the proof system's requirements.
                 let checks = match parameters.proving_system {
                     ProvingSystem::Groth16 => vec![
-                        (in_alpha_g1, alpha_g1, alpha_single_g2_check),
-                        (in_beta_g1, beta_g1, beta_single_g2_check),
+                        (in_alpha_g1, alpha_g1, alpha_single_g2_check, ElementType::AlphaG1),
+                        (in_beta_g1, beta_g1, beta_single_g2_check, ElementType::BetaG1),
                     ],
-                    ProvingSystem::Marlin => vec![(in_alpha_g1, alpha_g1, alpha_single_g2_check)],
+                    ProvingSystem::Marlin => vec![(in_alpha_g1, alpha_g1, alpha_single_g2_check, ElementType::AlphaG1)],
+                    ProvingSystem::Kzg => vec![],
                 };
 
                 // Check that alpha_g1[0] and beta_g1[0] was multiplied correctly.
-                for (before, after, check) in &checks {
+                for (before, after, check, element_type) in &checks {
                     before.read_batch_preallocated(&mut before_g1, compressed_input, check_input_for_correctness)?;
                     after.read_batch_preallocated(&mut after_g1, compressed_output, check_output_for_correctness)?;
-                    check_same_ratio::<E>(
+                    check_same_ratio_at::<E>(
                         &(before_g1[0], after_g1[0]),
                         check,
                         "Before-After: alpha_g1[0] / beta_g1[0]",
+                        location.clone().with_element_type(*element_type).with_power(0),
                     )?;
                 }
             }
@@ -162,10 +201,11 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
                         (&*beta_g2).read_element::<E::G2Affine>(compressed_output, check_output_for_correctness)?;
 
                     // Check that beta_g2[0] was multiplied correctly.
-                    check_same_ratio::<E>(
+                    check_same_ratio_at::<E>(
                         beta_single_g1_check,
                         &(before_beta_g2, after_beta_g2),
                         "Before-After: beta_g2[0]",
+                        location.with_element_type(ElementType::BetaG2).with_power(0),
                     )?;
                 }
             }
@@ -173,7 +213,7 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
 
         debug!("initial elements were computed correctly");
 
-        iter_chunk(&parameters, |start, end| {
+        iter_chunk(&parameters, progress, |start, end| {
             // Preallocate 2 vectors per batch.
             // Ensure that the pairs are created correctly (we do this in chunks!).
             // Load `batch_size` chunks on each iteration and perform the transformation.
@@ -194,6 +234,12 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
 
             match parameters.proving_system {
                 ProvingSystem::Groth16 => {
+                    // The checks run in parallel, and their results are propagated once the scope ends.
+                    let mut tau_g1_result = Ok(());
+                    let mut tau_g2_result = Ok(());
+                    let mut alpha_g1_result = Ok(());
+                    let mut beta_g1_result = Ok(());
+
                     rayon::scope(|t| {
                         let _enter = span.enter();
 
@@ -203,14 +249,11 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
 
                             let mut g1 = vec![E::G1Affine::zero(); parameters.batch_size];
 
-                            check_elements_are_nonzero_and_in_prime_order_subgroup::<E::G1Affine>(
+                            tau_g1_result = check_elements_are_nonzero_and_in_prime_order_subgroup::<E::G1Affine>(
                                 (tau_g1, compressed_output),
                                 (start_chunk, end_chunk),
                                 &mut g1,
-                            )
-                            .expect("could not check element are non zero and in prime order subgroup");
-
-                            trace!("tau_g1 verification was successful");
+                            );
                         });
 
                         if start < parameters.powers_length {
@@ -244,14 +287,11 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
 
                                     let mut g2 = vec![E::G2Affine::zero(); parameters.batch_size];
 
-                                    check_elements_are_nonzero_and_in_prime_order_subgroup::<E::G2Affine>(
+                                    tau_g2_result = check_elements_are_nonzero_and_in_prime_order_subgroup(
                                         (tau_g2, compressed_output),
                                         (start_chunk, end_chunk),
                                         &mut g2,
-                                    )
-                                    .expect("could not check element are non zero and in prime order subgroup");
-
-                                    trace!("tau_g2 verification was successful");
+                                    );
                                 });
 
                                 // Process alpha_g1 elements.
@@ -260,14 +300,11 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
 
                                     let mut g1 = vec![E::G1Affine::zero(); parameters.batch_size];
 
-                                    check_elements_are_nonzero_and_in_prime_order_subgroup::<E::G1Affine>(
+                                    alpha_g1_result = check_elements_are_nonzero_and_in_prime_order_subgroup(
                                         (alpha_g1, compressed_output),
                                         (start_chunk, end_chunk),
                                         &mut g1,
-                                    )
-                                    .expect("could not check element are non zero and in prime order subgroup");
-
-                                    trace!("alpha_g1 verification was successful");
+                                    );
                                 });
 
                                 // Process beta_g1 elements.
@@ -276,20 +313,29 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
 
                                     let mut g1 = vec![E::G1Affine::zero(); parameters.batch_size];
 
-                                    check_elements_are_nonzero_and_in_prime_order_subgroup::<E::G1Affine>(
+                                    beta_g1_result = check_elements_are_nonzero_and_in_prime_order_subgroup(
                                         (beta_g1, compressed_output),
                                         (start_chunk, end_chunk),
                                         &mut g1,
-                                    )
-                                    .expect("could not check element are non zero and in prime order subgroup");
-
-                                    trace!("beta_g1 verification was successful");
+                                    );
                                 });
                             });
                         }
                     });
+
+                    tau_g1_result?;
+                    trace!("tau_g1 verification was successful");
+                    tau_g2_result?;
+                    alpha_g1_result?;
+                    beta_g1_result?;
+                    trace!("tau_g2, alpha_g1 and beta_g1 verification was successful");
                 }
-                ProvingSystem::Marlin => {
+                ProvingSystem::Marlin | ProvingSystem::Kzg => {
+                    // The checks run in parallel, and their results are propagated once the scope ends.
+                    let mut tau_g1_result = Ok(());
+                    let mut alpha_g1_result = Ok(());
+                    let mut tau_g2_result = Ok(());
+
                     rayon::scope(|t| {
                         let _ = span.enter();
 
@@ -299,52 +345,53 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
 
                             let mut g1 = vec![E::G1Affine::zero(); parameters.batch_size];
 
-                            check_elements_are_nonzero_and_in_prime_order_subgroup::<E::G1Affine>(
+                            tau_g1_result = check_elements_are_nonzero_and_in_prime_order_subgroup::<E::G1Affine>(
                                 (tau_g1, compressed_output),
                                 (start_chunk, end_chunk),
                                 &mut g1,
-                            )
-                            .expect("could not check ratios for tau_g1 elements");
-
-                            trace!("tau_g1 verification was successful");
+                            );
                         });
 
                         if start == 0 {
                             t.spawn(|_| {
                                 let _ = span.enter();
 
-                                let mut g1 = vec![E::G1Affine::zero(); parameters.batch_size];
+                                let (g2_chunk_size, alpha_chunk_size) = first_chunk_sizes(parameters);
 
-                                let num_alpha_powers = 3;
+                                if alpha_chunk_size > 0 {
+                                    let mut g1 = vec![E::G1Affine::zero(); parameters.batch_size];
 
-                                let start_chunk = 0;
-                                let end_chunk = num_alpha_powers + 3 * parameters.total_size_in_log2;
+                                    let start_chunk = 0;
+                                    let end_chunk = alpha_chunk_size;
 
-                                check_elements_are_nonzero_and_in_prime_order_subgroup::<E::G1Affine>(
-                                    (alpha_g1, compressed_output),
-                                    (start_chunk, end_chunk),
-                                    &mut g1,
-                                )
-                                .expect("could not check ratios for tau_g1 elements");
-
-                                trace!("alpha_g1 verification was successful");
+                                    alpha_g1_result = check_elements_are_nonzero_and_in_prime_order_subgroup(
+                                        (alpha_g1, compressed_output),
+                                        (start_chunk, end_chunk),
+                                        &mut g1,
+                                    );
+                                }
 
                                 let start_chunk = 0;
-                                let end_chunk = parameters.total_size_in_log2 + 2;
+                                let end_chunk = g2_chunk_size;
 
                                 let mut g2 = vec![E::G2Affine::zero(); parameters.batch_size];
 
-                                check_elements_are_nonzero_and_in_prime_order_subgroup::<E::G2Affine>(
+                                tau_g2_result = check_elements_are_nonzero_and_in_prime_order_subgroup::<E::G2Affine>(
                                     (tau_g2, compressed_output),
                                     (start_chunk, end_chunk),
                                     &mut g2,
-                                )
-                                .expect("could not check element are non zero and in prime order subgroup");
-
-                                trace!("tau_g2 verification was successful");
+                                );
                             });
                         }
                     });
+
+                    tau_g1_result?;
+                    trace!("tau_g1 verification was successful");
+                    alpha_g1_result?;
+                    tau_g2_result?;
+                    if start == 0 {
+                        trace!("alpha_g1 and tau_g2 verification was successful");
+                    }
                 }
             }
 
@@ -361,9 +408,27 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
     /// Verifies that the accumulator was transformed correctly
     /// given the `PublicKey` and the so-far hash of the accumulator.
     /// This verifies the ratios in a given accumulator.
+    ///
+    /// With `VerificationStrategy::Batched`, the power pairs of all batches are
+    /// combined with random scalars and checked with a single multi-pairing.
+    /// A failure of that check can not be located, verify the accumulator again
+    /// with `VerificationStrategy::PerBatch` to find the power where it broke.
     pub fn aggregate_verification(
+        output: (&[u8], UseCompression, CheckForCorrectness),
+        strategy: VerificationStrategy,
+        parameters: &Phase1Parameters<E>,
+    ) -> Result<()> {
+        Self::aggregate_verification_with_progress(output, strategy, parameters, &NoProgress)
+    }
+
+    /// Verifies the ratios in a given accumulator, and reports the verified batches to `progress`.
+    ///
+    /// Returns `Error::Cancelled` if `progress` cancels the verification.
+    pub fn aggregate_verification_with_progress(
         (output, compressed_output, check_output_for_correctness): (&[u8], UseCompression, CheckForCorrectness),
+        strategy: VerificationStrategy,
         parameters: &Phase1Parameters<E>,
+        progress: &dyn Progress,
     ) -> Result<()> {
         let span = info_span!("phase1-aggregate-verification");
         let _enter = span.enter();
@@ -372,7 +437,7 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
 
         let (tau_g1, tau_g2, alpha_g1, beta_g1, _) = split(output, parameters, compressed_output);
 
-        let (g1_check, g2_check, g1_alpha_check) = {
+        let (g1_check, g2_check) = {
             // Ensure that the initial conditions are correctly formed (first 2 elements)
             // We allocate a G1 vector of length 2 and re-use it for our G1 elements.
             // We keep the values of the tau_g1 / tau_g2 elements for later use.
@@ -385,30 +450,36 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
             let after_g2 =
                 read_initial_elements::<E::G2Affine>(tau_g2, compressed_output, check_output_for_correctness)?;
 
-            // Fetch the iteration of alpha_g1[0].
-            let after_alpha_g1 =
-                read_initial_elements::<E::G1Affine>(alpha_g1, compressed_output, check_output_for_correctness)?;
-
             let g1_check = (after_g1[0], after_g1[1]);
             let g2_check = (after_g2[0], after_g2[1]);
-            let g1_alpha_check = (after_alpha_g1[0], after_alpha_g1[1]);
 
-            (g1_check, g2_check, g1_alpha_check)
+            (g1_check, g2_check)
         };
 
         debug!("initial elements were computed correctly");
 
+        let batched = match strategy {
+            VerificationStrategy::PerBatch => None,
+            VerificationStrategy::Batched => Some(BatchedRatios::<E>::new()),
+        };
+
         match parameters.proving_system {
             // preallocate 2 vectors per batch
             // Ensure that the pairs are created correctly (we do this in chunks!)
             // load `batch_size` chunks on each iteration and perform the transformation
             ProvingSystem::Groth16 => {
-                iter_chunk(&parameters, |start, end| {
+                iter_chunk(&parameters, progress, |start, end| {
                     debug!("verifying batch from {} to {}", start, end);
 
                     let span = info_span!("batch", start, end);
                     let _enter = span.enter();
 
+                    // The checks run in parallel, and their results are propagated once the scope ends.
+                    let mut tau_g1_result = Ok(());
+                    let mut tau_g2_result = Ok(());
+                    let mut alpha_g1_result = Ok(());
+                    let mut beta_g1_result = Ok(());
+
                     rayon::scope(|t| {
                         let _enter = span.enter();
 
@@ -417,15 +488,14 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
 
                             let mut g1 = vec![E::G1Affine::zero(); parameters.batch_size];
 
-                            check_power_ratios::<E>(
+                            tau_g1_result = check_power_ratios::<E>(
                                 (tau_g1, compressed_output, check_output_for_correctness),
                                 (start, end),
                                 &mut g1,
                                 &g2_check,
-                            )
-                            .expect("could not check ratios for tau_g1 elements");
-
-                            trace!("tau_g1 verification successful");
+                                (ElementType::TauG1, parameters.chunk_index),
+                                batched.as_ref(),
+                            );
                         });
 
                         if start < parameters.powers_length {
@@ -446,15 +516,14 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
 
                                     let mut g2 = vec![E::G2Affine::zero(); parameters.batch_size];
 
-                                    check_power_ratios_g2::<E>(
+                                    tau_g2_result = check_power_ratios_g2::<E>(
                                         (tau_g2, compressed_output, check_output_for_correctness),
                                         (start, end),
                                         &mut g2,
                                         &g1_check,
-                                    )
-                                    .expect("could not check ratios for tau_g2 elements");
-
-                                    trace!("tau_g2 verification successful");
+                                        (ElementType::TauG2, parameters.chunk_index),
+                                        batched.as_ref(),
+                                    );
                                 });
 
                                 t.spawn(|_| {
@@ -462,15 +531,14 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
 
                                     let mut g1 = vec![E::G1Affine::zero(); parameters.batch_size];
 
-                                    check_power_ratios::<E>(
+                                    alpha_g1_result = check_power_ratios::<E>(
                                         (alpha_g1, compressed_output, check_output_for_correctness),
                                         (start, end),
                                         &mut g1,
                                         &g2_check,
-                                    )
-                                    .expect("could not check ratios for alpha_g1 elements");
-
-                                    trace!("alpha_g1 verification successful");
+                                        (ElementType::AlphaG1, parameters.chunk_index),
+                                        batched.as_ref(),
+                                    );
                                 });
 
                                 t.spawn(|_| {
@@ -478,33 +546,47 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
 
                                     let mut g1 = vec![E::G1Affine::zero(); parameters.batch_size];
 
-                                    check_power_ratios::<E>(
+                                    beta_g1_result = check_power_ratios::<E>(
                                         (beta_g1, compressed_output, check_output_for_correctness),
                                         (start, end),
                                         &mut g1,
                                         &g2_check,
-                                    )
-                                    .expect("could not check ratios for beta_g1 elements");
-
-                                    trace!("beta_g1 verification successful");
+                                        (ElementType::BetaG1, parameters.chunk_index),
+                                        batched.as_ref(),
+                                    );
                                 });
                             });
                         }
                     });
 
+                    tau_g1_result?;
+                    trace!("tau_g1 verification successful");
+                    tau_g2_result?;
+                    alpha_g1_result?;
+                    beta_g1_result?;
+                    trace!("tau_g2, alpha_g1 and beta_g1 verification successful");
+
                     debug!("chunk verification successful");
 
                     Ok(())
                 })?;
             }
             ProvingSystem::Marlin => {
-                iter_chunk(&parameters, |start, end| {
+                // Fetch the iteration of alpha_g1[0].
+                let after_alpha_g1 =
+                    read_initial_elements::<E::G1Affine>(alpha_g1, compressed_output, check_output_for_correctness)?;
+                let g1_alpha_check = (after_alpha_g1[0], after_alpha_g1[1]);
+
+                iter_chunk(&parameters, progress, |start, end| {
                     debug!("verifying batch from {} to {}", start, end);
 
                     let span = info_span!("batch", start, end);
                     let _enter = span.enter();
 
-                    rayon::scope(|t| {
+                    // The tau_g1 check runs in parallel, and its result is propagated once the scope ends.
+                    let mut tau_g1_result = Ok(());
+
+                    rayon::scope(|t| -> Result<()> {
                         let _enter = span.enter();
 
                         t.spawn(|_| {
@@ -512,15 +594,14 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
 
                             let mut g1 = vec![E::G1Affine::zero(); parameters.batch_size];
 
-                            check_power_ratios::<E>(
+                            tau_g1_result = check_power_ratios::<E>(
                                 (tau_g1, compressed_output, check_output_for_correctness),
                                 (start, end),
                                 &mut g1,
                                 &g2_check,
-                            )
-                            .expect("could not check ratios for tau_g1 elements");
-
-                            trace!("tau_g1 verification successful");
+                                (ElementType::TauG1, parameters.chunk_index),
+                                batched.as_ref(),
+                            );
                         });
 
                         {
@@ -535,47 +616,52 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
                                 let g2_size = buffer_size::<E::G2Affine>(compressed_output);
 
                                 let g1 = (&tau_g1[p * g1_size..(p + 1) * g1_size])
-                                    .read_element(compressed_output, check_output_for_correctness)
-                                    .expect("should have read g1 element");
+                                    .read_element(compressed_output, check_output_for_correctness)?;
                                 let g2 = (&tau_g2[(2 + i) * g2_size..(2 + i + 1) * g2_size])
-                                    .read_element(compressed_output, check_output_for_correctness)
-                                    .expect("should have read g2 element");
-                                check_same_ratio::<E>(
+                                    .read_element(compressed_output, check_output_for_correctness)?;
+                                let location = RatioLocation::default()
+                                    .with_chunk_index(parameters.chunk_index)
+                                    .with_batch(start, end);
+                                check_same_ratio_at::<E>(
                                     &(g1, E::G1Affine::prime_subgroup_generator()),
                                     &(E::G2Affine::prime_subgroup_generator(), g2),
                                     "G1<>G2",
-                                )
-                                .expect("should have checked same ratio");
+                                    location.clone().with_element_type(ElementType::TauG1).with_power(p),
+                                )?;
 
                                 let mut alpha_g1_elements = vec![E::G1Affine::zero(); 3];
-                                (&alpha_g1[(3 + 3 * i) * g1_size..(3 + 3 * i + 3) * g1_size])
-                                    .read_batch_preallocated(
-                                        &mut alpha_g1_elements,
-                                        compressed_output,
-                                        check_output_for_correctness,
-                                    )
-                                    .expect("should have read alpha g1 elements");
-                                check_same_ratio::<E>(
+                                (&alpha_g1[(3 + 3 * i) * g1_size..(3 + 3 * i + 3) * g1_size]).read_batch_preallocated(
+                                    &mut alpha_g1_elements,
+                                    compressed_output,
+                                    check_output_for_correctness,
+                                )?;
+                                let location = location.with_element_type(ElementType::AlphaG1);
+                                check_same_ratio_at::<E>(
                                     &(alpha_g1_elements[0], alpha_g1_elements[1]),
                                     &g2_check,
                                     "alpha_g1 ratio 1",
-                                )
-                                .expect("should have checked same ratio");
-                                check_same_ratio::<E>(
+                                    location.clone().with_power(3 + 3 * i + 1),
+                                )?;
+                                check_same_ratio_at::<E>(
                                     &(alpha_g1_elements[1], alpha_g1_elements[2]),
                                     &g2_check,
                                     "alpha_g1 ratio 2",
-                                )
-                                .expect("should have checked same ratio");
-                                check_same_ratio::<E>(
+                                    location.clone().with_power(3 + 3 * i + 2),
+                                )?;
+                                check_same_ratio_at::<E>(
                                     &(alpha_g1_elements[0], g1_alpha_check.0),
                                     &(E::G2Affine::prime_subgroup_generator(), g2),
                                     "alpha consistent",
-                                )
-                                .expect("should have checked same ratio");
+                                    location.with_power(3 + 3 * i),
+                                )?;
                             }
                         }
-                    });
+
+                        Ok(())
+                    })?;
+
+                    tau_g1_result?;
+                    trace!("tau_g1 verification successful");
 
                     // This is the first batch, check alpha_g1. batch size is guaranteed to be of size >= 3
                     if start == 0 {
@@ -587,8 +673,9 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
                             (0, num_alpha_powers),
                             &mut g1,
                             &g2_check,
-                        )
-                        .expect("could not check ratios for alpha_g1");
+                            (ElementType::AlphaG1, parameters.chunk_index),
+                            batched.as_ref(),
+                        )?;
 
                         trace!("alpha_g1 verification was successful");
 
@@ -599,19 +686,50 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
                             (0, 2),
                             &mut g2,
                             &g1_check,
-                        )
-                        .expect("could not check ratios for tau_g2");
+                            (ElementType::TauG2, parameters.chunk_index),
+                            batched.as_ref(),
+                        )?;
 
                         trace!("tau_g2 verification was successful");
                     }
 
                     debug!("chunk verification successful");
 
+                    Ok(())
+                })?;
+            }
+            ProvingSystem::Kzg => {
+                iter_chunk(&parameters, progress, |start, end| {
+                    debug!("verifying batch from {} to {}", start, end);
+
+                    let span = info_span!("batch", start, end);
+                    let _enter = span.enter();
+
+                    let mut g1 = vec![E::G1Affine::zero(); parameters.batch_size];
+
+                    check_power_ratios::<E>(
+                        (tau_g1, compressed_output, check_output_for_correctness),
+                        (start, end),
+                        &mut g1,
+                        &g2_check,
+                        (ElementType::TauG1, parameters.chunk_index),
+                        batched.as_ref(),
+                    )?;
+
+                    trace!("tau_g1 verification successful");
+
+                    debug!("chunk verification successful");
+
                     Ok(())
                 })?;
             }
         }
 
+        if let Some(batched) = batched {
+            batched.check(&g2_check, &g1_check)?;
+            debug!("batched power pairs were verified");
+        }
+
         info!("aggregate verification complete");
         Ok(())
     }
@@ -620,8 +738,8 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::helpers::testing::{generate_input, generate_output};
-    use setup_utils::calculate_hash;
+    use crate::helpers::testing::{generate_input, generate_output, random_point, setup_verify};
+    use setup_utils::{calculate_hash, curves::Bls12_381};
 
     use snarkvm_curves::{bls12_377::Bls12_377, bw6_761::BW6_761};
 
@@ -631,7 +749,7 @@ mod tests {
         compressed_input: UseCompression,
         compressed_output: UseCompression,
     ) {
-        for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin] {
+        for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin, ProvingSystem::Kzg] {
             let parameters = Phase1Parameters::<E>::new_full(*proving_system, total_size_in_log2, batch);
 
             // allocate the input/output vectors
@@ -705,10 +823,14 @@ mod tests {
             );
             assert!(res.is_ok());
 
-            // verification will fail if the old hash is used
-            let res =
-                Phase1::aggregate_verification((&output_2, compressed_output, CheckForCorrectness::Full), &parameters);
-            assert!(res.is_ok());
+            for strategy in &[VerificationStrategy::PerBatch, VerificationStrategy::Batched] {
+                let res = Phase1::aggregate_verification(
+                    (&output_2, compressed_output, CheckForCorrectness::Full),
+                    *strategy,
+                    &parameters,
+                );
+                assert!(res.is_ok());
+            }
 
             // verification will fail if the old hash is used
             let res = Phase1::verification(
@@ -751,12 +873,12 @@ mod tests {
     ) {
         let correctness = CheckForCorrectness::Full;
 
-        for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin] {
+        for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin, ProvingSystem::Kzg] {
             let powers_length = 1 << total_size_in_log2;
             let powers_g1_length = (powers_length << 1) - 1;
             let powers_length_for_proving_system = match *proving_system {
                 ProvingSystem::Groth16 => powers_g1_length,
-                ProvingSystem::Marlin => powers_length,
+                ProvingSystem::Marlin | ProvingSystem::Kzg => powers_length,
             };
             let num_chunks = (powers_length_for_proving_system + batch - 1) / batch;
 
@@ -916,6 +1038,14 @@ mod tests {
         full_verification_test::<Bls12_377>(4, 3 + 3 * 4, UseCompression::No, UseCompression::Yes);
     }
 
+    #[test]
+    fn test_verification_bls12_381() {
+        full_verification_test::<Bls12_381>(4, 3 + 3 * 4, UseCompression::Yes, UseCompression::Yes);
+        full_verification_test::<Bls12_381>(4, 3 + 3 * 4, UseCompression::No, UseCompression::No);
+        full_verification_test::<Bls12_381>(4, 3 + 3 * 4, UseCompression::Yes, UseCompression::No);
+        full_verification_test::<Bls12_381>(4, 3 + 3 * 4, UseCompression::No, UseCompression::Yes);
+    }
+
     #[test]
     fn test_verification_bw6_761() {
         full_verification_test::<BW6_761>(4, 3 + 3 * 4, UseCompression::Yes, UseCompression::Yes);
@@ -924,6 +1054,66 @@ mod tests {
         full_verification_test::<BW6_761>(4, 3 + 3 * 4, UseCompression::No, UseCompression::Yes);
     }
 
+    #[test]
+    fn test_batched_aggregate_verification_rejects_invalid_ratios() {
+        let parameters = Phase1Parameters::<Bls12_377>::new_full(ProvingSystem::Groth16, 4, 4);
+        let (_, mut output, _, _) = setup_verify(
+            UseCompression::No,
+            CheckForCorrectness::Full,
+            UseCompression::No,
+            &parameters,
+        );
+        let accumulator = (&output[..], UseCompression::No, CheckForCorrectness::Full);
+        assert!(Phase1::aggregate_verification(accumulator, VerificationStrategy::Batched, &parameters).is_ok());
+
+        // Replace one of the powers of tau with a random element.
+        let size = buffer_size::<<Bls12_377 as PairingEngine>::G1Affine>(UseCompression::No);
+        let position = parameters.hash_size + 5 * size;
+        let element: <Bls12_377 as PairingEngine>::G1Affine = random_point(&mut rand::thread_rng());
+        (&mut output[position..position + size])
+            .write_element(&element, UseCompression::No)
+            .unwrap();
+
+        let accumulator = (&output[..], UseCompression::No, CheckForCorrectness::Full);
+        assert!(Phase1::aggregate_verification(accumulator, VerificationStrategy::Batched, &parameters).is_err());
+    }
+
+    #[test]
+    fn test_aggregate_verification_locates_invalid_ratios() {
+        let parameters = Phase1Parameters::<Bls12_377>::new_full(ProvingSystem::Groth16, 4, 4);
+        let (_, mut output, _, _) = setup_verify(
+            UseCompression::No,
+            CheckForCorrectness::Full,
+            UseCompression::No,
+            &parameters,
+        );
+
+        // Replace the 5th power of tau in G1 with a random element.
+        let size = buffer_size::<<Bls12_377 as PairingEngine>::G1Affine>(UseCompression::No);
+        let position = parameters.hash_size + 5 * size;
+        let element: <Bls12_377 as PairingEngine>::G1Affine = random_point(&mut rand::thread_rng());
+        (&mut output[position..position + size])
+            .write_element(&element, UseCompression::No)
+            .unwrap();
+
+        let location = |strategy| {
+            let accumulator = (&output[..], UseCompression::No, CheckForCorrectness::Full);
+            match Phase1::aggregate_verification(accumulator, strategy, &parameters) {
+                Err(Error::VerificationError(error)) => error.location().cloned(),
+                result => panic!("expected a verification error, got {:?}", result),
+            }
+        };
+
+        // Checking each batch locates the first power without the expected ratio.
+        let per_batch = location(VerificationStrategy::PerBatch).unwrap();
+        assert_eq!(Some(ElementType::TauG1), per_batch.element_type);
+        assert_eq!(Some(5), per_batch.power);
+
+        // The batched check combines all powers, so its failure can not be located.
+        let batched = location(VerificationStrategy::Batched).unwrap();
+        assert_eq!(RatioLocation::default(), batched);
+    }
+
     #[test]
     fn test_chunk_verification_bls12_377() {
         chunk_verification_test::<Bls12_377>(4, 3 + 3 * 4, UseCompression::Yes, UseCompression::Yes);
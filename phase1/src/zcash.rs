@@ -0,0 +1,432 @@
+//! Conversion between the challenge files of this crate and the challenge files of the original Zcash
+//! [powersoftau](https://github.com/ebfull/powersoftau) ceremony.
+//!
+//! Both formats start with the 64-byte BLAKE2b hash of the previous file, followed by the uncompressed
+//! τ/α/β powers in the same order. They only differ in the encoding of the group elements: powersoftau stores
+//! coordinates in big-endian order, with the flags in the top bits of the first byte, and the c1 component of
+//! an extension field element before the c0 component.
+//!
+//! Converting a file changes its bytes, and so the hashes which the next file in the chain commits to.
+//!
+//! Responses are not converted. The proofs of knowledge in the public key of a response hash the challenge hash
+//! and the G1 points in the encoding of the tool which produced it, and each tool maps that hash to G2 differently,
+//! so a converted public key would not verify. Re-signing it needs the secrets of the contributor. A response
+//! must be verified with the tool which produced it, and a ceremony can only continue from a converted challenge.
+//!
+//! Only BLS12-381 files can be converted. The perpetual powers of tau ceremony uses the same format over BN254,
+//! which `snarkvm-curves` does not implement, so its files are rejected with
+//! [`Error::UnsupportedZcashParameters`](setup_utils::Error::UnsupportedZcashParameters).
+use super::*;
+
+use snarkvm_algorithms::{cfg_chunks, cfg_chunks_mut};
+use snarkvm_fields::{Field, PrimeField, Zero};
+use snarkvm_utilities::{CanonicalDeserialize, CanonicalSerialize, ConstantSerializedSize};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::marker::PhantomData;
+
+/// Set on compressed elements.
+const COMPRESSION_FLAG: u8 = 1 << 7;
+/// Set on the point at infinity.
+const INFINITY_FLAG: u8 = 1 << 6;
+/// Set on compressed elements whose y-coordinate is the lexicographically largest of the two roots.
+const SORT_FLAG: u8 = 1 << 5;
+
+/// The base field modulus of BLS12-381, which is the curve used by the powersoftau ceremony.
+const BLS12_381_MODULUS: &[u64] = &[
+    0xb9feffffffffaaab,
+    0x1eabfffeb153ffff,
+    0x6730d2a0f6b0f624,
+    0x64774b84f38512bf,
+    0x4b1ba7b6434bacd7,
+    0x1a0111ea397fe69a,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    FromZcash,
+    ToZcash,
+}
+
+impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
+    /// Converts a powersoftau challenge file to the format of this crate.
+    ///
+    /// Both files are `parameters.accumulator_size` bytes long.
+    pub fn from_zcash(
+        input: &[u8],
+        output: &mut [u8],
+        check_input_for_correctness: CheckForCorrectness,
+        parameters: &Phase1Parameters<E>,
+    ) -> Result<()> {
+        convert(
+            input,
+            output,
+            Direction::FromZcash,
+            check_input_for_correctness,
+            parameters,
+        )
+    }
+
+    /// Converts a challenge file of this crate to the powersoftau format.
+    ///
+    /// Both files are `parameters.accumulator_size` bytes long.
+    pub fn to_zcash(
+        input: &[u8],
+        output: &mut [u8],
+        check_input_for_correctness: CheckForCorrectness,
+        parameters: &Phase1Parameters<E>,
+    ) -> Result<()> {
+        convert(
+            input,
+            output,
+            Direction::ToZcash,
+            check_input_for_correctness,
+            parameters,
+        )
+    }
+}
+
+fn convert<E: PairingEngine + Sync>(
+    input: &[u8],
+    output: &mut [u8],
+    direction: Direction,
+    check_input_for_correctness: CheckForCorrectness,
+    parameters: &Phase1Parameters<E>,
+) -> Result<()> {
+    match (parameters.proving_system, parameters.contribution_mode) {
+        (ProvingSystem::Groth16, ContributionMode::Full) => {}
+        _ => return Err(Error::UnsupportedZcashParameters),
+    }
+    let encoding = ZcashEncoding::<E>::new(direction, check_input_for_correctness)?;
+
+    if input.len() != parameters.accumulator_size {
+        return Err(Error::InvalidLength {
+            expected: parameters.accumulator_size,
+            got: input.len(),
+        });
+    }
+    if output.len() != parameters.accumulator_size {
+        return Err(Error::InvalidLength {
+            expected: parameters.accumulator_size,
+            got: output.len(),
+        });
+    }
+
+    // The hash of the previous file is kept as it is.
+    output[..parameters.hash_size].copy_from_slice(&input[..parameters.hash_size]);
+    let mut position = (parameters.hash_size, parameters.hash_size);
+
+    // Challenges are uncompressed in both formats.
+    let accumulator = (UseCompression::No, UseCompression::No);
+    let (g1_length, length) = (parameters.powers_g1_length, parameters.powers_length);
+    encoding.convert::<E::G1Affine>(input, output, &mut position, g1_length, accumulator)?;
+    encoding.convert::<E::G2Affine>(input, output, &mut position, length, accumulator)?;
+    encoding.convert::<E::G1Affine>(input, output, &mut position, 2 * length, accumulator)?;
+    encoding.convert::<E::G2Affine>(input, output, &mut position, 1, accumulator)?;
+
+    Ok(())
+}
+
+/// Converts group elements between the encoding of snarkVM and the encoding of powersoftau.
+struct ZcashEncoding<E: PairingEngine> {
+    /// The size in bytes of a base field element
+    n8: usize,
+    direction: Direction,
+    check_input_for_correctness: CheckForCorrectness,
+    _engine: PhantomData<E>,
+}
+
+impl<E: PairingEngine> ZcashEncoding<E> {
+    fn new(direction: Direction, check_input_for_correctness: CheckForCorrectness) -> Result<Self> {
+        if E::Fq::characteristic() != BLS12_381_MODULUS {
+            return Err(Error::UnsupportedZcashParameters);
+        }
+
+        Ok(Self {
+            n8: E::Fq::zero().serialized_size(),
+            direction,
+            check_input_for_correctness,
+            _engine: PhantomData,
+        })
+    }
+
+    /// Converts `count` elements at the given input and output positions, and advances the positions.
+    fn convert<G: AffineCurve>(
+        &self,
+        input: &[u8],
+        output: &mut [u8],
+        position: &mut (usize, usize),
+        count: usize,
+        (native, zcash): (UseCompression, UseCompression),
+    ) -> Result<()> {
+        let native_size = buffer_size::<G>(native);
+        let zcash_size = match zcash {
+            UseCompression::Yes => G::UNCOMPRESSED_SIZE / 2,
+            UseCompression::No => G::UNCOMPRESSED_SIZE,
+        };
+        let (input_size, output_size) = match self.direction {
+            Direction::FromZcash => (zcash_size, native_size),
+            Direction::ToZcash => (native_size, zcash_size),
+        };
+
+        let input = &input[position.0..position.0 + count * input_size];
+        let output = &mut output[position.1..position.1 + count * output_size];
+        cfg_chunks_mut!(output, output_size)
+            .zip(cfg_chunks!(input, input_size))
+            .map(|(output, mut input)| match self.direction {
+                Direction::FromZcash => {
+                    let element: G = self.read(input, zcash)?;
+                    output.write_element(&element, native)
+                }
+                Direction::ToZcash => {
+                    let element: G = input.read_element(native, self.check_input_for_correctness)?;
+                    self.write(&element, zcash, output)
+                }
+            })
+            .collect::<Result<()>>()?;
+
+        *position = (position.0 + count * input_size, position.1 + count * output_size);
+        Ok(())
+    }
+
+    /// Returns the number of base field elements in a coordinate of `G`.
+    fn degree<G: AffineCurve>(&self) -> usize {
+        G::UNCOMPRESSED_SIZE / (2 * self.n8)
+    }
+
+    /// Returns the canonical little-endian encoding of the coordinates of a non-zero element.
+    fn coordinates<G: AffineCurve>(&self, element: &G) -> Result<Vec<u8>> {
+        let mut coordinates = vec![0u8; G::UNCOMPRESSED_SIZE];
+        element.serialize_uncompressed(&mut &mut coordinates[..])?;
+        // Clear the flags which snarkVM stores in the top bits of the last coordinate.
+        coordinates[G::UNCOMPRESSED_SIZE - 1] &= 0x3f;
+        Ok(coordinates)
+    }
+
+    /// Reverses the order of the components of a coordinate, and the order of the bytes of each component.
+    /// This converts a coordinate from little-endian to big-endian order, and back.
+    fn reverse(&self, coordinate: &[u8]) -> Vec<u8> {
+        coordinate.iter().rev().copied().collect()
+    }
+
+    /// Returns true if the y-coordinate is larger than its negation, comparing the c1 component first.
+    fn is_lexicographically_largest(&self, y: &[u8]) -> Result<bool> {
+        for component in y.chunks(self.n8).rev() {
+            let component = E::Fq::deserialize(&mut &component[..])?;
+            let (component, negation) = (component.into_repr(), (-component).into_repr());
+            if component != negation {
+                return Ok(component > negation);
+            }
+        }
+        Ok(false)
+    }
+
+    fn write<G: AffineCurve>(&self, element: &G, compression: UseCompression, output: &mut [u8]) -> Result<()> {
+        output.iter_mut().for_each(|byte| *byte = 0);
+        let flag = match compression {
+            UseCompression::Yes => COMPRESSION_FLAG,
+            UseCompression::No => 0,
+        };
+
+        if element.is_zero() {
+            output[0] = flag | INFINITY_FLAG;
+            return Ok(());
+        }
+
+        let coordinates = self.coordinates(element)?;
+        let (x, y) = coordinates.split_at(self.degree::<G>() * self.n8);
+        match compression {
+            UseCompression::Yes => {
+                output.copy_from_slice(&self.reverse(x));
+                if self.is_lexicographically_largest(y)? {
+                    output[0] |= SORT_FLAG;
+                }
+            }
+            UseCompression::No => {
+                output[..x.len()].copy_from_slice(&self.reverse(x));
+                output[x.len()..].copy_from_slice(&self.reverse(y));
+            }
+        }
+        output[0] |= flag;
+        Ok(())
+    }
+
+    fn read<G: AffineCurve>(&self, input: &[u8], compression: UseCompression) -> Result<G> {
+        let flags = input[0] & (COMPRESSION_FLAG | INFINITY_FLAG | SORT_FLAG);
+        if (flags & COMPRESSION_FLAG != 0) != (compression == UseCompression::Yes) {
+            return Err(Error::InvalidZcashElement("unexpected compression flag"));
+        }
+
+        let mut input = input.to_vec();
+        input[0] &= !(COMPRESSION_FLAG | INFINITY_FLAG | SORT_FLAG);
+
+        let element = if flags & INFINITY_FLAG != 0 {
+            if flags & SORT_FLAG != 0 || input.iter().any(|byte| *byte != 0) {
+                return Err(Error::InvalidZcashElement("non-zero point at infinity"));
+            }
+            G::zero()
+        } else {
+            let size = self.degree::<G>() * self.n8;
+            match compression {
+                UseCompression::Yes => {
+                    // Decompress with snarkVM, and then pick the root which matches the sort flag.
+                    let mut x = self.reverse(&input[..size]);
+                    x.resize(G::SERIALIZED_SIZE, 0);
+                    let element = G::deserialize(&mut &x[..])?;
+                    let coordinates = self.coordinates(&element)?;
+                    match self.is_lexicographically_largest(&coordinates[size..])? == (flags & SORT_FLAG != 0) {
+                        true => element,
+                        false => -element,
+                    }
+                }
+                UseCompression::No => {
+                    if flags & SORT_FLAG != 0 {
+                        return Err(Error::InvalidZcashElement("unexpected sort flag"));
+                    }
+                    let mut coordinates = self.reverse(&input[..size]);
+                    coordinates.extend(self.reverse(&input[size..]));
+                    G::deserialize_uncompressed(&mut &coordinates[..])?
+                }
+            }
+        };
+
+        if (self.check_input_for_correctness == CheckForCorrectness::Full
+            || self.check_input_for_correctness == CheckForCorrectness::OnlyNonZero)
+            && element.is_zero()
+        {
+            return Err(Error::PointAtInfinity);
+        }
+
+        Ok(element)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::testing::generate_random_accumulator;
+    use setup_utils::curves::{
+        bls12_381::{G1Affine, G2Affine},
+        Bls12_381,
+    };
+
+    use snarkvm_curves::bls12_377::Bls12_377;
+
+    fn encoding() -> ZcashEncoding<Bls12_381> {
+        ZcashEncoding::new(Direction::ToZcash, CheckForCorrectness::Full).unwrap()
+    }
+
+    fn encode<G: AffineCurve>(element: &G, compression: UseCompression) -> String {
+        let mut output = vec![0u8; G::UNCOMPRESSED_SIZE];
+        let size = match compression {
+            UseCompression::Yes => G::UNCOMPRESSED_SIZE / 2,
+            UseCompression::No => G::UNCOMPRESSED_SIZE,
+        };
+        encoding().write(element, compression, &mut output[..size]).unwrap();
+        hex::encode(&output[..size])
+    }
+
+    fn decode<G: AffineCurve>(element: &str, compression: UseCompression) -> Result<G> {
+        encoding().read(&hex::decode(element).unwrap(), compression)
+    }
+
+    #[test]
+    fn test_zcash_generators() {
+        // The encodings of the generators, as published with the BLS12-381 specification.
+        let g1_compressed =
+            "97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb";
+        let g1_uncompressed = "17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1";
+        let g2_compressed = "93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8";
+
+        let g1 = G1Affine::prime_subgroup_generator();
+        let g2 = G2Affine::prime_subgroup_generator();
+        assert_eq!(encode(&g1, UseCompression::Yes), g1_compressed);
+        assert_eq!(encode(&g1, UseCompression::No), g1_uncompressed);
+        assert_eq!(encode(&g2, UseCompression::Yes), g2_compressed);
+
+        assert_eq!(decode::<G1Affine>(g1_compressed, UseCompression::Yes).unwrap(), g1);
+        assert_eq!(decode::<G1Affine>(g1_uncompressed, UseCompression::No).unwrap(), g1);
+        assert_eq!(decode::<G2Affine>(g2_compressed, UseCompression::Yes).unwrap(), g2);
+
+        // The negation of the generator only differs in the sort flag.
+        assert_eq!(&encode(&-g1, UseCompression::Yes)[2..], &g1_compressed[2..]);
+        assert_eq!(
+            decode::<G1Affine>(&encode(&-g1, UseCompression::Yes), UseCompression::Yes).unwrap(),
+            -g1
+        );
+        assert_eq!(
+            decode::<G2Affine>(&encode(&-g2, UseCompression::Yes), UseCompression::Yes).unwrap(),
+            -g2
+        );
+
+        // The compression flag must match the expected compression.
+        assert!(decode::<G1Affine>(g1_compressed, UseCompression::No).is_err());
+    }
+
+    #[test]
+    fn test_zcash_point_at_infinity() {
+        let zero = G1Affine::zero();
+        assert_eq!(encode(&zero, UseCompression::Yes), format!("c0{}", "00".repeat(47)));
+        assert_eq!(encode(&zero, UseCompression::No), format!("40{}", "00".repeat(95)));
+
+        // Points at infinity are rejected unless the correctness checks are disabled.
+        assert!(decode::<G1Affine>(&encode(&zero, UseCompression::Yes), UseCompression::Yes).is_err());
+        let encoding = ZcashEncoding::<Bls12_381>::new(Direction::FromZcash, CheckForCorrectness::No).unwrap();
+        let decoded: G1Affine = encoding
+            .read(
+                &hex::decode(encode(&zero, UseCompression::No)).unwrap(),
+                UseCompression::No,
+            )
+            .unwrap();
+        assert!(decoded.is_zero());
+    }
+
+    #[test]
+    fn test_zcash_challenge_round_trip() {
+        let parameters = Phase1Parameters::<Bls12_381>::new_full(ProvingSystem::Groth16, 2, 4);
+        let (challenge, _) = generate_random_accumulator(&parameters, UseCompression::No);
+
+        let mut zcash = vec![0u8; parameters.accumulator_size];
+        Phase1::to_zcash(&challenge, &mut zcash, CheckForCorrectness::Full, &parameters).unwrap();
+        assert_ne!(zcash, challenge);
+        assert_eq!(zcash[..parameters.hash_size], challenge[..parameters.hash_size]);
+
+        let mut converted = vec![0u8; parameters.accumulator_size];
+        Phase1::from_zcash(&zcash, &mut converted, CheckForCorrectness::Full, &parameters).unwrap();
+        assert_eq!(converted, challenge);
+    }
+
+    #[test]
+    fn test_zcash_rejects_responses() {
+        let parameters = Phase1Parameters::<Bls12_381>::new_full(ProvingSystem::Groth16, 2, 4);
+        let (mut response, _) = generate_random_accumulator(&parameters, UseCompression::Yes);
+        response.resize(parameters.contribution_size, 0);
+
+        let mut output = vec![0u8; parameters.accumulator_size];
+        assert!(matches!(
+            Phase1::to_zcash(&response, &mut output, CheckForCorrectness::Full, &parameters),
+            Err(Error::InvalidLength { .. })
+        ));
+        assert!(matches!(
+            Phase1::from_zcash(&response, &mut output, CheckForCorrectness::Full, &parameters),
+            Err(Error::InvalidLength { .. })
+        ));
+    }
+
+    #[test]
+    fn test_zcash_rejects_unsupported_parameters() {
+        let parameters = Phase1Parameters::<Bls12_377>::new_full(ProvingSystem::Groth16, 2, 4);
+        let (challenge, _) = generate_random_accumulator(&parameters, UseCompression::No);
+        let mut output = vec![0u8; challenge.len()];
+        assert!(matches!(
+            Phase1::to_zcash(&challenge, &mut output, CheckForCorrectness::Full, &parameters),
+            Err(Error::UnsupportedZcashParameters)
+        ));
+
+        let parameters = Phase1Parameters::<Bls12_381>::new_full(ProvingSystem::Groth16, 2, 4);
+        let (challenge, _) = generate_random_accumulator(&parameters, UseCompression::No);
+        let mut output = vec![0u8; challenge.len() - 1];
+        assert!(Phase1::to_zcash(&challenge, &mut output, CheckForCorrectness::Full, &parameters).is_err());
+    }
+}
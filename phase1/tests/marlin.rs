@@ -7,21 +7,18 @@ mod test {
     use snarkvm_algorithms::SNARK;
     use snarkvm_curves::{
         bls12_377::{Bls12_377, Fr},
-        PairingCurve,
         PairingEngine,
     };
     use snarkvm_fields::Field;
     use snarkvm_ledger::posw::{txids_to_roots, Marlin, PoswMarlin};
-    use snarkvm_polycommit::kzg10::UniversalParams;
     use snarkvm_r1cs::{ConstraintSynthesizer, ConstraintSystem, SynthesisError};
     use snarkvm_utilities::{serialize::*, UniformRand};
 
     use blake2::Blake2s;
-    use itertools::Itertools;
     use memmap::MmapOptions;
     use snarkvm_marlin::FiatShamirChaChaRng;
     use snarkvm_polycommit::sonic_pc::SonicKZG10;
-    use std::{collections::BTreeMap, fs::OpenOptions, ops::MulAssign};
+    use std::{fs::OpenOptions, ops::MulAssign};
 
     #[test]
     fn test_marlin_posw_bls12_377() {
@@ -54,52 +51,7 @@ mod test {
 
         let deserialized =
             Phase1::deserialize(&output, UseCompression::No, CheckForCorrectness::No, &parameters).unwrap();
-        let tau_powers_g1 = deserialized.tau_powers_g1;
-        let tau_powers_g2 = deserialized.tau_powers_g2;
-        let alpha_powers_g1 = deserialized.alpha_tau_powers_g1;
-
-        let mut alpha_tau_powers_g1 = BTreeMap::new();
-        for i in 0..3 {
-            alpha_tau_powers_g1.insert(i, alpha_powers_g1[i]);
-        }
-        alpha_powers_g1[3..]
-            .iter()
-            .chunks(3)
-            .into_iter()
-            .enumerate()
-            .for_each(|(i, c)| {
-                let c = c.into_iter().collect::<Vec<_>>();
-                alpha_tau_powers_g1.insert(parameters.powers_length - 1 - (1 << i) + 2, *c[0]);
-                alpha_tau_powers_g1.insert(parameters.powers_length - 1 - (1 << i) + 3, *c[1]);
-                alpha_tau_powers_g1.insert(parameters.powers_length - 1 - (1 << i) + 4, *c[2]);
-            });
-
-        let mut shift_powers_of_g = BTreeMap::new();
-        let mut neg_shift_powers_of_h = BTreeMap::new();
-        let mut degree_bounds = vec![];
-        tau_powers_g2[2..].iter().enumerate().skip(1).for_each(|(i, p)| {
-            degree_bounds.push((1 << i) - 2);
-            neg_shift_powers_of_h.insert((1 << i) - 2, (*p).clone());
-            shift_powers_of_g.insert(
-                (1 << i) - 2,
-                tau_powers_g1[parameters.powers_length - 1 - (1 << i) + 2].clone(),
-            );
-        });
-
-        let h = tau_powers_g2[0].clone();
-        let beta_h = tau_powers_g2[1].clone();
-
-        let universal_params = UniversalParams::<Bls12_377> {
-            powers_of_g: tau_powers_g1,
-            powers_of_gamma_g: alpha_tau_powers_g1,
-            h: h.clone(),
-            beta_h: beta_h.clone(),
-            supported_degree_bounds: degree_bounds,
-            inverse_powers_of_g: shift_powers_of_g,
-            inverse_neg_powers_of_h: neg_shift_powers_of_h,
-            prepared_h: h.prepare(),
-            prepared_beta_h: beta_h.prepare(),
-        };
+        let universal_params = deserialized.universal_params().unwrap();
 
         let posw = PoswMarlin::index::<_, rand_chacha::ChaChaRng>(&universal_params).unwrap();
 
@@ -196,51 +148,7 @@ mod test {
 
         let deserialized =
             Phase1::deserialize(&output, UseCompression::No, CheckForCorrectness::No, &parameters).unwrap();
-        let tau_powers_g1 = deserialized.tau_powers_g1;
-        let tau_powers_g2 = deserialized.tau_powers_g2;
-        let alpha_powers_g1 = deserialized.alpha_tau_powers_g1;
-
-        let mut alpha_tau_powers_g1 = BTreeMap::new();
-        for i in 0..3 {
-            alpha_tau_powers_g1.insert(i, alpha_powers_g1[i]);
-        }
-        alpha_powers_g1[3..]
-            .iter()
-            .chunks(3)
-            .into_iter()
-            .enumerate()
-            .for_each(|(i, c)| {
-                let c = c.into_iter().collect::<Vec<_>>();
-                alpha_tau_powers_g1.insert(parameters.powers_length - 1 - (1 << i) + 2, *c[0]);
-                alpha_tau_powers_g1.insert(parameters.powers_length - 1 - (1 << i) + 3, *c[1]);
-                alpha_tau_powers_g1.insert(parameters.powers_length - 1 - (1 << i) + 4, *c[2]);
-            });
-
-        let mut shift_powers_of_g = BTreeMap::new();
-        let mut neg_shift_powers_of_h = BTreeMap::new();
-        let mut degree_bounds = vec![];
-        tau_powers_g2[2..].iter().enumerate().skip(1).for_each(|(i, p)| {
-            degree_bounds.push((1 << i) - 2);
-            neg_shift_powers_of_h.insert((1 << i) - 2, (*p).clone());
-            shift_powers_of_g.insert(
-                (1 << i) - 2,
-                tau_powers_g1[parameters.powers_length - 1 - (1 << i) + 2].clone(),
-            );
-        });
-
-        let h = tau_powers_g2[0].clone();
-        let beta_h = tau_powers_g2[1].clone();
-        let universal_params = UniversalParams::<Bls12_377> {
-            powers_of_g: tau_powers_g1,
-            powers_of_gamma_g: alpha_tau_powers_g1,
-            h: h.clone(),
-            beta_h: beta_h.clone(),
-            supported_degree_bounds: degree_bounds,
-            inverse_powers_of_g: shift_powers_of_g,
-            inverse_neg_powers_of_h: neg_shift_powers_of_h,
-            prepared_h: h.prepare(),
-            prepared_beta_h: beta_h.prepare(),
-        };
+        let universal_params = deserialized.universal_params().unwrap();
 
         for _ in 0..100 {
             let a = Fr::rand(&mut rng);
@@ -305,51 +213,7 @@ mod test {
 
         let deserialized =
             Phase1::deserialize(&readable_map, UseCompression::No, CheckForCorrectness::No, &parameters).unwrap();
-        let tau_powers_g1 = deserialized.tau_powers_g1;
-        let tau_powers_g2 = deserialized.tau_powers_g2;
-        let alpha_powers_g1 = deserialized.alpha_tau_powers_g1;
-
-        let mut alpha_tau_powers_g1 = BTreeMap::new();
-        for i in 0..3 {
-            alpha_tau_powers_g1.insert(i, alpha_powers_g1[i]);
-        }
-        alpha_powers_g1[3..]
-            .iter()
-            .chunks(3)
-            .into_iter()
-            .enumerate()
-            .for_each(|(i, c)| {
-                let c = c.into_iter().collect::<Vec<_>>();
-                alpha_tau_powers_g1.insert(parameters.powers_length - 1 - (1 << i) + 2, *c[0]);
-                alpha_tau_powers_g1.insert(parameters.powers_length - 1 - (1 << i) + 3, *c[1]);
-                alpha_tau_powers_g1.insert(parameters.powers_length - 1 - (1 << i) + 4, *c[2]);
-            });
-
-        let mut shift_powers_of_g = BTreeMap::new();
-        let mut neg_shift_powers_of_h = BTreeMap::new();
-        let mut degree_bounds = vec![];
-        tau_powers_g2[2..].iter().enumerate().skip(1).for_each(|(i, p)| {
-            degree_bounds.push((1 << i) - 2);
-            neg_shift_powers_of_h.insert((1 << i) - 2, (*p).clone());
-            shift_powers_of_g.insert(
-                (1 << i) - 2,
-                tau_powers_g1[parameters.powers_length - 1 - (1 << i) + 2].clone(),
-            );
-        });
-
-        let h = tau_powers_g2[0].clone();
-        let beta_h = tau_powers_g2[1].clone();
-        let universal_params = UniversalParams::<Bls12_377> {
-            powers_of_g: tau_powers_g1,
-            powers_of_gamma_g: alpha_tau_powers_g1,
-            h: h.clone(),
-            beta_h: beta_h.clone(),
-            supported_degree_bounds: degree_bounds,
-            inverse_powers_of_g: shift_powers_of_g,
-            inverse_neg_powers_of_h: neg_shift_powers_of_h,
-            prepared_h: h.prepare(),
-            prepared_beta_h: beta_h.prepare(),
-        };
+        let universal_params = deserialized.universal_params().unwrap();
 
         for _ in 0..1 {
             let a = Fr::rand(&mut rng);
@@ -7,7 +7,7 @@ use crate::{
     keypair::{Keypair, PublicKey},
     parameters::*,
 };
-use setup_utils::{batch_mul, check_same_ratio, merge_pairs, InvariantKind, Phase2Error, Result};
+use setup_utils::{batch_mul, check_same_ratio, merge_pairs, zeroize_field, InvariantKind, Phase2Error, Result};
 use snarkvm_algorithms::snark::groth16::VerifyingKey;
 use snarkvm_curves::{AffineCurve, PairingEngine};
 use snarkvm_fields::Field;
@@ -241,12 +241,12 @@ pub fn contribute<E: PairingEngine, R: Rng + CryptoRng>(
     } = Keypair::new(delta_g1, cs_hash, &contributions, rng);
     let hash = public_key.hash();
     // THIS MUST BE DESTROYED
-    let delta = private_key.delta;
-    let delta_inv = private_key.delta.inverse().expect("nonzero");
+    let mut delta_inv = private_key.delta.inverse().expect("nonzero");
 
     // update the values
-    delta_g1 = delta_g1.mul(delta);
-    vk.delta_g2 = vk.delta_g2.mul(delta);
+    delta_g1 = delta_g1.mul(private_key.delta);
+    vk.delta_g2 = vk.delta_g2.mul(private_key.delta);
+    drop(private_key);
 
     // go back to the start of the buffer to write the updated vk and delta_g1
     buffer.seek(SeekFrom::Start(0))?;
@@ -275,7 +275,7 @@ pub fn contribute<E: PairingEngine, R: Rng + CryptoRng>(
     let l_query_len = u64::deserialize(&mut &*l)? as usize;
 
     // spawn 2 scoped threads to perform the contribution
-    crossbeam::scope(|s| -> Result<_> {
+    let result = crossbeam::scope(|s| -> Result<_> {
         let mut threads = Vec::with_capacity(2);
         let _enter = span.enter();
         threads.push(s.spawn(|_| {
@@ -304,7 +304,10 @@ pub fn contribute<E: PairingEngine, R: Rng + CryptoRng>(
         }
 
         Ok(())
-    })??;
+    });
+    // wipe the inverse of delta before handling any error
+    zeroize_field(&mut delta_inv);
+    result??;
 
     debug!("appending contribution...");
 
@@ -327,6 +330,26 @@ pub fn contribute<E: PairingEngine, R: Rng + CryptoRng>(
     Ok(hash)
 }
 
+/// Reads the contributions of a buffer which corresponds to the format of `MPCParameters`,
+/// skipping over the Groth16 Parameters instead of deserializing them
+pub fn read_contributions<E: PairingEngine>(buffer: &[u8]) -> Result<Vec<PublicKey<E>>> {
+    let buffer = &mut std::io::Cursor::new(buffer);
+    // The VK is small, and its length depends on the number of public inputs
+    VerifyingKey::<E>::deserialize(buffer)?;
+    // skip beta_g1 and delta_g1
+    buffer.seek(SeekFrom::Current(2 * E::G1Affine::SERIALIZED_SIZE as i64))?;
+
+    skip_vec::<E::G1Affine, _>(buffer)?; // Alpha G1
+    skip_vec::<E::G1Affine, _>(buffer)?; // Beta G1
+    skip_vec::<E::G2Affine, _>(buffer)?; // Beta G2
+    skip_vec::<E::G1Affine, _>(buffer)?; // H
+    skip_vec::<E::G1Affine, _>(buffer)?; // L
+
+    // skip the transcript hash
+    buffer.seek(SeekFrom::Current(64))?;
+    PublicKey::<E>::read_batch(buffer)
+}
+
 /// Skips the vector ahead of the cursor.
 fn skip_vec<C: AffineCurve, B: Read + Seek>(buffer: &mut B) -> Result<()> {
     let len = u64::deserialize(buffer)? as usize;
@@ -2,7 +2,7 @@
 //!
 //! A Groth16 keypair. Generate one with the Keypair::new method.
 //! Dispose of the private key ASAP once it's been used.
-use setup_utils::{CheckForCorrectness, Deserializer, HashWriter, Result, Serializer, UseCompression};
+use setup_utils::{zeroize_field, CheckForCorrectness, Deserializer, HashWriter, Result, Serializer, UseCompression};
 use snarkvm_curves::{PairingEngine, ProjectiveCurve};
 use snarkvm_utilities::{CanonicalSerialize, ConstantSerializedSize, UniformRand};
 
@@ -16,11 +16,18 @@ use std::{
 };
 
 /// This needs to be destroyed by at least one participant
-/// for the final parameters to be secure.
+/// for the final parameters to be secure. It is overwritten
+/// with zeros when it is dropped.
 pub struct PrivateKey<E: PairingEngine> {
     pub delta: E::Fr,
 }
 
+impl<E: PairingEngine> Drop for PrivateKey<E> {
+    fn drop(&mut self) {
+        zeroize_field(&mut self.delta);
+    }
+}
+
 pub const PUBKEY_SIZE: usize = 544; // 96 * 2 + 48 * 2 * 3 + 64, assuming uncompressed elements
 
 /// This allows others to verify that you contributed. The hash produced
@@ -122,18 +129,20 @@ impl<E: PairingEngine> Keypair<E> {
     /// in different parameters.
     pub fn new(delta_g1: E::G1Affine, cs_hash: [u8; 64], contributions: &[PublicKey<E>], rng: &mut impl Rng) -> Self {
         // Sample random delta -- THIS MUST BE DESTROYED
-        let delta: E::Fr = E::Fr::rand(rng);
-        let delta_after = delta_g1.mul(delta);
+        let private_key = PrivateKey {
+            delta: E::Fr::rand(rng),
+        };
+        let delta_after = delta_g1.mul(private_key.delta);
 
         // Compute delta s-pair in G1
         let s = E::G1Projective::rand(rng).into_affine();
-        let s_delta = s.mul(delta);
+        let s_delta = s.mul(private_key.delta);
 
         // Get the transcript
         let transcript = hash_cs_pubkeys(cs_hash, contributions, s, s_delta);
         // Compute delta s-pair in G2 by hashing the transcript and multiplying it by delta
         let r = hash_to_curve::<E::G2Affine>(&hex::encode(transcript[..].as_ref())).0;
-        let r_delta = r.mul(delta);
+        let r_delta = r.mul(private_key.delta);
 
         Self {
             public_key: PublicKey {
@@ -143,7 +152,7 @@ impl<E: PairingEngine> Keypair<E> {
                 r_delta,
                 transcript,
             },
-            private_key: PrivateKey { delta },
+            private_key,
         }
     }
 }
@@ -8,6 +8,9 @@ pub mod parameters;
 #[cfg(not(feature = "wasm"))]
 mod polynomial;
 
+#[cfg(not(feature = "wasm"))]
+pub mod r1cs;
+
 pub mod chunked_groth16;
 
 cfg_if! {
@@ -16,27 +19,55 @@ cfg_if! {
         use itertools::Itertools;
         use parameters::MPCParameters;
         use zexe_algebra::{Bls12_377, BW6_761, PairingEngine};
-        use setup_utils::{ get_rng, user_system_randomness };
+        use setup_utils::{ curves::Bls12_381, gather_entropy, get_rng, EntropySource, Zeroizing };
 
         macro_rules! log {
             ($($t:tt)*) => (web_sys::console::log_1(&format_args!($($t)*).to_string().into()))
         }
 
+        /// Contributes to the parameters, with randomness from the system only.
         #[wasm_bindgen]
         pub fn contribute(is_inner: bool, params: Vec<u8>) -> Result<Vec<u8>, JsValue> {
+            contribute_with_entropy(is_inner, params, vec![])
+        }
+
+        /// Contributes to the parameters, with randomness from the system combined with the
+        /// entropy gathered by the page, e.g. from mouse movements. The entropy may be empty.
+        #[wasm_bindgen]
+        pub fn contribute_with_entropy(is_inner: bool, params: Vec<u8>, entropy: Vec<u8>) -> Result<Vec<u8>, JsValue> {
+            let curve = match is_inner {
+                true => "bls12_377",
+                false => "bw6",
+            };
+            contribute_on_curve(curve.to_string(), params, entropy)
+        }
+
+        /// Contributes to parameters over the given curve: bls12_377, bw6 or bls12_381, e.g. for a
+        /// circuit set up from a constraint system file. The entropy may be empty.
+        #[wasm_bindgen]
+        pub fn contribute_on_curve(curve: String, params: Vec<u8>, entropy: Vec<u8>) -> Result<Vec<u8>, JsValue> {
             console_error_panic_hook::set_once();
 
+            let mut sources = EntropySource::non_interactive();
+            if !entropy.is_empty() {
+                sources.push(EntropySource::Provided(Zeroizing::new(entropy)));
+            }
+            let (seed, records) = gather_entropy(&sources).map_err(|e| JsValue::from_str(&e.to_string()))?;
+            log!("Gathered entropy from {:?}", records);
+
             log!("Initializing phase2");
-            let res = match is_inner {
-                true => contribute_challenge(&mut MPCParameters::<Bls12_377>::read(&*params).unwrap()),
-                false => contribute_challenge(&mut MPCParameters::<BW6_761>::read(&*params).unwrap()),
+            let res = match curve.to_lowercase().as_str() {
+                "bls12_377" => contribute_challenge(&mut MPCParameters::<Bls12_377>::read(&*params).unwrap(), &seed),
+                "bw6" => contribute_challenge(&mut MPCParameters::<BW6_761>::read(&*params).unwrap(), &seed),
+                "bls12_381" => contribute_challenge(&mut MPCParameters::<Bls12_381>::read(&*params).unwrap(), &seed),
+                _ => return Err(JsValue::from_str(&format!("unsupported curve: {}", curve))),
             };
 
             Ok(res)
         }
 
-        fn contribute_challenge<E: PairingEngine>(params: &mut MPCParameters<E>) -> Vec<u8> {
-            let mut rng = get_rng(&user_system_randomness());
+        fn contribute_challenge<E: PairingEngine>(params: &mut MPCParameters<E>, seed: &[u8]) -> Vec<u8> {
+            let mut rng = get_rng(seed);
             log!("Contributing...");
             let hash = params.contribute(&mut rng);
             log!("Contribution hash: 0x{:02x}", hash.unwrap().iter().format(""));
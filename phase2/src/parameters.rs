@@ -2,7 +2,7 @@ use cfg_if::cfg_if;
 
 cfg_if! {
     if #[cfg(not(feature = "wasm"))] {
-        use super::polynomial::eval;
+        use super::polynomial::{eval, eval_lazy};
         use snarkvm_fields::Zero;
         use snarkvm_r1cs::SynthesisError;
     }
@@ -72,14 +72,14 @@ impl<E: PairingEngine> MPCParameters<E> {
         Aleo: PairingEngine,
     {
         let assembly = circuit_to_qap::<Aleo, E, _>(circuit)?;
-        let params = Groth16Params::<E>::read(
+        let params = LazyGroth16Params::<E>::new(
             transcript,
             compressed,
             check_input_for_correctness,
             phase1_size,
             phase2_size,
         )?;
-        Self::new(assembly, params)
+        Self::new_lazy(assembly, &params)
     }
 
     /// Create new Groth16 parameters for a given QAP which has been produced from a circuit.
@@ -101,6 +101,52 @@ impl<E: PairingEngine> MPCParameters<E> {
             assembly.num_public_variables,
         );
 
+        Self::from_queries(
+            (params.alpha_g1, params.beta_g1, params.beta_g2),
+            (a_g1, b_g1, b_g2, gamma_abc_g1, l),
+            params.h_g1,
+        )
+    }
+
+    /// Create new Groth16 parameters for a given QAP like `new`, but only reads each
+    /// section of the Lagrange coefficients from Phase 1 when it is evaluated, so that
+    /// they are never all in memory.
+    #[cfg(not(feature = "wasm"))]
+    pub fn new_lazy(assembly: KeypairAssembly<E>, params: &LazyGroth16Params<E>) -> Result<MPCParameters<E>> {
+        // Evaluate the QAP against the coefficients created from phase 1
+        let evaluations = eval_lazy::<E>(
+            params,
+            // QAP polynomials of the circuit
+            &assembly.at,
+            &assembly.bt,
+            &assembly.ct,
+            // Helper
+            assembly.num_public_variables,
+        )?;
+        drop(assembly);
+
+        Self::from_queries(
+            (params.alpha_g1, params.beta_g1, params.beta_g2),
+            evaluations,
+            params.h_g1()?,
+        )
+    }
+
+    /// Assembles the parameters from alpha and beta, the evaluated QAP
+    /// and the H query, which are all from phase 1.
+    #[cfg(not(feature = "wasm"))]
+    #[allow(clippy::type_complexity)]
+    fn from_queries(
+        (alpha_g1, beta_g1, beta_g2): (E::G1Affine, E::G1Affine, E::G2Affine),
+        (a_g1, b_g1, b_g2, gamma_abc_g1, l): (
+            Vec<E::G1Affine>,
+            Vec<E::G1Affine>,
+            Vec<E::G2Affine>,
+            Vec<E::G1Affine>,
+            Vec<E::G1Affine>,
+        ),
+        h_query: Vec<E::G1Affine>,
+    ) -> Result<MPCParameters<E>> {
         // Reject unconstrained elements, so that
         // the L query is always fully dense.
         for e in l.iter() {
@@ -110,8 +156,8 @@ impl<E: PairingEngine> MPCParameters<E> {
         }
 
         let vk = VerifyingKey {
-            alpha_g1: params.alpha_g1,
-            beta_g2: params.beta_g2,
+            alpha_g1,
+            beta_g2,
             // Gamma_g2 is always 1, since we're implementing
             // BGM17, pg14 https://eprint.iacr.org/2017/1050.pdf
             gamma_g2: E::G2Affine::prime_subgroup_generator(),
@@ -120,12 +166,12 @@ impl<E: PairingEngine> MPCParameters<E> {
         };
         let params = ProvingKey {
             vk,
-            beta_g1: params.beta_g1,
+            beta_g1,
             delta_g1: E::G1Affine::prime_subgroup_generator(),
             a_query: a_g1,
             b_g1_query: b_g1,
             b_g2_query: b_g2,
-            h_query: params.h_g1,
+            h_query,
             l_query: l,
         };
 
@@ -159,9 +205,11 @@ impl<E: PairingEngine> MPCParameters<E> {
         } = Keypair::new(self.params.delta_g1, self.cs_hash, &self.contributions, rng);
 
         // Invert delta and multiply the query's `l` and `h` by it
-        let delta_inv = private_key.delta.inverse().expect("nonzero");
-        batch_mul(&mut self.params.l_query, &delta_inv)?;
-        batch_mul(&mut self.params.h_query, &delta_inv)?;
+        let mut delta_inv = private_key.delta.inverse().expect("nonzero");
+        let result = batch_mul(&mut self.params.l_query, &delta_inv)
+            .and_then(|_| batch_mul(&mut self.params.h_query, &delta_inv));
+        zeroize_field(&mut delta_inv);
+        result?;
 
         // Multiply the `delta_g1` and `delta_g2` elements by the private key's delta
         self.params.vk.delta_g2 = self.params.vk.delta_g2.mul(private_key.delta);
@@ -431,11 +479,11 @@ pub fn circuit_to_qap<E: PairingEngine, Zexe: PairingEngine, C: ConstraintSynthe
 mod tests {
     use super::*;
     use crate::{
-        chunked_groth16::{contribute, verify},
+        chunked_groth16::{contribute, read_contributions, verify},
         helpers::testing::TestCircuit,
     };
     use phase1::{helpers::testing::setup_verify, Phase1, Phase1Parameters, ProvingSystem};
-    use setup_utils::{Groth16Params, UseCompression};
+    use setup_utils::{Groth16Params, LazyGroth16Params, UseCompression};
     use snarkvm_curves::bls12_377::Bls12_377;
 
     use rand::thread_rng;
@@ -512,7 +560,10 @@ mod tests {
         // second contribution via batched method
         let mut c2_buf = c1_serialized.clone();
         c2_buf.resize(c2_buf.len() + PublicKey::<E>::size(), 0); // make the buffer larger by 1 contribution
-        contribute::<E, _>(&mut c2_buf, rng, 4).unwrap();
+        let hash = contribute::<E, _>(&mut c2_buf, rng, 4).unwrap();
+        let contributions = read_contributions::<E>(&c2_buf).unwrap();
+        assert_eq!(contributions.len(), 2);
+        assert_eq!(contributions[1].hash(), hash);
         let mut c2_cursor = std::io::Cursor::new(c2_buf.clone());
         c2_cursor.set_position(0);
 
@@ -542,9 +593,35 @@ mod tests {
         contribution2.verify(&contribution3).unwrap();
     }
 
+    #[test]
+    fn new_lazy_matches_new() {
+        let groth_params = generate_groth_params::<Bls12_377>();
+        let mut buffer = vec![];
+        groth_params.write(&mut buffer, UseCompression::No).unwrap();
+        let lazy =
+            LazyGroth16Params::<Bls12_377>::new(&buffer, UseCompression::No, CheckForCorrectness::Full, 7, 7).unwrap();
+
+        let assembly = circuit_to_qap::<Bls12_377, Bls12_377, _>(TestCircuit::<Bls12_377>(None)).unwrap();
+        let mpc = MPCParameters::new(assembly, groth_params).unwrap();
+        let assembly = circuit_to_qap::<Bls12_377, Bls12_377, _>(TestCircuit::<Bls12_377>(None)).unwrap();
+        let lazy_mpc = MPCParameters::new_lazy(assembly, &lazy).unwrap();
+        assert_eq!(lazy_mpc, mpc);
+    }
+
     // helper which generates the initial phase 2 params
     // for the TestCircuit
     fn generate_ceremony<Aleo: PairingEngine, E: PairingEngine>() -> MPCParameters<E> {
+        let groth_params = generate_groth_params::<E>();
+
+        // this circuit requires 7 constraints, so a ceremony with size 8 is sufficient
+        let c = TestCircuit::<Aleo>(None);
+        let assembly = circuit_to_qap::<Aleo, E, _>(c).unwrap();
+
+        MPCParameters::new(assembly, groth_params).unwrap()
+    }
+
+    // helper which prepares the powers of tau for the TestCircuit
+    fn generate_groth_params<E: PairingEngine>() -> Groth16Params<E> {
         // the phase2 params are generated correctly,
         // even though the powers of tau are >> the circuit size
         let powers = 5;
@@ -557,7 +634,7 @@ mod tests {
             Phase1::deserialize(&output, compressed, CheckForCorrectness::Full, &params).unwrap()
         };
 
-        let groth_params = Groth16Params::<E>::new(
+        Groth16Params::<E>::new(
             phase2_size,
             accumulator.tau_powers_g1,
             accumulator.tau_powers_g2,
@@ -565,12 +642,6 @@ mod tests {
             accumulator.beta_tau_powers_g1,
             accumulator.beta_g2,
         )
-        .unwrap();
-
-        // this circuit requires 7 constraints, so a ceremony with size 8 is sufficient
-        let c = TestCircuit::<Aleo>(None);
-        let assembly = circuit_to_qap::<Aleo, E, _>(c).unwrap();
-
-        MPCParameters::new(assembly, groth_params).unwrap()
+        .unwrap()
     }
 }
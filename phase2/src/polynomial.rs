@@ -1,3 +1,5 @@
+use setup_utils::{LazyGroth16Params, Result};
+
 use snarkvm_curves::{AffineCurve, PairingEngine, ProjectiveCurve};
 use snarkvm_fields::Zero;
 use snarkvm_r1cs::Index;
@@ -47,6 +49,57 @@ pub fn eval<E: PairingEngine>(
     (a_g1, b_g1, b_g2, gamma_abc_g1, l)
 }
 
+/// Evaluates the QAP polynomials like `eval`, but reads the Lagrange coefficients
+/// one section at a time, so that only one of them is in memory.
+/// Format: [a_g1, b_g1, b_g2, gamma_abc_g1, l_g1]
+#[allow(clippy::type_complexity)]
+pub fn eval_lazy<E: PairingEngine>(
+    // Lagrange coefficients for tau, read in from Phase 1 when needed
+    params: &LazyGroth16Params<E>,
+    // QAP polynomials
+    at: &[Vec<(E::Fr, Index)>],
+    bt: &[Vec<(E::Fr, Index)>],
+    ct: &[Vec<(E::Fr, Index)>],
+    // The number of inputs
+    num_inputs: usize,
+) -> Result<(
+    Vec<E::G1Affine>,
+    Vec<E::G1Affine>,
+    Vec<E::G2Affine>,
+    Vec<E::G1Affine>,
+    Vec<E::G1Affine>,
+)> {
+    // a and b in G1, and the c part of the `gamma_abc_g1` and `l` coeffs
+    let coeffs_g1 = params.coeffs_g1()?;
+    let a_g1 = dot_product_vec(at, &coeffs_g1, num_inputs);
+    let b_g1 = dot_product_vec(bt, &coeffs_g1, num_inputs);
+    let mut ext = dot_product_vec(ct, &coeffs_g1, num_inputs);
+    drop(coeffs_g1);
+
+    let b_g2 = dot_product_vec(bt, &params.coeffs_g2()?, num_inputs);
+
+    // add the alpha * b and beta * a parts of the `gamma_abc_g1` and `l` coeffs
+    let alpha_b = dot_product_vec(bt, &params.alpha_coeffs_g1()?, num_inputs);
+    ext.par_iter_mut()
+        .zip(alpha_b)
+        .for_each(|(ext, alpha_b)| *ext += alpha_b);
+    let beta_a = dot_product_vec(at, &params.beta_coeffs_g1()?, num_inputs);
+    ext.par_iter_mut().zip(beta_a).for_each(|(ext, beta_a)| *ext += beta_a);
+    E::G1Projective::batch_normalization(&mut ext);
+
+    // break to `gamma_abc_g1` and `l` coeffs
+    let (gamma_abc_g1, l) = ext.split_at(num_inputs);
+
+    // back to affine and return
+    let a_g1 = a_g1.iter().map(|p| p.into_affine()).collect();
+    let b_g1 = b_g1.iter().map(|p| p.into_affine()).collect();
+    let b_g2 = b_g2.iter().map(|p| p.into_affine()).collect();
+    let gamma_abc_g1 = gamma_abc_g1.iter().map(|p| p.into_affine()).collect();
+    let l = l.iter().map(|p| p.into_affine()).collect();
+
+    Ok((a_g1, b_g1, b_g2, gamma_abc_g1, l))
+}
+
 #[allow(clippy::type_complexity)]
 #[allow(clippy::op_ref)] // false positive by clippy
 fn dot_product_ext<E: PairingEngine>(
@@ -0,0 +1,348 @@
+//! Loaders of constraint systems from files, so that Phase 2 can be run for circuits which
+//! are not written as a snarkVM `ConstraintSynthesizer` in this repository:
+//!
+//! * circom's binary `.r1cs` format, which is read into an `R1CS` that synthesizes its constraints
+//! * snarkVM's serialization of a `KeypairAssembly`, as returned by `circuit_to_qap`
+use crate::parameters::circuit_to_qap;
+use setup_utils::{Error, Result};
+
+use snarkvm_algorithms::snark::groth16::KeypairAssembly;
+use snarkvm_curves::PairingEngine;
+use snarkvm_fields::PrimeField;
+use snarkvm_r1cs::{ConstraintSynthesizer, ConstraintSystem, LinearCombination, SynthesisError, Variable};
+use snarkvm_utilities::{CanonicalDeserialize, CanonicalSerialize, FromBytes};
+
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::{fmt, io::Write, str::FromStr};
+
+/// The magic bytes at the start of a circom `.r1cs` file.
+const CIRCOM_MAGIC: &[u8; 4] = b"r1cs";
+/// The version of the circom `.r1cs` format which can be read.
+const CIRCOM_VERSION: u32 = 1;
+/// The section of a circom `.r1cs` file with the sizes of the constraint system.
+const CIRCOM_HEADER_SECTION: u32 = 1;
+/// The section of a circom `.r1cs` file with the constraints.
+const CIRCOM_CONSTRAINTS_SECTION: u32 = 2;
+
+/// The format of a constraint system file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum R1csFormat {
+    /// circom's binary `.r1cs` format.
+    Circom,
+    /// A `KeypairAssembly` serialized by snarkVM, with its input constraints.
+    SnarkVM,
+}
+
+impl fmt::Display for R1csFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            R1csFormat::Circom => write!(f, "circom"),
+            R1csFormat::SnarkVM => write!(f, "snarkvm"),
+        }
+    }
+}
+
+impl FromStr for R1csFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "circom" => Ok(R1csFormat::Circom),
+            "snarkvm" => Ok(R1csFormat::SnarkVM),
+            _ => Err(Error::InvalidR1cs(format!("unknown format {}", s))),
+        }
+    }
+}
+
+/// A linear combination of wires, as pairs of a coefficient and a wire.
+pub type Terms<F> = Vec<(F, usize)>;
+
+/// A rank-1 constraint system over numbered wires, in the layout of circom: wire 0 is the
+/// constant one, followed by the other public wires, and then by the private wires.
+#[derive(Debug, Clone, PartialEq)]
+pub struct R1CS<F: PrimeField> {
+    /// The number of public wires, including the constant one.
+    pub num_public: usize,
+    /// The total number of wires.
+    pub num_wires: usize,
+    /// The constraints `a * b = c`.
+    pub constraints: Vec<(Terms<F>, Terms<F>, Terms<F>)>,
+}
+
+impl<F: PrimeField> R1CS<F> {
+    /// Reads a circom `.r1cs` file, whose prime must be the modulus of the field.
+    pub fn from_circom(mut bytes: &[u8]) -> Result<Self> {
+        let mut magic = [0u8; 4];
+        std::io::Read::read_exact(&mut bytes, &mut magic)?;
+        if &magic != CIRCOM_MAGIC {
+            return Err(invalid("the file does not start with the r1cs magic bytes".to_string()));
+        }
+        let version = bytes.read_u32::<LittleEndian>()?;
+        if version != CIRCOM_VERSION {
+            return Err(invalid(format!("version {} is not supported", version)));
+        }
+
+        // The sections can be in any order, so they are located before they are read.
+        let num_sections = bytes.read_u32::<LittleEndian>()?;
+        let mut header = None;
+        let mut constraints = None;
+        for _ in 0..num_sections {
+            let section_type = bytes.read_u32::<LittleEndian>()?;
+            let section_size = bytes.read_u64::<LittleEndian>()? as usize;
+            if section_size > bytes.len() {
+                return Err(invalid(format!("section {} is truncated", section_type)));
+            }
+            let (section, rest) = bytes.split_at(section_size);
+            match section_type {
+                CIRCOM_HEADER_SECTION => header = Some(section),
+                CIRCOM_CONSTRAINTS_SECTION => constraints = Some(section),
+                // e.g. the map from wires to the labels of the signals
+                _ => {}
+            }
+            bytes = rest;
+        }
+        let mut header = header.ok_or_else(|| invalid("the header section is missing".to_string()))?;
+        let mut constraints = constraints.ok_or_else(|| invalid("the constraints section is missing".to_string()))?;
+
+        let field_size = header.read_u32::<LittleEndian>()? as usize;
+        let modulus = F::characteristic()
+            .iter()
+            .flat_map(|limb| limb.to_le_bytes().to_vec())
+            .collect::<Vec<_>>();
+        if field_size != modulus.len() || header.len() < field_size || header[..field_size] != modulus[..] {
+            return Err(invalid(
+                "the prime of the file is not the modulus of the field".to_string(),
+            ));
+        }
+        header = &header[field_size..];
+        let num_wires = header.read_u32::<LittleEndian>()? as usize;
+        let num_public_outputs = header.read_u32::<LittleEndian>()? as usize;
+        let num_public_inputs = header.read_u32::<LittleEndian>()? as usize;
+        let _num_private_inputs = header.read_u32::<LittleEndian>()?;
+        let _num_labels = header.read_u64::<LittleEndian>()?;
+        let num_constraints = header.read_u32::<LittleEndian>()? as usize;
+
+        let num_public = 1 + num_public_outputs + num_public_inputs;
+        if num_public > num_wires {
+            return Err(invalid(format!(
+                "{} public wires do not fit in {} wires",
+                num_public, num_wires
+            )));
+        }
+
+        let mut read_terms = || -> Result<Terms<F>> {
+            let num_terms = constraints.read_u32::<LittleEndian>()?;
+            (0..num_terms)
+                .map(|_| {
+                    let wire = constraints.read_u32::<LittleEndian>()? as usize;
+                    if wire >= num_wires {
+                        return Err(invalid(format!("wire {} is not one of the {} wires", wire, num_wires)));
+                    }
+                    // The coefficients are in little-endian and in normal form, like `FromBytes`.
+                    if constraints.len() < field_size {
+                        return Err(invalid("the constraints section is truncated".to_string()));
+                    }
+                    let (coefficient, rest) = constraints.split_at(field_size);
+                    constraints = rest;
+                    Ok((F::read_le(coefficient)?, wire))
+                })
+                .collect()
+        };
+        let constraints = (0..num_constraints)
+            .map(|_| Ok((read_terms()?, read_terms()?, read_terms()?)))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(R1CS {
+            num_public,
+            num_wires,
+            constraints,
+        })
+    }
+}
+
+impl<F: PrimeField> ConstraintSynthesizer<F> for R1CS<F> {
+    fn generate_constraints<CS: ConstraintSystem<F>>(&self, cs: &mut CS) -> std::result::Result<(), SynthesisError> {
+        // The constant one is already allocated, and the values of the wires are not known.
+        let mut variables = vec![CS::one()];
+        for wire in 1..self.num_wires {
+            let variable = match wire < self.num_public {
+                true => cs.alloc_input(|| format!("wire {}", wire), || Err(SynthesisError::AssignmentMissing))?,
+                false => cs.alloc(|| format!("wire {}", wire), || Err(SynthesisError::AssignmentMissing))?,
+            };
+            variables.push(variable);
+        }
+
+        for (i, (a, b, c)) in self.constraints.iter().enumerate() {
+            cs.enforce(
+                || format!("constraint {}", i),
+                |lc| combine(lc, a, &variables),
+                |lc| combine(lc, b, &variables),
+                |lc| combine(lc, c, &variables),
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Reads a `KeypairAssembly` which was serialized by snarkVM, e.g. with `write_assembly`.
+pub fn read_assembly<E: PairingEngine>(bytes: &[u8]) -> Result<KeypairAssembly<E>> {
+    Ok(KeypairAssembly::<E>::deserialize(&mut &bytes[..])?)
+}
+
+/// Serializes a `KeypairAssembly`, e.g. from `circuit_to_qap`, so that Phase 2 can be
+/// run for its circuit with `R1csFormat::SnarkVM`.
+pub fn write_assembly<E: PairingEngine, W: Write>(assembly: &KeypairAssembly<E>, writer: &mut W) -> Result<()> {
+    assembly.serialize(writer)?;
+    Ok(())
+}
+
+/// Loads a constraint system file in the given format, and returns it in QAP form with
+/// its input constraints, like `circuit_to_qap`.
+pub fn load_assembly<E: PairingEngine>(format: R1csFormat, bytes: &[u8]) -> Result<KeypairAssembly<E>> {
+    match format {
+        R1csFormat::Circom => circuit_to_qap::<E, E, _>(R1CS::<E::Fr>::from_circom(bytes)?),
+        R1csFormat::SnarkVM => read_assembly(bytes),
+    }
+}
+
+/// Adds the terms to a linear combination, with the variables of their wires.
+fn combine<F: PrimeField>(lc: LinearCombination<F>, terms: &Terms<F>, variables: &[Variable]) -> LinearCombination<F> {
+    terms
+        .iter()
+        .fold(lc, |lc, (coefficient, wire)| lc + (*coefficient, variables[*wire]))
+}
+
+fn invalid(message: String) -> Error {
+    Error::InvalidR1cs(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::testing::TestCircuit;
+
+    use snarkvm_curves::bls12_377::{Bls12_377, Fr};
+    use snarkvm_fields::{Field, One, Zero};
+    use snarkvm_r1cs::Index;
+    use snarkvm_utilities::ToBytes;
+
+    use byteorder::WriteBytesExt;
+
+    type Constraint = (Terms<Fr>, Terms<Fr>, Terms<Fr>);
+
+    /// Writes a circom file with one public output, one private input, and the given constraints.
+    fn circom_file(num_wires: u32, constraints: &[Constraint]) -> Vec<u8> {
+        let mut header = vec![];
+        let modulus = Fr::characteristic()
+            .iter()
+            .flat_map(|limb| limb.to_le_bytes().to_vec())
+            .collect::<Vec<_>>();
+        header.write_u32::<LittleEndian>(modulus.len() as u32).unwrap();
+        header.write_all(&modulus).unwrap();
+        for size in &[num_wires, 1, 0, 1] {
+            header.write_u32::<LittleEndian>(*size).unwrap();
+        }
+        header.write_u64::<LittleEndian>(num_wires as u64).unwrap();
+        header.write_u32::<LittleEndian>(constraints.len() as u32).unwrap();
+
+        let mut section = vec![];
+        for (a, b, c) in constraints {
+            for terms in &[a, b, c] {
+                section.write_u32::<LittleEndian>(terms.len() as u32).unwrap();
+                for (coefficient, wire) in terms.iter() {
+                    section.write_u32::<LittleEndian>(*wire as u32).unwrap();
+                    section.write_all(&coefficient.to_bytes_le().unwrap()).unwrap();
+                }
+            }
+        }
+
+        let mut file = CIRCOM_MAGIC.to_vec();
+        file.write_u32::<LittleEndian>(CIRCOM_VERSION).unwrap();
+        file.write_u32::<LittleEndian>(2).unwrap();
+        // The constraints come first, to check that the sections are located by type
+        for (section_type, section) in &[(CIRCOM_CONSTRAINTS_SECTION, section), (CIRCOM_HEADER_SECTION, header)] {
+            file.write_u32::<LittleEndian>(*section_type).unwrap();
+            file.write_u64::<LittleEndian>(section.len() as u64).unwrap();
+            file.write_all(section).unwrap();
+        }
+        file
+    }
+
+    // x * x = out, and 2 * x * (x + 1) = 2 * out + 2 * x, where out is wire 1 and x is wire 2
+    fn square_constraints() -> Vec<Constraint> {
+        let two = Fr::one() + Fr::one();
+        vec![
+            (vec![(Fr::one(), 2)], vec![(Fr::one(), 2)], vec![(Fr::one(), 1)]),
+            (vec![(two, 2)], vec![(Fr::one(), 2), (Fr::one(), 0)], vec![
+                (two, 1),
+                (two, 2),
+            ]),
+        ]
+    }
+
+    #[test]
+    fn test_read_circom() {
+        let file = circom_file(3, &square_constraints());
+        let r1cs = R1CS::<Fr>::from_circom(&file).unwrap();
+        assert_eq!(r1cs, R1CS {
+            num_public: 2,
+            num_wires: 3,
+            constraints: square_constraints(),
+        });
+
+        let assembly = load_assembly::<Bls12_377>(R1csFormat::Circom, &file).unwrap();
+        assert_eq!(assembly.num_public_variables, 2);
+        assert_eq!(assembly.num_private_variables, 1);
+        // The constraints of the file, and an input constraint for each public wire
+        assert_eq!(assembly.at.len(), 4);
+        assert_eq!(assembly.at[0], vec![(Fr::one(), Index::Private(0))]);
+        assert_eq!(assembly.ct[0], vec![(Fr::one(), Index::Public(1))]);
+        assert_eq!(assembly.bt[1], vec![
+            (Fr::one(), Index::Private(0)),
+            (Fr::one(), Index::Public(0))
+        ]);
+        assert_eq!(assembly.at[3], vec![(Fr::one(), Index::Public(1))]);
+    }
+
+    #[test]
+    fn test_read_invalid_circom() {
+        let file = circom_file(3, &square_constraints());
+
+        let mut invalid = file.clone();
+        invalid[0] = b'x';
+        assert!(R1CS::<Fr>::from_circom(&invalid).is_err());
+        assert!(R1CS::<Fr>::from_circom(&file[..file.len() - 1]).is_err());
+
+        // The prime is at the start of the header, which is the last section
+        let mut invalid = file;
+        let prime = invalid.len() - 28 - Fr::characteristic().len() * 8;
+        invalid[prime] ^= 1;
+        assert!(R1CS::<Fr>::from_circom(&invalid).is_err());
+
+        // A wire out of range
+        let constraints = vec![(vec![(Fr::one(), 3)], vec![], vec![(Fr::zero(), 0)])];
+        assert!(R1CS::<Fr>::from_circom(&circom_file(3, &constraints)).is_err());
+    }
+
+    #[test]
+    fn test_snarkvm_assembly() {
+        let assembly = circuit_to_qap::<Bls12_377, Bls12_377, _>(TestCircuit::<Bls12_377>(None)).unwrap();
+        let mut file = vec![];
+        write_assembly(&assembly, &mut file).unwrap();
+
+        let loaded = load_assembly::<Bls12_377>(R1csFormat::SnarkVM, &file).unwrap();
+        assert_eq!(loaded.num_public_variables, assembly.num_public_variables);
+        assert_eq!(loaded.num_private_variables, assembly.num_private_variables);
+        assert_eq!(loaded.at, assembly.at);
+        assert_eq!(loaded.bt, assembly.bt);
+        assert_eq!(loaded.ct, assembly.ct);
+    }
+
+    #[test]
+    fn test_parse_format() {
+        for format in &[R1csFormat::Circom, R1csFormat::SnarkVM] {
+            assert_eq!(format.to_string().parse::<R1csFormat>().unwrap(), *format);
+        }
+        assert!("bellman".parse::<R1csFormat>().is_err());
+    }
+}
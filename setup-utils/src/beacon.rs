@@ -0,0 +1,288 @@
+//! A random beacon, which derives the randomness of the last contribution of a ceremony
+//! from a public value (e.g. a bitcoin block header hash) by iterating SHA-256 over it.
+//!
+//! The iterations make the beacon slow to compute, so that its result can not be known
+//! in time to bias the ceremony. The intermediate hashes are recorded in a transcript at
+//! regular intervals, which lets anyone verify the beacon by checking the intervals in parallel.
+use crate::{Error, Result};
+
+use snarkvm_algorithms::cfg_into_iter;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sha2::{Digest, Sha256};
+use std::{
+    fs::File,
+    io::{self, BufReader, BufWriter, Write},
+};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The base 2 logarithm of the maximum number of checkpoints recorded in a beacon transcript.
+const CHECKPOINTS_LOG2: u32 = 10;
+
+/// The default base 2 logarithm of the number of SHA-256 iterations of the beacon.
+pub const DEFAULT_BEACON_ITERATIONS_LOG2: u32 = 42;
+
+/// The base 2 logarithm of the smallest number of iterations which delays the beacon.
+/// Fewer iterations are only computed when the beacon is explicitly insecure, for testing.
+pub const MIN_BEACON_ITERATIONS_LOG2: u32 = 32;
+
+/// The base 2 logarithm of the largest number of iterations of a beacon. A transcript
+/// claiming more iterations is rejected, as verifying it recomputes all of them.
+pub const MAX_BEACON_ITERATIONS_LOG2: u32 = 48;
+
+/// An intermediate hash of the beacon, after the given number of iterations.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BeaconCheckpoint {
+    /// The number of iterations performed before this checkpoint.
+    pub iteration: u64,
+    /// The hash after these iterations, encoded in hex.
+    #[serde(serialize_with = "serialize_hash", deserialize_with = "deserialize_hash")]
+    pub hash: [u8; 32],
+}
+
+/// The transcript of a beacon computation, which can be saved as JSON.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BeaconTranscript {
+    /// The base 2 logarithm of the number of SHA-256 iterations.
+    pub iterations_log2: u32,
+    /// The intermediate hashes, starting with the beacon hash at iteration 0.
+    pub checkpoints: Vec<BeaconCheckpoint>,
+    /// The hash after all iterations, which seeds the contribution.
+    #[serde(serialize_with = "serialize_hash", deserialize_with = "deserialize_hash")]
+    pub result: [u8; 32],
+}
+
+impl BeaconTranscript {
+    /// Returns the public value the beacon was computed from.
+    pub fn beacon_hash(&self) -> Option<[u8; 32]> {
+        self.checkpoints.first().map(|checkpoint| checkpoint.hash)
+    }
+
+    /// Returns the total number of SHA-256 iterations.
+    pub fn iterations(&self) -> u64 {
+        1u64 << self.iterations_log2
+    }
+
+    /// Recomputes the iterations between each pair of consecutive checkpoints, and between
+    /// the last checkpoint and the result, in parallel.
+    pub fn verify(&self) -> Result<()> {
+        if self.iterations_log2 > MAX_BEACON_ITERATIONS_LOG2 {
+            return Err(invalid(format!(
+                "2^{} iterations exceed the maximum of 2^{}",
+                self.iterations_log2, MAX_BEACON_ITERATIONS_LOG2
+            )));
+        }
+        if self.checkpoints.len() > 1 << CHECKPOINTS_LOG2 {
+            return Err(invalid(format!(
+                "{} checkpoints exceed the maximum of {}",
+                self.checkpoints.len(),
+                1 << CHECKPOINTS_LOG2
+            )));
+        }
+        match self.checkpoints.first() {
+            Some(checkpoint) if checkpoint.iteration == 0 => {}
+            _ => return Err(invalid("the first checkpoint must be the beacon hash".to_string())),
+        }
+
+        // Each interval goes from a checkpoint to the next one, and the last one to the result.
+        let mut intervals = Vec::with_capacity(self.checkpoints.len());
+        for (i, checkpoint) in self.checkpoints.iter().enumerate() {
+            let (end, expected) = match self.checkpoints.get(i + 1) {
+                Some(next) => (next.iteration, next.hash),
+                None => (self.iterations(), self.result),
+            };
+            if end <= checkpoint.iteration || end > self.iterations() {
+                return Err(invalid(format!(
+                    "the checkpoint after iteration {} is out of order",
+                    checkpoint.iteration
+                )));
+            }
+            intervals.push((checkpoint, end, expected));
+        }
+
+        cfg_into_iter!(intervals).try_for_each(|(checkpoint, end, expected)| {
+            let mut hash = checkpoint.hash;
+            iterate(&mut hash, end - checkpoint.iteration);
+            match hash == expected {
+                true => Ok(()),
+                false => Err(invalid(format!(
+                    "the hashes from iteration {} to {} do not match",
+                    checkpoint.iteration, end
+                ))),
+            }
+        })
+    }
+}
+
+/// Performs 2^`iterations_log2` SHA-256 iterations over the beacon hash, and records up
+/// to 1024 of the intermediate hashes so that the computation can be verified in parallel.
+///
+/// Returns `Error::UnsupportedBeaconIterations` if `iterations_log2` exceeds
+/// `MAX_BEACON_ITERATIONS_LOG2`.
+pub fn beacon_randomness(beacon_hash: [u8; 32], iterations_log2: u32) -> Result<BeaconTranscript> {
+    if iterations_log2 > MAX_BEACON_ITERATIONS_LOG2 {
+        return Err(Error::UnsupportedBeaconIterations(iterations_log2));
+    }
+    let interval = 1u64 << iterations_log2.saturating_sub(CHECKPOINTS_LOG2);
+
+    let mut hash = beacon_hash;
+    let mut checkpoints = vec![];
+    for iteration in (0..1u64 << iterations_log2).step_by(interval as usize) {
+        checkpoints.push(BeaconCheckpoint { iteration, hash });
+        iterate(&mut hash, interval);
+    }
+
+    Ok(BeaconTranscript {
+        iterations_log2,
+        checkpoints,
+        result: hash,
+    })
+}
+
+/// Computes the random beacon from a hex encoded hash, and writes its transcript as JSON.
+///
+/// Fewer than 2^`MIN_BEACON_ITERATIONS_LOG2` iterations do not delay the beacon, and return
+/// `Error::InsecureBeaconIterations` unless `insecure` is set.
+pub fn compute_beacon(
+    beacon_hash: &str,
+    iterations_log2: u32,
+    insecure: bool,
+    transcript_filename: &str,
+) -> Result<BeaconTranscript> {
+    if iterations_log2 < MIN_BEACON_ITERATIONS_LOG2 && !insecure {
+        return Err(Error::InsecureBeaconIterations(iterations_log2));
+    }
+    let transcript = beacon_randomness(parse_beacon_hash(beacon_hash)?, iterations_log2)?;
+
+    let mut writer = BufWriter::new(File::create(transcript_filename)?);
+    serde_json::to_writer_pretty(&mut writer, &transcript).map_err(io::Error::from)?;
+    writer.flush()?;
+
+    Ok(transcript)
+}
+
+/// Reads the JSON transcript of a random beacon, checks that it starts from the hex encoded
+/// hash, and verifies its checkpoints in parallel.
+///
+/// The transcript may have fewer than 2^`MIN_BEACON_ITERATIONS_LOG2` iterations, so its
+/// `iterations_log2` should be checked against the one announced for the ceremony.
+pub fn verify_beacon(beacon_hash: &str, transcript_filename: &str) -> Result<BeaconTranscript> {
+    let beacon_hash = parse_beacon_hash(beacon_hash)?;
+    let reader = BufReader::new(File::open(transcript_filename)?);
+    let transcript: BeaconTranscript = serde_json::from_reader(reader).map_err(io::Error::from)?;
+
+    if transcript.beacon_hash() != Some(beacon_hash) {
+        return Err(invalid(format!(
+            "it does not start from the beacon hash {}",
+            hex::encode(beacon_hash)
+        )));
+    }
+    transcript.verify()?;
+
+    Ok(transcript)
+}
+
+/// Decodes a hex encoded beacon hash of 32 bytes.
+fn parse_beacon_hash(beacon_hash: &str) -> Result<[u8; 32]> {
+    let bytes = hex::decode(beacon_hash).map_err(|e| Error::InvalidBeaconHash(e.to_string()))?;
+    match bytes.len() {
+        32 => Ok(crate::from_slice(&bytes)),
+        len => Err(Error::InvalidBeaconHash(format!("expected 32 bytes, got {}", len))),
+    }
+}
+
+/// Replaces the hash with the result of the given number of SHA-256 iterations over it.
+fn iterate(hash: &mut [u8; 32], iterations: u64) {
+    for _ in 0..iterations {
+        let result = Sha256::digest(&hash[..]);
+        hash.copy_from_slice(&result);
+    }
+}
+
+fn invalid(message: String) -> Error {
+    Error::InvalidBeaconTranscript(message)
+}
+
+fn serialize_hash<S: Serializer>(hash: &[u8; 32], serializer: S) -> std::result::Result<S::Ok, S::Error> {
+    serializer.serialize_str(&hex::encode(hash))
+}
+
+fn deserialize_hash<'de, D: Deserializer<'de>>(deserializer: D) -> std::result::Result<[u8; 32], D::Error> {
+    let bytes = hex::decode(String::deserialize(deserializer)?).map_err(serde::de::Error::custom)?;
+    match bytes.len() {
+        32 => Ok(crate::from_slice(&bytes)),
+        len => Err(serde::de::Error::custom(format!("expected 32 bytes, got {}", len))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn beacon_hash() -> [u8; 32] {
+        crate::from_slice(&hex::decode("0000000000000000000a558a61ddc8ee4e488d647a747fe4dcc362fe2026c620").unwrap())
+    }
+
+    #[test]
+    fn test_beacon_transcript() {
+        for iterations_log2 in &[0, 3, 10, 12] {
+            let transcript = beacon_randomness(beacon_hash(), *iterations_log2).unwrap();
+            assert_eq!(transcript.beacon_hash(), Some(beacon_hash()));
+            assert_eq!(
+                transcript.checkpoints.len(),
+                1 << (*iterations_log2).min(CHECKPOINTS_LOG2)
+            );
+            transcript.verify().unwrap();
+
+            let mut expected = beacon_hash();
+            iterate(&mut expected, transcript.iterations());
+            assert_eq!(transcript.result, expected);
+        }
+    }
+
+    #[test]
+    fn test_beacon_transcript_rejects_invalid_checkpoints() {
+        let transcript = beacon_randomness(beacon_hash(), 12).unwrap();
+
+        let mut invalid = transcript.clone();
+        invalid.checkpoints[100].hash[0] ^= 1;
+        assert!(invalid.verify().is_err());
+
+        let mut invalid = transcript.clone();
+        invalid.result[0] ^= 1;
+        assert!(invalid.verify().is_err());
+
+        let mut invalid = transcript.clone();
+        invalid.checkpoints.swap(1, 2);
+        assert!(invalid.verify().is_err());
+
+        let mut invalid = transcript.clone();
+        invalid.checkpoints.remove(0);
+        assert!(invalid.verify().is_err());
+
+        // A transcript claiming too many iterations is rejected before recomputing them.
+        let mut invalid = transcript;
+        invalid.iterations_log2 = 63;
+        assert!(invalid.verify().is_err());
+    }
+
+    #[test]
+    fn test_beacon_iterations_are_bounded() {
+        assert!(matches!(
+            beacon_randomness(beacon_hash(), MAX_BEACON_ITERATIONS_LOG2 + 1),
+            Err(Error::UnsupportedBeaconIterations(_))
+        ));
+
+        let hash = hex::encode(beacon_hash());
+        assert!(matches!(
+            compute_beacon(&hash, 3, false, "unused.json"),
+            Err(Error::InsecureBeaconIterations(3))
+        ));
+        assert!(matches!(
+            compute_beacon("00", 3, true, "unused.json"),
+            Err(Error::InvalidBeaconHash(_))
+        ));
+    }
+}
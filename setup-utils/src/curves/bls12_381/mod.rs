@@ -0,0 +1,28 @@
+//! The BLS12-381 curve, implemented with the BLS12 templates of `snarkvm-curves`.
+
+pub mod fq;
+pub use fq::*;
+
+pub mod fq2;
+pub use fq2::*;
+
+pub mod fq6;
+pub use fq6::*;
+
+pub mod fq12;
+pub use fq12::*;
+
+pub mod fr;
+pub use fr::*;
+
+pub mod g1;
+pub use g1::*;
+
+pub mod g2;
+pub use g2::*;
+
+pub mod parameters;
+pub use parameters::*;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,33 @@
+use crate::curves::bls12_381::{
+    Bls12_381G1Parameters,
+    Bls12_381G2Parameters,
+    Fq,
+    Fq12Parameters,
+    Fq2Parameters,
+    Fq6Parameters,
+};
+use snarkvm_curves::templates::bls12::{Bls12, Bls12Parameters, TwistType};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Bls12_381Parameters;
+
+impl Bls12Parameters for Bls12_381Parameters {
+    type Fp = Fq;
+    type Fp12Params = Fq12Parameters;
+    type Fp2Params = Fq2Parameters;
+    type Fp6Params = Fq6Parameters;
+    type G1Parameters = Bls12_381G1Parameters;
+    type G2Parameters = Bls12_381G2Parameters;
+
+    /// TWIST_TYPE = M
+    const TWIST_TYPE: TwistType = TwistType::M;
+    /// X = -0xd201000000010000
+    const X: &'static [u64] = &[0xd201000000010000];
+    /// `x` is negative.
+    const X_IS_NEGATIVE: bool = true;
+}
+
+/// The BLS12-381 pairing-friendly curve, as used by Zcash and Ethereum 2.0.
+pub type Bls12_381 = Bls12<Bls12_381Parameters>;
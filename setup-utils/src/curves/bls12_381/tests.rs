@@ -0,0 +1,107 @@
+use crate::{
+    curves::bls12_381::{Bls12_381, Fq, Fq12, Fq2, Fr, G1Affine, G1Projective, G2Affine, G2Projective},
+    power_pairs,
+    same_ratio,
+};
+use snarkvm_curves::{AffineCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{Field, One, PrimeField, SquareRootField, Zero};
+use snarkvm_utilities::rand::UniformRand;
+
+use rand::thread_rng;
+use std::ops::MulAssign;
+
+#[test]
+fn test_fq_and_fr_arithmetic() {
+    let rng = &mut thread_rng();
+
+    let a = Fq::rand(rng);
+    assert_eq!(a * a.inverse().unwrap(), Fq::one());
+    assert_eq!(a.square().sqrt().map(|b| b == a || b == -a), Some(true));
+
+    let b = Fr::rand(rng);
+    assert_eq!(b * b.inverse().unwrap(), Fr::one());
+    assert_eq!(Fr::size_in_bits(), 255);
+    assert_eq!(Fq::size_in_bits(), 381);
+}
+
+#[test]
+fn test_frobenius() {
+    let rng = &mut thread_rng();
+    let characteristic = Fq::characteristic();
+
+    let a = Fq2::rand(rng);
+    let mut b = a;
+    b.frobenius_map(1);
+    assert_eq!(b, a.pow(characteristic));
+
+    let a = Fq12::rand(rng);
+    for power in 0..12 {
+        let mut b = a;
+        b.frobenius_map(power);
+
+        let mut c = a;
+        for _ in 0..power {
+            c = c.pow(characteristic);
+        }
+        assert_eq!(b, c);
+    }
+}
+
+#[test]
+fn test_generators() {
+    let g1 = G1Affine::prime_subgroup_generator();
+    assert!(g1.is_on_curve());
+    assert!(g1.is_in_correct_subgroup_assuming_on_curve());
+
+    let g2 = G2Affine::prime_subgroup_generator();
+    assert!(g2.is_on_curve());
+    assert!(g2.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_bilinearity() {
+    let rng = &mut thread_rng();
+
+    let a = G1Projective::rand(rng).into_affine();
+    let b = G2Projective::rand(rng).into_affine();
+    let s = Fr::rand(rng);
+
+    let sa = a.mul(s);
+    let sb = b.mul(s);
+
+    let ans1 = Bls12_381::pairing(sa, b);
+    let ans2 = Bls12_381::pairing(a, sb);
+    let ans3 = Bls12_381::pairing(a, b).pow(s.to_repr());
+
+    assert_eq!(ans1, ans2);
+    assert_eq!(ans2, ans3);
+
+    assert_ne!(ans1, Fq12::one());
+    assert_eq!(ans1.pow(Fr::characteristic()), Fq12::one());
+    assert!(Bls12_381::pairing(G1Affine::zero(), b).is_one());
+}
+
+#[test]
+fn test_same_ratio() {
+    let rng = &mut thread_rng();
+
+    let mut v = vec![];
+    let x = Fr::rand(rng);
+    let mut acc = Fr::one();
+    for _ in 0..16 {
+        v.push(G1Affine::prime_subgroup_generator().mul(acc));
+        acc.mul_assign(&x);
+    }
+
+    let gx = G2Affine::prime_subgroup_generator().mul(x);
+    assert!(same_ratio::<Bls12_381>(
+        &power_pairs(&v),
+        &(G2Affine::prime_subgroup_generator(), gx)
+    ));
+
+    v[1] = v[1].mul(Fr::rand(rng));
+    assert!(!same_ratio::<Bls12_381>(
+        &power_pairs(&v),
+        &(G2Affine::prime_subgroup_generator(), gx)
+    ));
+}
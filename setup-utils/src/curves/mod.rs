@@ -0,0 +1,4 @@
+//! Pairing-friendly curves which are not provided by `snarkvm-curves`.
+
+pub mod bls12_381;
+pub use bls12_381::Bls12_381;
@@ -37,6 +37,33 @@ impl fmt::Display for CheckForCorrectness {
     }
 }
 
+/// Determines how the ratios of an accumulator are checked.
+///
+/// Defaults to `Batched`, which is what the coordinator environments use.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub enum VerificationStrategy {
+    /// Checks the ratios of each batch with its own pairings.
+    PerBatch,
+    /// Combines the ratios of all batches with random scalars and checks them with a single multi-pairing.
+    /// This is much faster, and fails to detect an invalid accumulator with negligible probability.
+    Batched,
+}
+
+impl Default for VerificationStrategy {
+    fn default() -> Self {
+        VerificationStrategy::Batched
+    }
+}
+
+impl fmt::Display for VerificationStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            VerificationStrategy::PerBatch => write!(f, "PerBatch"),
+            VerificationStrategy::Batched => write!(f, "Batched"),
+        }
+    }
+}
+
 // todo: remove this, we can always get the size of the element
 // from the `buffer_size` method
 #[derive(Copy, Clone, Debug, PartialEq)]
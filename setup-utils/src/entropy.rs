@@ -0,0 +1,263 @@
+//! Sources of entropy for the randomness of a contribution.
+//!
+//! A contribution is only as secret as the seed of its RNG, so the seed is derived from a
+//! combination of sources: if any one of them is unpredictable, so is the seed. The sources
+//! which were used are recorded without their contents, so that they can be published.
+use crate::{Error, Result, Zeroize, Zeroizing};
+
+use blake2::{Blake2b, Digest};
+use rand::{rngs::OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt,
+    fs::File,
+    io::{self, BufRead, Read},
+    path::PathBuf,
+    str::FromStr,
+    time::Instant,
+};
+
+/// The number of bytes read from the operating system's RNG.
+const SYSTEM_BYTES: usize = 1024;
+
+/// The number of bytes read from a hardware RNG.
+const HARDWARE_BYTES: usize = 64;
+
+/// The number of lines of random text the user is asked to type.
+const KEYSTROKE_LINES: usize = 4;
+
+/// A source of entropy for a contribution.
+#[derive(Clone, PartialEq, Eq)]
+pub enum EntropySource {
+    /// Random bytes from the operating system's RNG.
+    System,
+    /// Random text typed by the user on stdin, along with the time at which each line is entered.
+    Keystrokes,
+    /// The contents of a file supplied by the user.
+    File(PathBuf),
+    /// Random bytes from a hardware RNG exposed as a device file, e.g. `/dev/hwrng`.
+    Hardware(PathBuf),
+    /// Random bytes provided by the caller, e.g. gathered by a browser.
+    Provided(Zeroizing<Vec<u8>>),
+}
+
+impl EntropySource {
+    /// Returns the sources used by an automated contribution, which never reads from stdin.
+    pub fn non_interactive() -> Vec<EntropySource> {
+        vec![EntropySource::System]
+    }
+
+    /// Returns the sources used by an interactive contribution.
+    pub fn interactive() -> Vec<EntropySource> {
+        vec![EntropySource::System, EntropySource::Keystrokes]
+    }
+
+    /// Feeds the entropy of this source to the hasher, and returns the number of bytes gathered.
+    fn gather(&self, hasher: &mut Blake2b) -> Result<usize> {
+        match self {
+            EntropySource::System => {
+                let mut bytes = Zeroizing::new(vec![0u8; SYSTEM_BYTES]);
+                OsRng.fill_bytes(&mut bytes);
+                hasher.update(&bytes[..]);
+                Ok(bytes.len())
+            }
+            EntropySource::Keystrokes => {
+                println!(
+                    "Type some random text and press [ENTER], {} times, to provide additional entropy...",
+                    KEYSTROKE_LINES
+                );
+                read_keystrokes(io::stdin().lock(), hasher)
+            }
+            EntropySource::File(path) => {
+                let mut bytes = Zeroizing::new(vec![]);
+                File::open(path)?.read_to_end(&mut bytes)?;
+                if bytes.is_empty() {
+                    return Err(Error::EntropyUnavailable(format!("{} is empty", path.display())));
+                }
+                hasher.update(&bytes[..]);
+                Ok(bytes.len())
+            }
+            EntropySource::Hardware(path) => {
+                let mut bytes = Zeroizing::new(vec![0u8; HARDWARE_BYTES]);
+                File::open(path)?.read_exact(&mut bytes)?;
+                hasher.update(&bytes[..]);
+                Ok(bytes.len())
+            }
+            EntropySource::Provided(bytes) => {
+                if bytes.is_empty() {
+                    return Err(Error::EntropyUnavailable("no bytes were provided".to_string()));
+                }
+                hasher.update(&bytes[..]);
+                Ok(bytes.len())
+            }
+        }
+    }
+}
+
+/// Formats the source as it is parsed, without the contents of provided bytes.
+impl fmt::Display for EntropySource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EntropySource::System => write!(f, "system"),
+            EntropySource::Keystrokes => write!(f, "keystrokes"),
+            EntropySource::File(path) => write!(f, "file:{}", path.display()),
+            EntropySource::Hardware(path) => write!(f, "hardware:{}", path.display()),
+            EntropySource::Provided(_) => write!(f, "provided"),
+        }
+    }
+}
+
+impl fmt::Debug for EntropySource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// Parses `system`, `keystrokes`, `file:<path>` or `hardware:<path>`.
+impl FromStr for EntropySource {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.split_once(':') {
+            None if s == "system" => Ok(EntropySource::System),
+            None if s == "keystrokes" => Ok(EntropySource::Keystrokes),
+            Some(("file", path)) if !path.is_empty() => Ok(EntropySource::File(path.into())),
+            Some(("hardware", path)) if !path.is_empty() => Ok(EntropySource::Hardware(path.into())),
+            _ => Err(Error::InvalidEntropySource(s.to_string())),
+        }
+    }
+}
+
+/// A public record of a source of entropy which was used, without its contents.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EntropyRecord {
+    /// The source, formatted as it is parsed.
+    pub source: String,
+    /// The number of bytes gathered from the source.
+    pub bytes: usize,
+}
+
+/// Hashes the entropy of all the sources into a 64 byte seed for `get_rng`, and returns it
+/// along with the records of the sources. Fails if there are no sources, or if any of them
+/// is unavailable, e.g. when stdin is closed.
+pub fn gather_entropy(sources: &[EntropySource]) -> Result<(Zeroizing<Vec<u8>>, Vec<EntropyRecord>)> {
+    if sources.is_empty() {
+        return Err(Error::EntropyUnavailable("no entropy sources were given".to_string()));
+    }
+
+    let mut hasher = Blake2b::default();
+    let mut records = Vec::with_capacity(sources.len());
+    for source in sources {
+        // Separate the sources, so that bytes can not be moved from one to the other.
+        let name = source.to_string();
+        hasher.update(&(name.len() as u64).to_le_bytes());
+        hasher.update(name.as_bytes());
+
+        let bytes = source.gather(&mut hasher)?;
+        records.push(EntropyRecord { source: name, bytes });
+    }
+
+    let mut digest = hasher.finalize();
+    let seed = Zeroizing::new(digest.to_vec());
+    digest.as_mut_slice().zeroize();
+    Ok((seed, records))
+}
+
+/// Reads lines of random text, and hashes them along with the time at which they were
+/// entered. Fails if the input ends before all the lines were read.
+fn read_keystrokes<R: BufRead>(mut reader: R, hasher: &mut Blake2b) -> Result<usize> {
+    let start = Instant::now();
+    let mut bytes = 0;
+    for _ in 0..KEYSTROKE_LINES {
+        let mut line = Zeroizing::new(String::new());
+        if reader.read_line(&mut line)? == 0 {
+            return Err(Error::EntropyUnavailable(
+                "stdin was closed before the random text was entered".to_string(),
+            ));
+        }
+        let elapsed = start.elapsed().as_nanos().to_le_bytes();
+        hasher.update(line.as_bytes());
+        hasher.update(&elapsed);
+        bytes += line.len() + elapsed.len();
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::Write;
+
+    fn provided(bytes: &[u8]) -> EntropySource {
+        EntropySource::Provided(Zeroizing::new(bytes.to_vec()))
+    }
+
+    #[test]
+    fn test_gather_entropy() {
+        let (first, records) = gather_entropy(&[provided(b"first"), provided(b"second")]).unwrap();
+        assert_eq!(first.len(), 64);
+        assert_eq!(records, vec![
+            EntropyRecord {
+                source: "provided".to_string(),
+                bytes: 5
+            },
+            EntropyRecord {
+                source: "provided".to_string(),
+                bytes: 6
+            },
+        ]);
+
+        // The seed only depends on the provided bytes, which are separated by source.
+        let (second, _) = gather_entropy(&[provided(b"first"), provided(b"second")]).unwrap();
+        assert_eq!(first, second);
+        let (third, _) = gather_entropy(&[provided(b"firsts"), provided(b"econd")]).unwrap();
+        assert_ne!(first, third);
+
+        // The system RNG gives a different seed every time.
+        let (first, _) = gather_entropy(&EntropySource::non_interactive()).unwrap();
+        let (second, _) = gather_entropy(&EntropySource::non_interactive()).unwrap();
+        assert_ne!(first, second);
+
+        assert!(gather_entropy(&[]).is_err());
+        assert!(gather_entropy(&[EntropySource::System, provided(b"")]).is_err());
+    }
+
+    #[test]
+    fn test_file_entropy() {
+        let path = std::env::temp_dir().join(format!("entropy_test_{}", std::process::id()));
+        File::create(&path).unwrap().write_all(&[7u8; 100]).unwrap();
+
+        let (seed, records) = gather_entropy(&[EntropySource::File(path.clone())]).unwrap();
+        assert_eq!(records[0].bytes, 100);
+        assert_eq!(records[0].source, format!("file:{}", path.display()));
+        let (hardware_seed, records) = gather_entropy(&[EntropySource::Hardware(path.clone())]).unwrap();
+        assert_eq!(records[0].bytes, HARDWARE_BYTES);
+        assert_ne!(seed, hardware_seed);
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(gather_entropy(&[EntropySource::File(path)]).is_err());
+    }
+
+    #[test]
+    fn test_keystrokes() {
+        let mut hasher = Blake2b::default();
+        let bytes = read_keystrokes(&b"a\nbc\nd\ne\n"[..], &mut hasher).unwrap();
+        assert_eq!(bytes, 9 + KEYSTROKE_LINES * 16);
+
+        // A closed stdin is an error rather than a panic.
+        let mut hasher = Blake2b::default();
+        assert!(read_keystrokes(&b"a\nb\n"[..], &mut hasher).is_err());
+        assert!(read_keystrokes(&b""[..], &mut hasher).is_err());
+    }
+
+    #[test]
+    fn test_parse_entropy_source() {
+        for source in &["system", "keystrokes", "file:seed.txt", "hardware:/dev/hwrng"] {
+            assert_eq!(&source.parse::<EntropySource>().unwrap().to_string(), source);
+        }
+        assert!("file:".parse::<EntropySource>().is_err());
+        assert!("provided".parse::<EntropySource>().is_err());
+        assert!("dice".parse::<EntropySource>().is_err());
+    }
+}
@@ -33,6 +33,50 @@ pub enum Error {
     IncorrectSubgroup,
     #[error("Got invalid decompression parameters")]
     InvalidDecompressionParametersError,
+    #[error("Invalid ptau file: {0}")]
+    InvalidPtauFile(String),
+    #[error("The ptau format is not defined for this curve")]
+    UnsupportedPtauCurve,
+    #[error("The powersoftau format is only defined for full Groth16 accumulators over BLS12-381")]
+    UnsupportedZcashParameters,
+    #[error("Invalid powersoftau group element: {0}")]
+    InvalidZcashElement(&'static str),
+    #[error("Universal parameters can only be exported from full Marlin accumulators")]
+    UnsupportedUniversalParams,
+    #[error("The operation was cancelled")]
+    Cancelled,
+    #[error("Invalid file header: {0}")]
+    InvalidFileHeader(String),
+    #[error("The file header describes the {field} {found}, but {expected} was expected")]
+    FileHeaderMismatch {
+        field: &'static str,
+        expected: String,
+        found: String,
+    },
+    #[error("Invalid checkpoint: {0}")]
+    InvalidCheckpoint(String),
+    #[error("Invalid beacon transcript: {0}")]
+    InvalidBeaconTranscript(String),
+    #[error("Invalid beacon hash: {0}")]
+    InvalidBeaconHash(String),
+    #[error("2^{0} beacon iterations are not supported")]
+    UnsupportedBeaconIterations(u32),
+    #[error("2^{0} beacon iterations do not delay the beacon, and are only allowed for testing")]
+    InsecureBeaconIterations(u32),
+    #[error("Invalid entropy source: {0}")]
+    InvalidEntropySource(String),
+    #[error("Entropy unavailable: {0}")]
+    EntropyUnavailable(String),
+    #[error("Invalid receipt: {0}")]
+    InvalidReceipt(String),
+    #[error("The receipt records the {field} {expected}, but {found} was found")]
+    ReceiptMismatch {
+        field: String,
+        expected: String,
+        found: String,
+    },
+    #[error("Invalid R1CS file: {0}")]
+    InvalidR1cs(String),
 }
 
 impl From<Box<dyn std::any::Any + Send>> for Error {
@@ -89,14 +133,83 @@ impl fmt::Display for InvariantKind {
     }
 }
 
-// todo: make this more detailed so that we can know which
-// exact pairing ratio check failed
 #[derive(Debug, Error)]
 pub enum VerificationError {
-    #[error("Invalid ratio! Context: {0}")]
+    #[error("Invalid ratio! Context: {context}{location}")]
     /// The ratio check via the pairing of the provided elements failed
-    InvalidRatio(&'static str),
+    InvalidRatio {
+        context: &'static str,
+        location: RatioLocation,
+    },
     #[error("Invalid generator for {0} powers")]
     /// The first power of Tau was not the generator of that group
     InvalidGenerator(ElementType),
 }
+
+impl VerificationError {
+    /// Returns the location of a failed ratio check, if this is one.
+    pub fn location(&self) -> Option<&RatioLocation> {
+        match self {
+            VerificationError::InvalidRatio { location, .. } => Some(location),
+            VerificationError::InvalidGenerator(_) => None,
+        }
+    }
+}
+
+/// The location in an accumulator of a ratio check which failed.
+/// Every field is optional, as not all checks are tied to elements of the accumulator.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RatioLocation {
+    /// The type of the checked elements.
+    pub element_type: Option<ElementType>,
+    /// The index of the chunk containing the checked elements.
+    pub chunk_index: Option<usize>,
+    /// The start and end indices of the batch containing the checked elements.
+    pub batch: Option<(usize, usize)>,
+    /// The index of the first power which does not have the expected ratio with the power before it.
+    pub power: Option<usize>,
+}
+
+impl RatioLocation {
+    pub fn with_element_type(mut self, element_type: ElementType) -> Self {
+        self.element_type = Some(element_type);
+        self
+    }
+
+    pub fn with_chunk_index(mut self, chunk_index: usize) -> Self {
+        self.chunk_index = Some(chunk_index);
+        self
+    }
+
+    pub fn with_batch(mut self, start: usize, end: usize) -> Self {
+        self.batch = Some((start, end));
+        self
+    }
+
+    pub fn with_power(mut self, power: usize) -> Self {
+        self.power = Some(power);
+        self
+    }
+}
+
+impl fmt::Display for RatioLocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut parts = vec![];
+        if let Some(element_type) = self.element_type {
+            parts.push(format!("{} elements", element_type));
+        }
+        if let Some(chunk_index) = self.chunk_index {
+            parts.push(format!("chunk {}", chunk_index));
+        }
+        if let Some((start, end)) = self.batch {
+            parts.push(format!("batch [{}, {})", start, end));
+        }
+        if let Some(power) = self.power {
+            parts.push(format!("power {}", power));
+        }
+        match parts.is_empty() {
+            true => Ok(()),
+            false => write!(f, " (at {})", parts.join(", ")),
+        }
+    }
+}
@@ -1,6 +1,16 @@
 /// Utilities to read/write and convert the Powers of Tau from Phase 1
 /// to Phase 2-compatible Lagrange Coefficients.
-use crate::{buffer_size, CheckForCorrectness, Deserializer, Result, Serializer, UseCompression};
+use crate::{
+    buffer_size,
+    check_cancelled,
+    CheckForCorrectness,
+    Deserializer,
+    NoProgress,
+    Progress,
+    Result,
+    Serializer,
+    UseCompression,
+};
 
 use snarkvm_algorithms::{
     cfg_into_iter,
@@ -40,6 +50,41 @@ impl<E: PairingEngine> PartialEq for Groth16Params<E> {
     }
 }
 
+/// The serialized sections of a Phase 1 accumulator, e.g. in a memory-mapped file,
+/// which `Groth16Params::write_streaming` deserializes one at a time.
+#[derive(Debug, Clone, Copy)]
+pub struct SerializedPowersOfTau<'a> {
+    /// tau^0, tau^1, ..., of which the first 2 * phase2_size - 1 are used
+    pub tau_powers_g1: &'a [u8],
+    /// tau^0, tau^1, ..., of which the first phase2_size are used
+    pub tau_powers_g2: &'a [u8],
+    /// alpha * tau^0, alpha * tau^1, ..., of which the first phase2_size are used
+    pub alpha_tau_powers_g1: &'a [u8],
+    /// beta * tau^0, beta * tau^1, ..., of which the first phase2_size are used
+    pub beta_tau_powers_g1: &'a [u8],
+    /// beta in G2
+    pub beta_g2: &'a [u8],
+    /// Whether the elements are compressed
+    pub compression: UseCompression,
+}
+
+/// Processed Phase 1 parameters in a buffer, e.g. a memory-mapped file, whose sections
+/// are only deserialized when they are requested. Unlike `Groth16Params`, only the
+/// sections which are in use need to be in memory.
+#[derive(Debug)]
+pub struct LazyGroth16Params<'a, E: PairingEngine> {
+    pub alpha_g1: E::G1Affine,
+    pub beta_g1: E::G1Affine,
+    pub beta_g2: E::G2Affine,
+    coeffs_g1: &'a [u8],
+    coeffs_g2: &'a [u8],
+    alpha_coeffs_g1: &'a [u8],
+    beta_coeffs_g1: &'a [u8],
+    h_g1: &'a [u8],
+    compressed: UseCompression,
+    check_input_for_correctness: CheckForCorrectness,
+}
+
 /// Performs an IFFT over the provided evaluation domain to the provided
 /// vector of affine points. It then normalizes and returns them back into
 /// affine form
@@ -79,48 +124,79 @@ impl<E: PairingEngine> Groth16Params<E> {
         alpha_tau_powers_g1: Vec<E::G1Affine>,
         beta_tau_powers_g1: Vec<E::G1Affine>,
         beta_g2: E::G2Affine,
+    ) -> Result<Self> {
+        Self::new_with_progress(
+            phase2_size,
+            tau_powers_g1,
+            tau_powers_g2,
+            alpha_tau_powers_g1,
+            beta_tau_powers_g1,
+            beta_g2,
+            &NoProgress,
+        )
+    }
+
+    /// Loads the Powers of Tau and transforms them to coefficient form,
+    /// and reports each of the 5 computed vectors to `progress`, which can
+    /// cancel the transformation between them.
+    ///
+    /// # Panics
+    ///
+    /// If `phase2_size` > length of any of the provided vectors
+    pub fn new_with_progress(
+        phase2_size: usize,
+        tau_powers_g1: Vec<E::G1Affine>,
+        tau_powers_g2: Vec<E::G2Affine>,
+        alpha_tau_powers_g1: Vec<E::G1Affine>,
+        beta_tau_powers_g1: Vec<E::G1Affine>,
+        beta_g2: E::G2Affine,
+        progress: &dyn Progress,
     ) -> Result<Self> {
         let span = info_span!("Groth16Utils_new");
         let _enter = span.enter();
 
+        check_cancelled(progress)?;
+
         // Create the evaluation domain
         let domain = EvaluationDomain::<E::Fr>::new(phase2_size).expect("could not create domain");
 
         info!("converting powers of tau to lagrange coefficients");
 
-        Ok(crossbeam::scope(|s| -> Result<_> {
-            // Convert the accumulated powers to Lagrange coefficients
-            let coeffs_g1 = s.spawn(|_| to_coeffs(&domain, &tau_powers_g1[0..phase2_size]));
-            let coeffs_g2 = s.spawn(|_| to_coeffs(&domain, &tau_powers_g2[0..phase2_size]));
-            let alpha_coeffs_g1 = s.spawn(|_| to_coeffs(&domain, &alpha_tau_powers_g1[0..phase2_size]));
-            let beta_coeffs_g1 = s.spawn(|_| to_coeffs(&domain, &beta_tau_powers_g1[0..phase2_size]));
-            // Calculate the query for the Groth16 proving system
-            let h_g1 = s.spawn(|_| h_query_groth16(&tau_powers_g1, phase2_size));
-
-            let coeffs_g1 = coeffs_g1.join()?;
-            debug!("tau g1 coefficients calculated");
-            let coeffs_g2 = coeffs_g2.join()?;
-            debug!("tau g2 coefficients calculated");
-            let alpha_coeffs_g1 = alpha_coeffs_g1.join()?;
-            debug!("alpha tau g1 coefficients calculated");
-            let beta_coeffs_g1 = beta_coeffs_g1.join()?;
-            debug!("beta tau g1 coefficients calculated");
-            let h_g1 = h_g1.join()?;
-            debug!("h query coefficients calculated");
-
-            info!("successfully created groth16 parameters from powers of tau");
-
-            Ok(Groth16Params {
-                alpha_g1: alpha_tau_powers_g1[0],
-                beta_g1: beta_tau_powers_g1[0],
-                beta_g2,
-                coeffs_g1,
-                coeffs_g2,
-                alpha_coeffs_g1,
-                beta_coeffs_g1,
-                h_g1,
-            })
-        })??)
+        // Convert the accumulated powers to Lagrange coefficients. The conversions run
+        // one after the other, so that `progress` can cancel the transformation between them.
+        let coeffs_g1 = to_coeffs(&domain, &tau_powers_g1[0..phase2_size]);
+        debug!("tau g1 coefficients calculated");
+        progress.on_progress(1, 5);
+        check_cancelled(progress)?;
+        let coeffs_g2 = to_coeffs(&domain, &tau_powers_g2[0..phase2_size]);
+        debug!("tau g2 coefficients calculated");
+        progress.on_progress(2, 5);
+        check_cancelled(progress)?;
+        let alpha_coeffs_g1 = to_coeffs(&domain, &alpha_tau_powers_g1[0..phase2_size]);
+        debug!("alpha tau g1 coefficients calculated");
+        progress.on_progress(3, 5);
+        check_cancelled(progress)?;
+        let beta_coeffs_g1 = to_coeffs(&domain, &beta_tau_powers_g1[0..phase2_size]);
+        debug!("beta tau g1 coefficients calculated");
+        progress.on_progress(4, 5);
+        check_cancelled(progress)?;
+        // Calculate the query for the Groth16 proving system
+        let h_g1 = h_query_groth16(&tau_powers_g1, phase2_size);
+        debug!("h query coefficients calculated");
+        progress.on_progress(5, 5);
+
+        info!("successfully created groth16 parameters from powers of tau");
+
+        Ok(Groth16Params {
+            alpha_g1: alpha_tau_powers_g1[0],
+            beta_g1: beta_tau_powers_g1[0],
+            beta_g2,
+            coeffs_g1,
+            coeffs_g2,
+            alpha_coeffs_g1,
+            beta_coeffs_g1,
+            h_g1,
+        })
     }
 
     /// Writes the data structure to the provided writer, in compressed or uncompressed form.
@@ -161,6 +237,95 @@ impl<E: PairingEngine> Groth16Params<E> {
         Ok(())
     }
 
+    /// Transforms serialized Powers of Tau to coefficient form and writes them like `write`,
+    /// without holding them all in memory like `new`. Each section is deserialized and
+    /// transformed on its own, and written as soon as it is ready. The H query is computed
+    /// and written in batches of `batch_size` elements. Each written section is reported
+    /// to `progress`, which can cancel the transformation between sections.
+    ///
+    /// # Panics
+    ///
+    /// If `phase2_size` > length of any of the provided sections
+    pub fn write_streaming<W: Write>(
+        writer: &mut W,
+        compression: UseCompression,
+        phase2_size: usize,
+        batch_size: usize,
+        powers: SerializedPowersOfTau,
+        check_input_for_correctness: CheckForCorrectness,
+        progress: &dyn Progress,
+    ) -> Result<()> {
+        let span = info_span!("Groth16Utils_write_streaming");
+        let _enter = span.enter();
+
+        check_cancelled(progress)?;
+
+        // Create the evaluation domain
+        let domain = EvaluationDomain::<E::Fr>::new(phase2_size).expect("could not create domain");
+
+        let input = powers.compression;
+        let check = check_input_for_correctness;
+        let g1_size = buffer_size::<E::G1Affine>(input);
+        let g2_size = buffer_size::<E::G2Affine>(input);
+
+        let alpha_g1: E::G1Affine = (&*powers.alpha_tau_powers_g1).read_element(input, check)?;
+        let beta_g1: E::G1Affine = (&*powers.beta_tau_powers_g1).read_element(input, check)?;
+        let beta_g2: E::G2Affine = (&*powers.beta_g2).read_element(input, check)?;
+        writer.write_element(&alpha_g1, compression)?;
+        writer.write_element(&beta_g1, compression)?;
+        writer.write_element(&beta_g2, compression)?;
+
+        info!("converting powers of tau to lagrange coefficients, one section at a time");
+
+        let tau_powers_g1 = powers.tau_powers_g1[..g1_size * phase2_size].read_batch::<E::G1Affine>(input, check)?;
+        writer.write_elements_exact(&to_coeffs(&domain, &tau_powers_g1), compression)?;
+        drop(tau_powers_g1);
+        debug!("tau g1 coefficients written");
+        progress.on_progress(1, 5);
+        check_cancelled(progress)?;
+
+        let tau_powers_g2 = powers.tau_powers_g2[..g2_size * phase2_size].read_batch::<E::G2Affine>(input, check)?;
+        writer.write_elements_exact(&to_coeffs(&domain, &tau_powers_g2), compression)?;
+        drop(tau_powers_g2);
+        debug!("tau g2 coefficients written");
+        progress.on_progress(2, 5);
+        check_cancelled(progress)?;
+
+        let alpha_tau_powers_g1 =
+            powers.alpha_tau_powers_g1[..g1_size * phase2_size].read_batch::<E::G1Affine>(input, check)?;
+        writer.write_elements_exact(&to_coeffs(&domain, &alpha_tau_powers_g1), compression)?;
+        drop(alpha_tau_powers_g1);
+        debug!("alpha tau g1 coefficients written");
+        progress.on_progress(3, 5);
+        check_cancelled(progress)?;
+
+        let beta_tau_powers_g1 =
+            powers.beta_tau_powers_g1[..g1_size * phase2_size].read_batch::<E::G1Affine>(input, check)?;
+        writer.write_elements_exact(&to_coeffs(&domain, &beta_tau_powers_g1), compression)?;
+        drop(beta_tau_powers_g1);
+        debug!("beta tau g1 coefficients written");
+        progress.on_progress(4, 5);
+        check_cancelled(progress)?;
+
+        // The H query pairs each of the first m - 1 powers with the power m places after it
+        for start in (0..phase2_size - 1).step_by(batch_size) {
+            let end = std::cmp::min(start + batch_size, phase2_size - 1);
+            let low = powers.tau_powers_g1[g1_size * start..g1_size * end].read_batch::<E::G1Affine>(input, check)?;
+            let high = powers.tau_powers_g1[g1_size * (start + phase2_size)..g1_size * (end + phase2_size)]
+                .read_batch::<E::G1Affine>(input, check)?;
+            let h_g1 = cfg_into_iter!(0..end - start)
+                .map(|i| high[i] + low[i].neg())
+                .collect::<Vec<_>>();
+            writer.write_elements_exact(&h_g1, compression)?;
+        }
+        debug!("h query coefficients written");
+        progress.on_progress(5, 5);
+
+        info!("successfully wrote groth16 parameters from powers of tau");
+
+        Ok(())
+    }
+
     /// Reads the first `num_constraints` coefficients from the provided processed
     /// Phase 1 transcript with size `phase1_size`.
     pub fn read(
@@ -173,32 +338,96 @@ impl<E: PairingEngine> Groth16Params<E> {
         let span = info_span!("Groth16Utils_read");
         let _enter = span.enter();
 
-        let mut reader = std::io::Cursor::new(reader);
+        LazyGroth16Params::<E>::new(
+            reader,
+            compressed,
+            check_input_for_correctness,
+            phase1_size,
+            num_constraints,
+        )?
+        .load()
+    }
+}
+
+impl<'a, E: PairingEngine> LazyGroth16Params<'a, E> {
+    /// Reads alpha and beta, and locates the sections of the first `num_constraints`
+    /// coefficients in the provided processed Phase 1 transcript with size `phase1_size`.
+    pub fn new(
+        buffer: &'a [u8],
+        compressed: UseCompression,
+        check_input_for_correctness: CheckForCorrectness,
+        phase1_size: usize,
+        num_constraints: usize,
+    ) -> Result<Self> {
+        let mut reader = buffer;
         let alpha_g1 = reader.read_element(compressed, check_input_for_correctness)?;
         let beta_g1 = reader.read_element(compressed, check_input_for_correctness)?;
         let beta_g2 = reader.read_element(compressed, check_input_for_correctness)?;
 
-        let position = reader.position() as usize;
-        let reader = &mut &reader.get_mut()[position..];
-
         // Split the transcript in the appropriate sections
-        let (in_coeffs_g1, in_coeffs_g2, in_alpha_coeffs_g1, in_beta_coeffs_g1, in_h_g1) =
+        let (coeffs_g1, coeffs_g2, alpha_coeffs_g1, beta_coeffs_g1, h_g1) =
             split_transcript::<E>(reader, phase1_size, num_constraints, compressed);
 
+        Ok(LazyGroth16Params {
+            alpha_g1,
+            beta_g1,
+            beta_g2,
+            coeffs_g1,
+            coeffs_g2,
+            alpha_coeffs_g1,
+            beta_coeffs_g1,
+            h_g1,
+            compressed,
+            check_input_for_correctness,
+        })
+    }
+
+    /// Returns the number of coefficients in each section, besides the H query which has one less.
+    pub fn num_constraints(&self) -> usize {
+        self.coeffs_g1.len() / buffer_size::<E::G1Affine>(self.compressed)
+    }
+
+    /// Reads the Lagrange coefficients in G1.
+    pub fn coeffs_g1(&self) -> Result<Vec<E::G1Affine>> {
+        self.coeffs_g1
+            .read_batch(self.compressed, self.check_input_for_correctness)
+    }
+
+    /// Reads the Lagrange coefficients in G2.
+    pub fn coeffs_g2(&self) -> Result<Vec<E::G2Affine>> {
+        self.coeffs_g2
+            .read_batch(self.compressed, self.check_input_for_correctness)
+    }
+
+    /// Reads the Lagrange coefficients in G1 with alpha.
+    pub fn alpha_coeffs_g1(&self) -> Result<Vec<E::G1Affine>> {
+        self.alpha_coeffs_g1
+            .read_batch(self.compressed, self.check_input_for_correctness)
+    }
+
+    /// Reads the Lagrange coefficients in G1 with beta.
+    pub fn beta_coeffs_g1(&self) -> Result<Vec<E::G1Affine>> {
+        self.beta_coeffs_g1
+            .read_batch(self.compressed, self.check_input_for_correctness)
+    }
+
+    /// Reads the bases of the H polynomial.
+    pub fn h_g1(&self) -> Result<Vec<E::G1Affine>> {
+        self.h_g1.read_batch(self.compressed, self.check_input_for_correctness)
+    }
+
+    /// Reads all the sections, in parallel.
+    pub fn load(&self) -> Result<Groth16Params<E>> {
         info!("reading groth16 parameters...");
         // Read all elements in parallel
         // note: '??' is used for getting the result from the threaded operation,
         // and then getting the result from the function inside the thread)
         Ok(crossbeam::scope(|s| -> Result<_> {
-            let coeffs_g1 =
-                s.spawn(|_| in_coeffs_g1.read_batch::<E::G1Affine>(compressed, check_input_for_correctness));
-            let coeffs_g2 =
-                s.spawn(|_| in_coeffs_g2.read_batch::<E::G2Affine>(compressed, check_input_for_correctness));
-            let alpha_coeffs_g1 =
-                s.spawn(|_| in_alpha_coeffs_g1.read_batch::<E::G1Affine>(compressed, check_input_for_correctness));
-            let beta_coeffs_g1 =
-                s.spawn(|_| in_beta_coeffs_g1.read_batch::<E::G1Affine>(compressed, check_input_for_correctness));
-            let h_g1 = s.spawn(|_| in_h_g1.read_batch::<E::G1Affine>(compressed, check_input_for_correctness));
+            let coeffs_g1 = s.spawn(|_| self.coeffs_g1());
+            let coeffs_g2 = s.spawn(|_| self.coeffs_g2());
+            let alpha_coeffs_g1 = s.spawn(|_| self.alpha_coeffs_g1());
+            let beta_coeffs_g1 = s.spawn(|_| self.beta_coeffs_g1());
+            let h_g1 = s.spawn(|_| self.h_g1());
 
             let coeffs_g1 = coeffs_g1.join()??;
             debug!("read tau g1 Coefficients");
@@ -214,9 +443,9 @@ impl<E: PairingEngine> Groth16Params<E> {
             info!("successfully read groth16 parameters");
 
             Ok(Groth16Params {
-                alpha_g1,
-                beta_g1,
-                beta_g2,
+                alpha_g1: self.alpha_g1,
+                beta_g1: self.beta_g1,
+                beta_g2: self.beta_g2,
                 coeffs_g1,
                 coeffs_g2,
                 alpha_coeffs_g1,
@@ -264,7 +493,7 @@ fn split_transcript<E: PairingEngine>(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::UseCompression;
+    use crate::{Error, UseCompression};
     use phase1::{
         helpers::testing::{
             setup_verify,
@@ -277,6 +506,7 @@ mod tests {
     };
 
     use snarkvm_curves::bls12_377::Bls12_377;
+    use snarkvm_fields::Zero;
 
     fn read_write_curve<E: PairingEngine>(powers: usize, prepared_phase1_size: usize, compressed: UseCompression) {
         fn compat(compression: UseCompression) -> UseCompressionPhase1 {
@@ -310,6 +540,24 @@ mod tests {
             &params,
         )
         .unwrap();
+        let generator_g1 = accumulator.tau_powers_g1[0];
+
+        // The accumulator as it would be in a file, for the streaming transformation
+        let mut sections = vec![vec![]; 4];
+        sections[0]
+            .write_elements_exact(&accumulator.tau_powers_g1, compressed)
+            .unwrap();
+        sections[1]
+            .write_elements_exact(&accumulator.tau_powers_g2, compressed)
+            .unwrap();
+        sections[2]
+            .write_elements_exact(&accumulator.alpha_tau_powers_g1, compressed)
+            .unwrap();
+        sections[3]
+            .write_elements_exact(&accumulator.beta_tau_powers_g1, compressed)
+            .unwrap();
+        let mut beta_g2 = vec![];
+        beta_g2.write_element(&accumulator.beta_g2, compressed).unwrap();
 
         let groth_params = Groth16Params::<E>::new(
             prepared_phase1_size,
@@ -321,8 +569,52 @@ mod tests {
         )
         .unwrap();
 
+        // The Lagrange polynomials of a domain sum to 1
+        let sum = groth_params
+            .coeffs_g1
+            .iter()
+            .fold(E::G1Projective::zero(), |sum, coeff| sum + coeff.into_projective());
+        assert_eq!(sum.into_affine(), generator_g1);
+
         let mut writer = vec![];
         groth_params.write(&mut writer, compressed).unwrap();
+
+        // Streaming the transformation writes the same parameters, in any batch size
+        for batch_size in &[3, prepared_phase1_size] {
+            let powers = SerializedPowersOfTau {
+                tau_powers_g1: &sections[0],
+                tau_powers_g2: &sections[1],
+                alpha_tau_powers_g1: &sections[2],
+                beta_tau_powers_g1: &sections[3],
+                beta_g2: &beta_g2,
+                compression: compressed,
+            };
+            let mut streamed = vec![];
+            Groth16Params::<E>::write_streaming(
+                &mut streamed,
+                compressed,
+                prepared_phase1_size,
+                *batch_size,
+                powers,
+                CheckForCorrectness::Full,
+                &NoProgress,
+            )
+            .unwrap();
+            assert_eq!(streamed, writer);
+        }
+
+        // The sections can be read lazily
+        let lazy = LazyGroth16Params::<E>::new(
+            &writer,
+            compressed,
+            CheckForCorrectness::Full,
+            prepared_phase1_size,
+            prepared_phase1_size,
+        )
+        .unwrap();
+        assert_eq!(lazy.num_constraints(), prepared_phase1_size);
+        assert_eq!(lazy.coeffs_g2().unwrap(), groth_params.coeffs_g2);
+        assert_eq!(lazy.h_g1().unwrap(), groth_params.h_g1);
         let mut reader = std::io::Cursor::new(writer);
         let deserialized = Groth16Params::<E>::read(
             &mut reader.get_mut(),
@@ -374,6 +666,39 @@ mod tests {
         read_write_curve::<Bls12_377>(power, prepared_phase1_size, UseCompression::No);
     }
 
+    #[test]
+    fn cancelled_between_vectors() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        // Cancels the transformation once the first vector is computed
+        struct CancelAfterFirst(AtomicUsize);
+        impl Progress for CancelAfterFirst {
+            fn on_progress(&self, processed: usize, _total: usize) {
+                self.0.store(processed, Ordering::SeqCst);
+            }
+
+            fn is_cancelled(&self) -> bool {
+                self.0.load(Ordering::SeqCst) >= 1
+            }
+        }
+
+        type G1 = <Bls12_377 as PairingEngine>::G1Affine;
+        type G2 = <Bls12_377 as PairingEngine>::G2Affine;
+        let size = 8;
+        let progress = CancelAfterFirst(AtomicUsize::new(0));
+        let result = Groth16Params::<Bls12_377>::new_with_progress(
+            size,
+            vec![G1::prime_subgroup_generator(); 2 * size],
+            vec![G2::prime_subgroup_generator(); size],
+            vec![G1::prime_subgroup_generator(); size],
+            vec![G1::prime_subgroup_generator(); size],
+            G2::prime_subgroup_generator(),
+            &progress,
+        );
+        assert!(matches!(result, Err(Error::Cancelled)));
+        assert_eq!(progress.0.load(Ordering::SeqCst), 1);
+    }
+
     #[test]
     #[should_panic]
     fn large_phase2_fails() {
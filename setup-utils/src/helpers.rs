@@ -1,15 +1,17 @@
 use crate::{
-    errors::{Error, VerificationError},
+    errors::{Error, RatioLocation, VerificationError},
     Result,
+    SecretRng,
+    Zeroize,
 };
 
 use snarkvm_algorithms::{cfg_into_iter, cfg_iter, cfg_iter_mut};
-use snarkvm_curves::{AffineCurve, Group, PairingEngine, ProjectiveCurve};
+use snarkvm_curves::{AffineCurve, Group, PairingCurve, PairingEngine, ProjectiveCurve};
 use snarkvm_fields::{Field, One, PrimeField, Zero};
 use snarkvm_utilities::{biginteger::BigInteger, rand::UniformRand, CanonicalSerialize, ConstantSerializedSize};
 
 use blake2::{digest::generic_array::GenericArray, Blake2b, Digest};
-use rand::{rngs::OsRng, thread_rng, CryptoRng, Rng, SeedableRng};
+use rand::{thread_rng, CryptoRng, Rng, SeedableRng};
 use rand_chacha::ChaChaRng;
 use std::{
     convert::TryInto,
@@ -19,9 +21,6 @@ use std::{
 };
 use typenum::consts::U64;
 
-#[cfg(not(feature = "wasm"))]
-use sha2::Sha256;
-
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
@@ -97,68 +96,12 @@ pub fn batch_exp<C: AffineCurve>(
     Ok(())
 }
 
-// Create an RNG based on a mixture of system randomness and user provided randomness
-pub fn user_system_randomness() -> Vec<u8> {
-    let mut system_rng = OsRng;
-    let mut h = Blake2b::default();
-
-    // Gather 1024 bytes of entropy from the system
-    for _ in 0..1024 {
-        let r: u8 = system_rng.gen();
-        h.update(&[r]);
-    }
-
-    // Ask the user to provide some information for additional entropy
-    let mut user_input = String::new();
-    println!("Type some random text and press [ENTER] to provide additional entropy...");
-    std::io::stdin()
-        .read_line(&mut user_input)
-        .expect("expected to read some random text from the user");
-
-    // Hash it all up to make a seed
-    h.update(&user_input.as_bytes());
-    let arr: GenericArray<u8, U64> = h.finalize();
-    arr.to_vec()
-}
-
-#[allow(clippy::modulo_one)]
-#[cfg(not(feature = "wasm"))]
-pub fn beacon_randomness(mut beacon_hash: [u8; 32]) -> [u8; 32] {
-    // Performs 2^n hash iterations over it
-    const N: u64 = 10;
-
-    for i in 0..(1u64 << N) {
-        // Print 1024 of the interstitial states
-        // so that verification can be
-        // parallelized
-
-        if i % (1u64 << (N - 10)) == 0 {
-            print!("{}: ", i);
-            for b in beacon_hash.iter() {
-                print!("{:02x}", b);
-            }
-            println!();
-        }
-
-        let mut h = Sha256::new();
-        h.update(&beacon_hash);
-        let result = h.finalize();
-        beacon_hash.copy_from_slice(&result);
-    }
-
-    print!("Final result of beacon: ");
-    for b in beacon_hash.iter() {
-        print!("{:02x}", b);
-    }
-    println!();
-
-    beacon_hash
-}
-
 /// Interpret the first 32 bytes of the digest as 8 32-bit words
 pub fn get_rng(digest: &[u8]) -> impl Rng + CryptoRng {
-    let seed = from_slice(digest);
-    ChaChaRng::from_seed(seed)
+    let mut seed = from_slice(digest);
+    let rng = SecretRng::new(ChaChaRng::from_seed(seed));
+    seed.zeroize();
+    rng
 }
 
 /// Gets the number of bits of the provided type
@@ -230,6 +173,17 @@ pub fn calculate_hash(input_map: &[u8]) -> GenericArray<u8, U64> {
     hasher.finalize()
 }
 
+/// Hashes the given parts after a previous hash, which chains the hashes of data
+/// produced incrementally.
+pub fn chain_hash(previous: &[u8], parts: &[&[u8]]) -> GenericArray<u8, U64> {
+    let mut hasher = Blake2b::default();
+    hasher.update(previous);
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher.finalize()
+}
+
 pub fn hash_to_g2<E: PairingEngine>(digest: &[u8]) -> E::G2Projective {
     let seed = from_slice(digest);
     let mut rng = ChaChaRng::from_seed(seed);
@@ -297,6 +251,24 @@ mod tests {
         assert!(!same_ratio::<Bls12_377>(&(g1_s, g1), &(g2, g2_s)));
     }
 
+    #[test]
+    fn test_check_same_ratios() {
+        let rng = &mut thread_rng();
+
+        let (s, t) = (Fr::rand(rng), Fr::rand(rng));
+        let g1 = G1Affine::prime_subgroup_generator();
+        let g2 = G2Affine::prime_subgroup_generator();
+        let (g1_s, g2_s) = (g1.mul(s), g2.mul(s));
+        let (g1_t, g2_t) = (g1.mul(t), g2.mul(t));
+
+        assert!(
+            check_same_ratios::<Bls12_377>(&[(&(g1, g1_s), &(g2, g2_s)), (&(g1_s, g1_s.mul(t)), &(g2, g2_t))], "")
+                .is_ok()
+        );
+        assert!(check_same_ratios::<Bls12_377>(&[(&(g1, g1_s), &(g2, g2_s)), (&(g1, g1_t), &(g2, g2_s))], "").is_err());
+        assert!(check_same_ratios::<Bls12_377>(&[(&(g1_s, g1), &(g2, g2_s))], "").is_err());
+    }
+
     #[test]
     fn test_power_pairs() {
         use std::ops::MulAssign;
@@ -368,8 +340,47 @@ pub fn check_same_ratio<E: PairingEngine>(
     g2: &(E::G2Affine, E::G2Affine),
     err: &'static str,
 ) -> Result<()> {
-    if E::pairing(g1.0, g2.1) != E::pairing(g1.1, g2.0) {
-        return Err(VerificationError::InvalidRatio(err).into());
+    check_same_ratio_at::<E>(g1, g2, err, RatioLocation::default())
+}
+
+/// Checks if pairs have the same ratio, and reports the given
+/// location of the pairs in the accumulator if they do not.
+pub fn check_same_ratio_at<E: PairingEngine>(
+    g1: &(E::G1Affine, E::G1Affine),
+    g2: &(E::G2Affine, E::G2Affine),
+    err: &'static str,
+    location: RatioLocation,
+) -> Result<()> {
+    if !same_ratio::<E>(g1, g2) {
+        return Err(VerificationError::InvalidRatio { context: err, location }.into());
+    }
+    Ok(())
+}
+
+/// Checks if all the given pairs have the same ratio with a single multi-pairing.
+/// Each check is scaled by a random scalar, so that a failing check can not be
+/// cancelled out by the others.
+pub fn check_same_ratios<E: PairingEngine>(
+    checks: &[(&(E::G1Affine, E::G1Affine), &(E::G2Affine, E::G2Affine))],
+    err: &'static str,
+) -> Result<()> {
+    let rng = &mut thread_rng();
+    let pairs = checks
+        .iter()
+        .flat_map(|(g1, g2)| {
+            let r = E::Fr::rand(rng);
+            vec![
+                (g1.0.mul(r).prepare(), g2.1.prepare()),
+                ((-g1.1).mul(r).prepare(), g2.0.prepare()),
+            ]
+        })
+        .collect::<Vec<_>>();
+    if !E::product_of_pairings(&pairs).is_one() {
+        return Err(VerificationError::InvalidRatio {
+            context: err,
+            location: RatioLocation::default(),
+        }
+        .into());
     }
     Ok(())
 }
@@ -2,17 +2,36 @@
 //!
 //! Utilities for building MPC Ceremonies for large SNARKs.
 //! Provides traits for batched writing and reading group elements to buffers.
+#[cfg(not(feature = "wasm"))]
+mod beacon;
+#[cfg(not(feature = "wasm"))]
+pub use beacon::{
+    beacon_randomness,
+    compute_beacon,
+    verify_beacon,
+    BeaconCheckpoint,
+    BeaconTranscript,
+    DEFAULT_BEACON_ITERATIONS_LOG2,
+    MAX_BEACON_ITERATIONS_LOG2,
+    MIN_BEACON_ITERATIONS_LOG2,
+};
+
 pub mod errors;
-pub use errors::{Error, InvariantKind, Phase2Error, VerificationError};
+pub use errors::{Error, InvariantKind, Phase2Error, RatioLocation, VerificationError};
 
 /// A convenience result type for returning errors
 pub type Result<T> = std::result::Result<T, Error>;
 
+mod entropy;
+pub use entropy::{gather_entropy, EntropyRecord, EntropySource};
+
 mod groth16_utils;
-pub use groth16_utils::Groth16Params;
+pub use groth16_utils::{Groth16Params, LazyGroth16Params, SerializedPowersOfTau};
+
+pub mod curves;
 
 mod elements;
-pub use elements::{CheckForCorrectness, ElementType, UseCompression};
+pub use elements::{CheckForCorrectness, ElementType, UseCompression, VerificationStrategy};
 
 mod helpers;
 pub use helpers::*;
@@ -20,8 +39,19 @@ pub use helpers::*;
 mod io;
 pub use io::{buffer_size, BatchDeserializer, BatchSerializer, Deserializer, Serializer};
 
+mod progress;
+pub use progress::{check_cancelled, NoProgress, Progress};
+
 pub mod rayon_cfg;
 
+#[cfg(not(feature = "wasm"))]
+mod receipt;
+#[cfg(not(feature = "wasm"))]
+pub use receipt::{ContributionReceipt, ReceiptRandomness};
+
+mod secret;
+pub use secret::{zeroize_field, zeroize_fields, SecretRng, Zeroize, Zeroizing};
+
 mod seed;
 pub use seed::derive_rng_from_seed;
 
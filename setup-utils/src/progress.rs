@@ -0,0 +1,39 @@
+//! Progress reporting and cancellation of long running operations.
+
+use crate::{Error, Result};
+
+/// Observes the progress of a long running operation, such as a contribution,
+/// a verification, an aggregation or the preparation of the phase 2 parameters.
+///
+/// Closures taking the number of processed steps and the total number of steps
+/// can be used to only report the progress.
+pub trait Progress: Send + Sync {
+    /// Called after `processed` out of `total` steps of the operation are done.
+    fn on_progress(&self, _processed: usize, _total: usize) {}
+
+    /// Returns `true` if the operation should stop. It is polled between steps,
+    /// and the operation then returns `Error::Cancelled`.
+    fn is_cancelled(&self) -> bool {
+        false
+    }
+}
+
+/// Ignores the progress of an operation, and never cancels it.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoProgress;
+
+impl Progress for NoProgress {}
+
+impl<F: Fn(usize, usize) + Send + Sync> Progress for F {
+    fn on_progress(&self, processed: usize, total: usize) {
+        self(processed, total)
+    }
+}
+
+/// Returns `Error::Cancelled` if the operation observed by `progress` should stop.
+pub fn check_cancelled(progress: &dyn Progress) -> Result<()> {
+    match progress.is_cancelled() {
+        true => Err(Error::Cancelled),
+        false => Ok(()),
+    }
+}
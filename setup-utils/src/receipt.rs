@@ -0,0 +1,189 @@
+//! Receipts of contributions, which can be published as JSON and checked against a transcript.
+use crate::{BeaconTranscript, EntropyRecord, Error, Result};
+
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Where the randomness of a contribution came from, without any of its secrets.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ReceiptRandomness {
+    /// The sources of entropy which seeded the contribution.
+    Entropy { sources: Vec<EntropyRecord> },
+    /// The random beacon which seeded the contribution, encoded in hex.
+    Beacon {
+        beacon_hash: String,
+        iterations_log2: u32,
+        result: String,
+    },
+}
+
+impl ReceiptRandomness {
+    /// Returns the randomness of a beacon contribution, without its checkpoints.
+    pub fn beacon(transcript: &BeaconTranscript) -> Self {
+        ReceiptRandomness::Beacon {
+            beacon_hash: hex::encode(transcript.beacon_hash().unwrap_or_default()),
+            iterations_log2: transcript.iterations_log2,
+            result: hex::encode(transcript.result),
+        }
+    }
+}
+
+/// A machine-readable receipt of a contribution. Hashes and keys are encoded in hex.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContributionReceipt {
+    /// The name of the software which made the contribution.
+    pub software: String,
+    /// The version of the software which made the contribution.
+    pub version: String,
+    /// The parameters of the ceremony, e.g. the curve and the power.
+    pub parameters: BTreeMap<String, String>,
+    /// The hash of the file which was contributed to.
+    pub challenge_hash: String,
+    /// The hash of the file which was produced.
+    pub response_hash: String,
+    /// The public key of the contribution, as it is serialized in the response.
+    pub public_key: String,
+    /// The time at which the contribution started, in seconds since the Unix epoch.
+    pub started_at: u64,
+    /// The duration of the contribution, in seconds.
+    pub duration_secs: f64,
+    /// Where the randomness of the contribution came from.
+    pub randomness: ReceiptRandomness,
+}
+
+impl ContributionReceipt {
+    /// Returns a receipt for a contribution which started at `started_at` and has just finished.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        software: &str,
+        version: &str,
+        parameters: BTreeMap<String, String>,
+        challenge_hash: &[u8],
+        response_hash: &[u8],
+        public_key: &[u8],
+        started_at: SystemTime,
+        randomness: ReceiptRandomness,
+    ) -> Self {
+        Self {
+            software: software.to_string(),
+            version: version.to_string(),
+            parameters,
+            challenge_hash: hex::encode(challenge_hash),
+            response_hash: hex::encode(response_hash),
+            public_key: hex::encode(public_key),
+            started_at: started_at.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs(),
+            duration_secs: started_at.elapsed().unwrap_or_default().as_secs_f64(),
+            randomness,
+        }
+    }
+
+    /// Checks that the receipt records the given parameters, hashes and public key.
+    pub fn check(
+        &self,
+        parameters: &BTreeMap<String, String>,
+        challenge_hash: &[u8],
+        response_hash: &[u8],
+        public_key: &[u8],
+    ) -> Result<()> {
+        self.check_parameters(parameters)?;
+
+        let fields = [
+            ("challenge hash", &self.challenge_hash, challenge_hash),
+            ("response hash", &self.response_hash, response_hash),
+            ("public key", &self.public_key, public_key),
+        ];
+        for (field, recorded, found) in &fields {
+            let found = hex::encode(found);
+            if !recorded.eq_ignore_ascii_case(&found) {
+                return Err(Error::ReceiptMismatch {
+                    field: field.to_string(),
+                    expected: recorded.to_string(),
+                    found,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that the receipt records the given parameters, and possibly others.
+    pub fn check_parameters(&self, parameters: &BTreeMap<String, String>) -> Result<()> {
+        for (name, value) in parameters {
+            match self.parameters.get(name) {
+                Some(recorded) if recorded == value => {}
+                recorded => {
+                    return Err(Error::ReceiptMismatch {
+                        field: format!("parameter {}", name),
+                        expected: recorded.cloned().unwrap_or_else(|| "nothing".to_string()),
+                        found: value.clone(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::beacon_randomness;
+
+    fn parameters() -> BTreeMap<String, String> {
+        [("power", "10"), ("curve", "Bls12_377")]
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_receipt_check() {
+        let receipt = ContributionReceipt::new(
+            "phase1-cli",
+            "0.3.0",
+            parameters(),
+            &[1; 64],
+            &[2; 64],
+            &[3; 10],
+            SystemTime::now(),
+            ReceiptRandomness::Entropy {
+                sources: vec![EntropyRecord {
+                    source: "system".to_string(),
+                    bytes: 1024,
+                }],
+            },
+        );
+        receipt.check(&parameters(), &[1; 64], &[2; 64], &[3; 10]).unwrap();
+
+        assert!(receipt.check(&parameters(), &[2; 64], &[2; 64], &[3; 10]).is_err());
+        assert!(receipt.check(&parameters(), &[1; 64], &[1; 64], &[3; 10]).is_err());
+        assert!(receipt.check(&parameters(), &[1; 64], &[2; 64], &[3; 11]).is_err());
+
+        let mut other_parameters = parameters();
+        other_parameters.insert("power".to_string(), "11".to_string());
+        assert!(receipt.check(&other_parameters, &[1; 64], &[2; 64], &[3; 10]).is_err());
+        let mut other_parameters = parameters();
+        other_parameters.insert("chunk_size".to_string(), "5".to_string());
+        assert!(receipt.check(&other_parameters, &[1; 64], &[2; 64], &[3; 10]).is_err());
+    }
+
+    #[test]
+    fn test_beacon_randomness() {
+        let transcript = beacon_randomness([5; 32], 4);
+        match ReceiptRandomness::beacon(&transcript) {
+            ReceiptRandomness::Beacon {
+                beacon_hash,
+                iterations_log2,
+                result,
+            } => {
+                assert_eq!(beacon_hash, hex::encode([5; 32]));
+                assert_eq!(iterations_log2, 4);
+                assert_eq!(result, hex::encode(transcript.result));
+            }
+            randomness => panic!("unexpected randomness {:?}", randomness),
+        }
+    }
+}
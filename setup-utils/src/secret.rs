@@ -0,0 +1,96 @@
+//! Helpers for wiping the toxic waste of a contribution from memory.
+//!
+//! Field elements from snarkVM do not implement `Zeroize`, so they are overwritten
+//! with volatile writes, followed by a compiler fence which keeps the writes from
+//! being reordered or optimized away.
+
+use snarkvm_fields::Field;
+
+use rand::{CryptoRng, RngCore, SeedableRng};
+use std::{
+    ptr,
+    sync::atomic::{self, Ordering},
+};
+
+pub use zeroize::{Zeroize, Zeroizing};
+
+/// Overwrites a secret field element with zero.
+pub fn zeroize_field<F: Field>(element: &mut F) {
+    // Safety: `element` is a valid and aligned mutable reference, and field elements do not own memory.
+    unsafe { ptr::write_volatile(element, F::zero()) };
+    atomic::compiler_fence(Ordering::SeqCst);
+}
+
+/// Overwrites a vector of secret field elements with zeros.
+pub fn zeroize_fields<F: Field>(elements: &mut [F]) {
+    elements.iter_mut().for_each(zeroize_field);
+}
+
+/// An RNG whose state is overwritten when it is dropped.
+///
+/// The RNGs used for contributions are seeded with secret randomness, and their state
+/// can be used to recompute every secret they have produced.
+pub struct SecretRng<R: RngCore + SeedableRng>(R);
+
+impl<R: RngCore + SeedableRng> SecretRng<R> {
+    pub fn new(rng: R) -> Self {
+        Self(rng)
+    }
+}
+
+impl<R: RngCore + SeedableRng> RngCore for SecretRng<R> {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.0.try_fill_bytes(dest)
+    }
+}
+
+impl<R: RngCore + SeedableRng + CryptoRng> CryptoRng for SecretRng<R> {}
+
+impl<R: RngCore + SeedableRng> Drop for SecretRng<R> {
+    fn drop(&mut self) {
+        // Safety: `self.0` is a valid and aligned mutable reference. The previous state is
+        // overwritten in place instead of being dropped, so the RNG must not own memory,
+        // which is the case for the block RNGs of `rand`.
+        unsafe { ptr::write_volatile(&mut self.0, R::from_seed(R::Seed::default())) };
+        atomic::compiler_fence(Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{One, Zero};
+
+    use rand::Rng;
+    use rand_chacha::ChaChaRng;
+
+    #[test]
+    fn test_zeroize_fields() {
+        let mut elements = vec![Fr::one(); 4];
+        zeroize_fields(&mut elements);
+        assert!(elements.iter().all(|element| element.is_zero()));
+    }
+
+    #[test]
+    fn test_secret_rng_matches_inner_rng() {
+        let mut expected = ChaChaRng::from_seed([7u8; 32]);
+        let mut rng = SecretRng::new(ChaChaRng::from_seed([7u8; 32]));
+        for _ in 0..16 {
+            assert_eq!(rng.gen::<u64>(), expected.gen::<u64>());
+        }
+    }
+}
@@ -1,14 +1,28 @@
+use crate::{SecretRng, Zeroizing};
+
 use blake2s_simd::Params;
 use rand::{CryptoRng, Rng, SeedableRng};
 use rand_chacha::ChaChaRng;
+use std::{
+    ptr,
+    sync::atomic::{self, Ordering},
+};
 
 pub const SEED_PERSONALIZATION: &[u8] = b"ALEOSEED";
 
 pub fn derive_rng_from_seed(seed: &[u8]) -> impl Rng + CryptoRng {
-    let seed_hash = Params::new()
-        .personal(SEED_PERSONALIZATION)
-        .to_state()
-        .update(seed)
-        .finalize();
-    ChaChaRng::from_seed(*seed_hash.as_array())
+    let mut state = Params::new().personal(SEED_PERSONALIZATION).to_state();
+    state.update(seed);
+    let mut seed_hash = state.finalize();
+    let rng_seed = Zeroizing::new(*seed_hash.as_array());
+
+    // The hasher buffers the seed and the hash is the seed of the RNG, but neither implements `Zeroize`.
+    // Safety: both are valid and aligned mutable references, and neither owns memory.
+    unsafe {
+        ptr::write_volatile(&mut state, Params::new().to_state());
+        ptr::write_volatile(&mut seed_hash, Params::new().to_state().finalize());
+    }
+    atomic::compiler_fence(Ordering::SeqCst);
+
+    SecretRng::new(ChaChaRng::from_seed(*rng_seed))
 }
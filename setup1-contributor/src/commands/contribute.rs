@@ -21,7 +21,7 @@ use phase1_coordinator::{
     objects::{Chunk, Round},
 };
 use setup1_shared::structures::{ContributorStatus, LockResponse, PublicSettings, TwitterInfo};
-use setup_utils::calculate_hash;
+use setup_utils::{calculate_hash, curves::Bls12_381};
 use snarkvm_curves::{bls12_377::Bls12_377, bw6_761::BW6_761, PairingEngine};
 use snarkvm_dpc::{parameters::testnet2::Testnet2Parameters, Address, PrivateKey, ViewKey};
 
@@ -242,8 +242,15 @@ impl Contribute {
             let compressed_output = self.environment.compressed_outputs();
             let check_input_correctness = self.environment.check_input_for_correctness();
 
-            // Run the contribution.
+            // Run the contribution, and show the contributed batches of the chunk.
+            let chunk_progress_bar = progress_bar.clone();
             let h = spawn_quiet(move || {
+                let progress = move |processed: usize, total: usize| {
+                    chunk_progress_bar.set_message(format!(
+                        "Contributing to chunk {}... ({}/{} batches)",
+                        chunk_id, processed, total
+                    ));
+                };
                 contribute(
                     compressed_input,
                     CHALLENGE_FILENAME,
@@ -252,6 +259,8 @@ impl Contribute {
                     check_input_correctness,
                     &parameters,
                     seeded_rng,
+                    false,
+                    &progress,
                 );
             });
             let result = h.join();
@@ -809,6 +818,7 @@ async fn start_contributor(opts: &ContributeOptions, public_settings: &PublicSet
     // Run the contributor.
     let contribution = match curve_kind {
         CurveKind::Bls12_377 => contribute.run_and_catch_errors::<Bls12_377>().await,
+        CurveKind::Bls12_381 => contribute.run_and_catch_errors::<Bls12_381>().await,
         CurveKind::BW6 => contribute.run_and_catch_errors::<BW6_761>().await,
     };
 
@@ -150,6 +150,16 @@ fn universal_environment() -> Environment {
     Production::from(Parameters::AleoUniversal).into()
 }
 
+#[inline]
+fn kzg_environment() -> Environment {
+    Production::from(Parameters::AleoKzg).into()
+}
+
+#[inline]
+fn bls12_381_environment() -> Environment {
+    Production::from(Parameters::Bls12_381).into()
+}
+
 /// Returns the [Environment] settings based on a setup kind
 pub fn environment_by_setup_kind(kind: &SetupKind) -> Environment {
     match kind {
@@ -157,5 +167,7 @@ pub fn environment_by_setup_kind(kind: &SetupKind) -> Environment {
         SetupKind::Inner => inner_environment(),
         SetupKind::Outer => outer_environment(),
         SetupKind::Universal => universal_environment(),
+        SetupKind::Kzg => kzg_environment(),
+        SetupKind::Bls12_381 => bls12_381_environment(),
     }
 }
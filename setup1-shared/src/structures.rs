@@ -10,6 +10,8 @@ pub enum SetupKind {
     Inner,
     Outer,
     Universal,
+    Kzg,
+    Bls12_381,
 }
 
 impl SetupKind {
@@ -19,6 +21,8 @@ impl SetupKind {
             SetupKind::Inner => "inner".to_owned(),
             SetupKind::Outer => "outer".to_owned(),
             SetupKind::Universal => "universal".to_owned(),
+            SetupKind::Kzg => "kzg".to_owned(),
+            SetupKind::Bls12_381 => "bls12_381".to_owned(),
         }
     }
 }
@@ -64,6 +68,19 @@ pub enum ContributorStatus {
     Other,
 }
 
+/// The verification task assigned to a verifier by the coordinator
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AssignedTask {
+    /// The round height of the task
+    pub round_id: u64,
+
+    /// The chunk id
+    pub chunk_id: u64,
+
+    /// The contribution id to be verified
+    pub contribution_id: u64,
+}
+
 #[derive(Serialize, Deserialize, Debug, Hash, Clone, PartialEq, Eq)]
 pub struct LockResponse {
     /// The chunk id
@@ -35,6 +35,14 @@ fn universal() -> Environment {
     Production::from(Parameters::AleoUniversal).into()
 }
 
+fn kzg() -> Environment {
+    Production::from(Parameters::AleoKzg).into()
+}
+
+fn bls12_381() -> Environment {
+    Production::from(Parameters::Bls12_381).into()
+}
+
 #[derive(Debug, StructOpt)]
 #[structopt(name = "Aleo setup verifier")]
 struct Options {
@@ -73,6 +81,8 @@ async fn main() {
         SetupKind::Inner => inner(),
         SetupKind::Outer => outer(),
         SetupKind::Universal => universal(),
+        SetupKind::Kzg => kzg(),
+        SetupKind::Bls12_381 => bls12_381(),
     };
 
     let raw_view_key = std::fs::read_to_string(options.view_key).expect("View key not found");
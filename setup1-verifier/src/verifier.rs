@@ -12,11 +12,11 @@ use phase1_coordinator::{
     phase1_chunked_parameters,
     Participant,
 };
-use setup_utils::calculate_hash;
+use setup1_shared::structures::AssignedTask;
+use setup_utils::{calculate_hash, curves::Bls12_381};
 use snarkvm_curves::{bls12_377::Bls12_377, bw6_761::BW6_761};
 use snarkvm_dpc::{parameters::testnet2::Testnet2Parameters, Address, ViewKey};
 
-use serde::{Deserialize, Serialize};
 use tracing::{debug, error, info};
 use url::Url;
 
@@ -44,13 +44,6 @@ fn pretty_hash(input: &[u8]) -> String {
     output
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
-pub struct AssignedTask {
-    pub round_id: u64,
-    pub chunk_id: u64,
-    pub contribution_id: u64,
-}
-
 ///
 /// The verifier used to manage and dispatch/execute verifier operations
 /// to the remote coordinator.
@@ -215,6 +208,15 @@ impl Verifier {
                 &next_challenge_locator,
                 &phase1_chunked_parameters!(Bls12_377, settings, chunk_id),
             ),
+            CurveKind::Bls12_381 => transform_pok_and_correctness(
+                compressed_challenge,
+                &challenge_file_locator,
+                compressed_response,
+                &response_locator,
+                compressed_challenge,
+                &next_challenge_locator,
+                &phase1_chunked_parameters!(Bls12_381, settings, chunk_id),
+            ),
             CurveKind::BW6 => transform_pok_and_correctness(
                 compressed_challenge,
                 &challenge_file_locator,
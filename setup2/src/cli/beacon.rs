@@ -0,0 +1,13 @@
+use gumdrop::Options;
+
+#[derive(Debug, Options, Clone)]
+pub struct VerifyBeaconOpts {
+    help: bool,
+    #[options(
+        help = "the beacon hash the transcript should start from",
+        default = "0000000000000000000a558a61ddc8ee4e488d647a747fe4dcc362fe2026c620"
+    )]
+    pub beacon_hash: String,
+    #[options(help = "the provided beacon transcript file", default = "beacon_transcript.json")]
+    pub beacon_transcript: String,
+}
@@ -1,5 +1,6 @@
+use crate::cli::{curve_from_str, curve_kind, CurveKind};
 use phase2::{chunked_groth16::contribute as chunked_contribute, keypair::PublicKey};
-use setup_utils::Result;
+use setup_utils::{curves::Bls12_381, EntropySource, Result};
 
 use snarkvm_curves::{bls12_377::Bls12_377, bw6_761::BW6_761};
 
@@ -23,9 +24,46 @@ pub struct ContributeOpts {
         default = "0000000000000000000a558a61ddc8ee4e488d647a747fe4dcc362fe2026c620"
     )]
     pub beacon_hash: String,
+    #[options(
+        help = "the base 2 logarithm of the number of hash iterations of the beacon",
+        default = "42"
+    )]
+    pub beacon_iterations_log2: u32,
+    #[options(help = "allow a beacon with fewer than 2^32 hash iterations, which is only secure for testing")]
+    pub insecure_beacon: bool,
+    #[options(
+        help = "the file the beacon transcript will be written to",
+        default = "beacon_transcript.json"
+    )]
+    pub beacon_transcript: String,
+    #[options(help = "a source of entropy: system, keystrokes, file:<path> or hardware:<path>, can be repeated")]
+    pub entropy: Vec<EntropySource>,
+    #[options(help = "only use the system entropy when no source is given, instead of also asking for random text")]
+    pub non_interactive: bool,
+    #[options(
+        help = "the JSON receipt of the contribution which will be generated",
+        default = "receipt.json"
+    )]
+    pub receipt: String,
 
     #[options(help = "setup the inner or the outer circuit?")]
     pub is_inner: bool,
+    #[options(
+        help = "the curve of a constraint system file: bls12_377, bw6 or bls12_381 (default: the curve of the circuit)",
+        parse(try_from_str = "curve_from_str")
+    )]
+    pub curve_type: Option<CurveKind>,
+}
+
+impl ContributeOpts {
+    /// Returns the sources of entropy given on the command line, or the default ones.
+    pub fn entropy_sources(&self) -> Vec<EntropySource> {
+        match (self.entropy.is_empty(), self.non_interactive) {
+            (false, _) => self.entropy.clone(),
+            (true, false) => EntropySource::interactive(),
+            (true, true) => EntropySource::non_interactive(),
+        }
+    }
 }
 
 pub fn contribute<R: Rng + CryptoRng>(opts: &ContributeOpts, rng: &mut R) -> Result<()> {
@@ -36,22 +74,24 @@ pub fn contribute<R: Rng + CryptoRng>(opts: &ContributeOpts, rng: &mut R) -> Res
         .expect("could not open file for writing the new MPC parameters ");
     let metadata = file.metadata()?;
     // extend the file by 1 pubkey
-    if opts.is_inner {
-        file.set_len(metadata.len() + PublicKey::<Bls12_377>::size() as u64)?;
-    } else {
-        file.set_len(metadata.len() + PublicKey::<BW6_761>::size() as u64)?;
-    }
+    let curve = curve_kind(opts.curve_type, opts.is_inner);
+    let public_key_size = match curve {
+        CurveKind::Bls12_377 => PublicKey::<Bls12_377>::size(),
+        CurveKind::BW6 => PublicKey::<BW6_761>::size(),
+        CurveKind::Bls12_381 => PublicKey::<Bls12_381>::size(),
+    };
+    file.set_len(metadata.len() + public_key_size as u64)?;
     let mut file = unsafe {
         MmapOptions::new()
             .map_mut(file.file())
             .expect("unable to create a memory map for input")
     };
 
-    if opts.is_inner {
-        chunked_contribute::<Bls12_377, _>(&mut file, rng, opts.batch)?;
-    } else {
-        chunked_contribute::<BW6_761, _>(&mut file, rng, opts.batch)?;
-    }
+    match curve {
+        CurveKind::Bls12_377 => chunked_contribute::<Bls12_377, _>(&mut file, rng, opts.batch)?,
+        CurveKind::BW6 => chunked_contribute::<BW6_761, _>(&mut file, rng, opts.batch)?,
+        CurveKind::Bls12_381 => chunked_contribute::<Bls12_381, _>(&mut file, rng, opts.batch)?,
+    };
 
     Ok(())
 }
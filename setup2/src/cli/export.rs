@@ -0,0 +1,256 @@
+use crate::cli::{curve_from_str, curve_kind, receipt::receipt_parameters, CurveKind};
+use phase2::{
+    chunked_groth16::{read_contributions, verify as chunked_verify},
+    parameters::MPCParameters,
+};
+use setup_utils::{calculate_hash, curves::Bls12_381, HashWriter, Result};
+
+use snarkvm_curves::{bls12_377::Bls12_377, bw6_761::BW6_761, PairingEngine};
+use snarkvm_utilities::CanonicalSerialize;
+
+use fs_err::{File, OpenOptions};
+use gumdrop::Options;
+use memmap::MmapOptions;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    io::{self, BufWriter, Write},
+};
+
+#[derive(Debug, Options, Clone)]
+pub struct ExportOpts {
+    help: bool,
+    #[options(
+        help = "the parameters created by `new`, before any contribution",
+        default = "initial"
+    )]
+    pub initial: String,
+    #[options(help = "the parameters after the last contribution", default = "challenge")]
+    pub transcript: String,
+    #[options(help = "the batches which can be loaded in memory", default = "50000")]
+    pub batch: usize,
+    #[options(help = "the file the proving key will be written to", default = "proving_key")]
+    pub proving_key: String,
+    #[options(help = "the file the verifying key will be written to", default = "verifying_key")]
+    pub verifying_key: String,
+    #[options(
+        help = "the JSON file the checksums of the keys and the contributions will be written to",
+        default = "keys.json"
+    )]
+    pub manifest: String,
+    #[options(help = "setup the inner or the outer circuit?")]
+    pub is_inner: bool,
+    #[options(
+        help = "the curve of a constraint system file: bls12_377, bw6 or bls12_381 (default: the curve of the circuit)",
+        parse(try_from_str = "curve_from_str")
+    )]
+    pub curve_type: Option<CurveKind>,
+}
+
+/// A key written by `export`, with the hash of its file encoded in hex.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeyFile {
+    pub file: String,
+    pub hash: String,
+}
+
+/// The JSON manifest written by `export`. Hashes are encoded in hex.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeysManifest {
+    /// The name of the software which exported the keys.
+    pub software: String,
+    /// The version of the software which exported the keys.
+    pub version: String,
+    /// The circuit and the curve of the ceremony.
+    pub parameters: BTreeMap<String, String>,
+    /// The hash of the circuit, which the contributions are bound to.
+    pub cs_hash: String,
+    /// The hash of the parameters written by `new`, which anyone can check by running `new` again.
+    pub initial_hash: String,
+    /// The hash of the transcript after the last contribution.
+    pub transcript_hash: String,
+    pub proving_key: KeyFile,
+    pub verifying_key: KeyFile,
+    /// The hashes of the contributions in order, as returned when they were made.
+    pub contributions: Vec<String>,
+}
+
+/// Verifies every contribution of the transcript since the initial parameters, and writes the
+/// final proving and verifying keys, with a JSON manifest of their hashes and of the contributions.
+///
+/// The initial parameters are trusted: the contributions are only checked to build on them. Since
+/// `new` is deterministic, the manifest records their hash, which anyone can compare with the hash
+/// of the parameters they get by running `new` on the same Phase 1 transcript and circuit.
+pub fn export(opts: &ExportOpts) -> Result<()> {
+    let initial = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(&opts.initial)
+        .expect("could not read the initial MPC parameters");
+    let mut initial = unsafe {
+        MmapOptions::new()
+            .map_mut(initial.file())
+            .expect("unable to create a memory map for input")
+    };
+    let transcript = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(&opts.transcript)
+        .expect("could not read the final MPC transcript file");
+    let mut transcript = unsafe {
+        MmapOptions::new()
+            .map_mut(transcript.file())
+            .expect("unable to create a memory map for input")
+    };
+
+    match curve_kind(opts.curve_type, opts.is_inner) {
+        CurveKind::Bls12_377 => export_keys::<Bls12_377>(opts, &mut initial, &mut transcript),
+        CurveKind::BW6 => export_keys::<BW6_761>(opts, &mut initial, &mut transcript),
+        CurveKind::Bls12_381 => export_keys::<Bls12_381>(opts, &mut initial, &mut transcript),
+    }
+}
+
+fn export_keys<E: PairingEngine>(opts: &ExportOpts, initial: &mut [u8], transcript: &mut [u8]) -> Result<()> {
+    // The queries are only checked against the parameters before the first contribution.
+    let initial_contributions = read_contributions::<E>(initial)?.len();
+    if initial_contributions != 0 {
+        panic!(
+            "INVALID TRANSCRIPT: {} already has {} contributions",
+            opts.initial, initial_contributions
+        );
+    }
+    let initial_hash = calculate_hash(initial);
+    let contributions = chunked_verify::<E>(initial, transcript, opts.batch)?;
+    println!(
+        "Verified the {} contributions of {}",
+        contributions.len(),
+        opts.transcript
+    );
+
+    let mpc = MPCParameters::<E>::read(&transcript[..])?;
+    let proving_key = mpc.get_params();
+    // snarkVM serializes a `PreparedVerifyingKey` as its `VerifyingKey`, which it is prepared from.
+    let proving_key_hash = write_key(&opts.proving_key, proving_key)?;
+    let verifying_key_hash = write_key(&opts.verifying_key, &proving_key.vk)?;
+
+    let manifest = KeysManifest {
+        software: env!("CARGO_PKG_NAME").to_string(),
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        parameters: receipt_parameters(opts.is_inner, curve_kind(opts.curve_type, opts.is_inner)),
+        cs_hash: hex::encode(&mpc.cs_hash[..]),
+        initial_hash: hex::encode(initial_hash),
+        transcript_hash: hex::encode(calculate_hash(transcript)),
+        proving_key: KeyFile {
+            file: opts.proving_key.clone(),
+            hash: hex::encode(proving_key_hash),
+        },
+        verifying_key: KeyFile {
+            file: opts.verifying_key.clone(),
+            hash: hex::encode(verifying_key_hash),
+        },
+        contributions: contributions.iter().map(|hash| hex::encode(&hash[..])).collect(),
+    };
+    let mut writer = BufWriter::new(File::create(&opts.manifest)?);
+    serde_json::to_writer_pretty(&mut writer, &manifest).map_err(io::Error::from)?;
+    writer.flush()?;
+    println!(
+        "Wrote the proving key to {}, the verifying key to {} and their hashes to {}",
+        opts.proving_key, opts.verifying_key, opts.manifest
+    );
+    Ok(())
+}
+
+/// Writes a key in snarkVM's canonical serialization, and returns the hash of the file.
+fn write_key<K: CanonicalSerialize>(filename: &str, key: &K) -> Result<Vec<u8>> {
+    let mut writer = HashWriter::new(BufWriter::new(File::create(filename)?));
+    key.serialize(&mut writer)?;
+    writer.flush()?;
+    Ok(writer.into_hash().to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use phase1::{helpers::testing::setup_verify, Phase1, Phase1Parameters, ProvingSystem};
+    use phase2::{helpers::testing::TestCircuit, parameters::circuit_to_qap};
+    use setup_utils::{CheckForCorrectness, Groth16Params, UseCompression};
+
+    use snarkvm_algorithms::snark::groth16::{ProvingKey, VerifyingKey};
+    use snarkvm_utilities::CanonicalDeserialize;
+
+    use rand::thread_rng;
+    use std::{fs, path::Path};
+
+    #[test]
+    fn export_writes_the_keys_of_the_manifest() {
+        let dir = std::env::temp_dir().join(format!("setup2_export_test_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = |name: &str| dir.join(name).to_str().unwrap().to_string();
+
+        // the TestCircuit requires 7 constraints, so a ceremony with size 8 is sufficient
+        let params = Phase1Parameters::<Bls12_377>::new_full(ProvingSystem::Groth16, 5, 16);
+        let accumulator = {
+            let compressed = UseCompression::No;
+            let (_, output, _, _) = setup_verify(compressed, CheckForCorrectness::Full, compressed, &params);
+            Phase1::deserialize(&output, compressed, CheckForCorrectness::Full, &params).unwrap()
+        };
+        let groth_params = Groth16Params::<Bls12_377>::new(
+            8,
+            accumulator.tau_powers_g1,
+            accumulator.tau_powers_g2,
+            accumulator.alpha_tau_powers_g1,
+            accumulator.beta_tau_powers_g1,
+            accumulator.beta_g2,
+        )
+        .unwrap();
+        let assembly = circuit_to_qap::<Bls12_377, Bls12_377, _>(TestCircuit::<Bls12_377>(None)).unwrap();
+        let mut mpc = MPCParameters::new(assembly, groth_params).unwrap();
+        mpc.write(&mut File::create(path("initial")).unwrap()).unwrap();
+
+        let rng = &mut thread_rng();
+        let contribution1 = mpc.contribute(rng).unwrap();
+        let contribution2 = mpc.contribute(rng).unwrap();
+        mpc.write(&mut File::create(path("transcript")).unwrap()).unwrap();
+
+        let opts = ExportOpts {
+            help: false,
+            initial: path("initial"),
+            transcript: path("transcript"),
+            batch: 4,
+            proving_key: path("proving_key"),
+            verifying_key: path("verifying_key"),
+            manifest: path("keys.json"),
+            is_inner: true,
+            curve_type: None,
+        };
+        export(&opts).unwrap();
+
+        let manifest: KeysManifest = serde_json::from_slice(&fs::read(&opts.manifest).unwrap()).unwrap();
+        assert_eq!(manifest.parameters, receipt_parameters(true, CurveKind::Bls12_377));
+        assert_eq!(manifest.cs_hash, hex::encode(&mpc.cs_hash[..]));
+        assert_eq!(manifest.contributions, vec![
+            hex::encode(&contribution1[..]),
+            hex::encode(&contribution2[..])
+        ]);
+        assert_eq!(manifest.initial_hash, file_hash(&opts.initial));
+        assert_eq!(manifest.transcript_hash, file_hash(&opts.transcript));
+
+        // the keys are the ones of the transcript, and match the hashes of the manifest
+        assert_eq!(manifest.proving_key.file, opts.proving_key);
+        assert_eq!(manifest.proving_key.hash, file_hash(&opts.proving_key));
+        let proving_key = ProvingKey::<Bls12_377>::deserialize(&mut &fs::read(&opts.proving_key).unwrap()[..]).unwrap();
+        assert!(proving_key == mpc.params);
+
+        assert_eq!(manifest.verifying_key.file, opts.verifying_key);
+        assert_eq!(manifest.verifying_key.hash, file_hash(&opts.verifying_key));
+        let verifying_key =
+            VerifyingKey::<Bls12_377>::deserialize(&mut &fs::read(&opts.verifying_key).unwrap()[..]).unwrap();
+        assert!(verifying_key == mpc.params.vk);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    fn file_hash<P: AsRef<Path>>(path: P) -> String {
+        hex::encode(calculate_hash(&fs::read(path).unwrap()))
+    }
+}
@@ -1,9 +1,18 @@
-pub use new::{new, NewOpts};
+pub use new::{curve_from_str, curve_kind, new, CurveKind, NewOpts};
 mod new;
 
+mod beacon;
+pub use beacon::VerifyBeaconOpts;
+
 mod contribute;
 pub use contribute::{contribute, ContributeOpts};
 
+mod export;
+pub use export::{export, ExportOpts};
+
+mod receipt;
+pub use receipt::{hash_parameters, verify_receipt, write_receipt, VerifyReceiptOpts};
+
 mod verify;
 pub use verify::{verify, VerifyOpts};
 
@@ -20,6 +29,12 @@ pub enum Command {
     Beacon(ContributeOpts),
     #[options(help = "verify the contributions so far")]
     Verify(VerifyOpts),
+    #[options(help = "verify the transcript written by a beacon contribution")]
+    VerifyBeacon(VerifyBeaconOpts),
+    #[options(help = "check the receipt of a contribution against the contributions of a transcript")]
+    VerifyReceipt(VerifyReceiptOpts),
+    #[options(help = "verify the whole transcript and write the final proving and verifying keys")]
+    Export(ExportOpts),
 }
 
 #[derive(Debug, Options, Clone)]
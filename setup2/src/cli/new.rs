@@ -1,6 +1,17 @@
-use phase2::parameters::{circuit_to_qap, MPCParameters};
-use setup_utils::{log_2, CheckForCorrectness, Groth16Params, UseCompression};
-use snarkvm_algorithms::{SNARK, SRS};
+use phase2::{
+    parameters::{circuit_to_qap, MPCParameters},
+    r1cs::{load_assembly, R1csFormat},
+};
+use setup_utils::{
+    curves::Bls12_381,
+    log_2,
+    CheckForCorrectness,
+    LazyGroth16Params,
+    SecretRng,
+    UseCompression,
+    Zeroizing,
+};
+use snarkvm_algorithms::{snark::groth16::KeypairAssembly, SNARK, SRS};
 use snarkvm_curves::{bls12_377::Bls12_377, bw6_761::BW6_761, PairingEngine};
 use snarkvm_dpc::{
     parameters::testnet2::{Testnet2DPC, Testnet2Parameters},
@@ -25,21 +36,33 @@ const COMPRESSION: UseCompression = UseCompression::No;
 pub const SEED_LENGTH: usize = 32;
 pub type Seed = [u8; SEED_LENGTH];
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum CurveKind {
     Bls12_377,
     BW6,
+    Bls12_381,
 }
 
 pub fn curve_from_str(src: &str) -> std::result::Result<CurveKind, String> {
     let curve = match src.to_lowercase().as_str() {
         "bls12_377" => CurveKind::Bls12_377,
         "bw6" => CurveKind::BW6,
+        "bls12_381" => CurveKind::Bls12_381,
         _ => return Err("unsupported curve.".to_string()),
     };
     Ok(curve)
 }
 
+/// Returns the curve of the parameters, which is the curve of the inner or the outer circuit
+/// unless another one is given for a constraint system file.
+pub fn curve_kind(curve_type: Option<CurveKind>, is_inner: bool) -> CurveKind {
+    match (curve_type, is_inner) {
+        (Some(curve), _) => curve,
+        (None, true) => CurveKind::Bls12_377,
+        (None, false) => CurveKind::BW6,
+    }
+}
+
 #[derive(Debug, Options, Clone)]
 pub struct NewOpts {
     help: bool,
@@ -51,24 +74,46 @@ pub struct NewOpts {
     pub output: String,
 
     #[options(
-        help = "the elliptic curve to use",
-        default = "bls12_377",
+        help = "the curve of a constraint system file: bls12_377, bw6 or bls12_381 (default: the curve of the circuit)",
         parse(try_from_str = "curve_from_str")
     )]
-    pub curve_type: CurveKind,
+    pub curve_type: Option<CurveKind>,
 
     #[options(help = "setup the inner or the outer circuit?")]
     pub is_inner: bool,
+
+    #[options(help = "a constraint system file to setup instead of the inner or the outer circuit")]
+    pub r1cs: Option<String>,
+    #[options(
+        help = "the format of the constraint system file: circom or snarkvm",
+        default = "circom"
+    )]
+    pub r1cs_format: R1csFormat,
 }
 
 pub fn new(opt: &NewOpts) -> anyhow::Result<()> {
+    if let Some(r1cs) = &opt.r1cs {
+        // The constraint system is over the scalar field of the curve of the parameters.
+        let bytes = fs_err::read(r1cs)?;
+        return match curve_kind(opt.curve_type, opt.is_inner) {
+            CurveKind::Bls12_377 => write_params(opt, load_assembly::<ZexeInner>(opt.r1cs_format, &bytes)?),
+            CurveKind::BW6 => write_params(opt, load_assembly::<ZexeOuter>(opt.r1cs_format, &bytes)?),
+            CurveKind::Bls12_381 => write_params(opt, load_assembly::<Bls12_381>(opt.r1cs_format, &bytes)?),
+        };
+    }
+    if curve_kind(opt.curve_type, opt.is_inner) != curve_kind(None, opt.is_inner) {
+        anyhow::bail!(
+            "the inner and the outer circuits have their own curves, another curve needs a constraint system file"
+        );
+    }
+
     if opt.is_inner {
         let circuit = InnerCircuit::<Testnet2Parameters>::blank();
         generate_params::<AleoInner, ZexeInner, _>(opt, circuit)
     } else {
-        let mut seed: Seed = [0; SEED_LENGTH];
+        let mut seed: Zeroizing<Seed> = Zeroizing::new([0; SEED_LENGTH]);
         rand::thread_rng().fill_bytes(&mut seed[..]);
-        let rng = &mut ChaChaRng::from_seed(seed);
+        let rng = &mut SecretRng::new(ChaChaRng::from_seed(*seed));
         let dpc = Testnet2DPC::load(false)?;
 
         let noop_circuit = dpc
@@ -125,15 +170,35 @@ fn ceremony_size<F: Field, C: Clone + ConstraintSynthesizer<F>>(circuit: &C) ->
 pub fn generate_params<Aleo: PairingEngine, Zexe: PairingEngine, C: Clone + ConstraintSynthesizer<Aleo::Fr>>(
     opt: &NewOpts,
     circuit: C,
+) -> anyhow::Result<()> {
+    let phase2_size = ceremony_size(&circuit);
+    let keypair = circuit_to_qap::<Aleo, Zexe, _>(circuit)?;
+    write_phase2_params(opt, keypair, phase2_size)
+}
+
+/// Writes the initial parameters for a circuit which is already in QAP form, e.g. loaded from a file.
+/// Its input constraints are already counted, so the ceremony size is the next power of 2.
+pub fn write_params<Zexe: PairingEngine>(opt: &NewOpts, keypair: KeypairAssembly<Zexe>) -> anyhow::Result<()> {
+    let phase2_size = std::cmp::max(
+        keypair.at.len(),
+        keypair.num_public_variables + keypair.num_private_variables,
+    )
+    .next_power_of_two();
+    write_phase2_params(opt, keypair, phase2_size)
+}
+
+fn write_phase2_params<Zexe: PairingEngine>(
+    opt: &NewOpts,
+    keypair: KeypairAssembly<Zexe>,
+    phase2_size: usize,
 ) -> anyhow::Result<()> {
     let phase1_transcript = OpenOptions::new()
         .read(true)
-        .write(true)
         .open(&opt.phase1)
         .expect("could not read phase 1 transcript file");
-    let mut phase1_transcript = unsafe {
+    let phase1_transcript = unsafe {
         MmapOptions::new()
-            .map_mut(phase1_transcript.file())
+            .map(phase1_transcript.file())
             .expect("unable to create a memory map for input")
     };
     let mut output = OpenOptions::new()
@@ -143,13 +208,10 @@ pub fn generate_params<Aleo: PairingEngine, Zexe: PairingEngine, C: Clone + Cons
         .open(&opt.output)
         .expect("could not open file for writing the MPC parameters ");
 
-    let phase2_size = ceremony_size(&circuit);
-    let keypair = circuit_to_qap::<Aleo, Zexe, _>(circuit)?;
-
-    // Read `num_constraints` Lagrange coefficients from the Phase1 Powers of Tau which were
-    // prepared for this step. This will fail if Phase 1 was too small.
-    let phase1 = Groth16Params::<Zexe>::read(
-        &mut phase1_transcript,
+    // Locate `num_constraints` Lagrange coefficients in the Phase1 Powers of Tau which were
+    // prepared for this step, which are read one section at a time. This will fail if Phase 1 was too small.
+    let phase1 = LazyGroth16Params::<Zexe>::new(
+        &phase1_transcript,
         COMPRESSION,
         CheckForCorrectness::No, // No need to check for correctness, since this has been processed by the coordinator.
         2usize.pow(opt.phase1_size),
@@ -157,7 +219,7 @@ pub fn generate_params<Aleo: PairingEngine, Zexe: PairingEngine, C: Clone + Cons
     )?;
 
     // Generate the initial transcript
-    let mpc = MPCParameters::new(keypair, phase1)?;
+    let mpc = MPCParameters::new_lazy(keypair, &phase1)?;
     mpc.write(&mut output)?;
 
     Ok(())
@@ -0,0 +1,145 @@
+use crate::cli::{curve_from_str, curve_kind, ContributeOpts, CurveKind};
+use phase2::{chunked_groth16::read_contributions, keypair::PublicKey};
+use setup_utils::{calculate_hash, curves::Bls12_381, ContributionReceipt, Error, ReceiptRandomness, Result};
+
+use snarkvm_curves::{bls12_377::Bls12_377, bw6_761::BW6_761, PairingEngine};
+
+use fs_err::{File, OpenOptions};
+use gumdrop::Options;
+use memmap::MmapOptions;
+use std::{
+    collections::BTreeMap,
+    io::{self, BufReader, BufWriter, Write},
+    time::SystemTime,
+};
+
+#[derive(Debug, Options, Clone)]
+pub struct VerifyReceiptOpts {
+    help: bool,
+    #[options(help = "the provided receipt file", default = "receipt.json")]
+    pub receipt: String,
+    #[options(
+        help = "the parameters after the contribution, or after any later contribution",
+        default = "challenge"
+    )]
+    pub transcript: String,
+    #[options(help = "setup the inner or the outer circuit?")]
+    pub is_inner: bool,
+    #[options(
+        help = "the curve of a constraint system file: bls12_377, bw6 or bls12_381 (default: the curve of the circuit)",
+        parse(try_from_str = "curve_from_str")
+    )]
+    pub curve_type: Option<CurveKind>,
+}
+
+/// Hashes the parameters before they are contributed to in place, for the receipt.
+pub fn hash_parameters(filename: &str) -> Result<Vec<u8>> {
+    let file = OpenOptions::new().read(true).open(filename)?;
+    let map = unsafe { MmapOptions::new().map(file.file())? };
+    Ok(calculate_hash(&map).to_vec())
+}
+
+/// Writes a JSON receipt of the contribution which started at `started_at`, with the hash
+/// of the parameters before it, and the hash and the last public key of the parameters after it.
+pub fn write_receipt(
+    opts: &ContributeOpts,
+    challenge_hash: &[u8],
+    started_at: SystemTime,
+    randomness: ReceiptRandomness,
+) -> Result<()> {
+    let file = OpenOptions::new().read(true).open(&opts.data)?;
+    let response = unsafe { MmapOptions::new().map(file.file())? };
+    let curve = curve_kind(opts.curve_type, opts.is_inner);
+    let public_key_size = match curve {
+        CurveKind::Bls12_377 => PublicKey::<Bls12_377>::size(),
+        CurveKind::BW6 => PublicKey::<BW6_761>::size(),
+        CurveKind::Bls12_381 => PublicKey::<Bls12_381>::size(),
+    };
+
+    let receipt = ContributionReceipt::new(
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_VERSION"),
+        receipt_parameters(opts.is_inner, curve),
+        challenge_hash,
+        &calculate_hash(&response),
+        &response[response.len() - public_key_size..],
+        started_at,
+        randomness,
+    );
+
+    let mut writer = BufWriter::new(File::create(&opts.receipt)?);
+    serde_json::to_writer_pretty(&mut writer, &receipt).map_err(io::Error::from)?;
+    writer.flush()?;
+    println!("Wrote the receipt of the contribution to {}", opts.receipt);
+    Ok(())
+}
+
+/// Reads a JSON receipt, and checks that its public key is one of the contributions of the
+/// transcript. If the transcript has the hash of the response, its public key must be the last one.
+pub fn verify_receipt(opts: &VerifyReceiptOpts) -> Result<()> {
+    let reader = BufReader::new(File::open(&opts.receipt)?);
+    let receipt: ContributionReceipt = serde_json::from_reader(reader).map_err(io::Error::from)?;
+    let curve = curve_kind(opts.curve_type, opts.is_inner);
+    receipt.check_parameters(&receipt_parameters(opts.is_inner, curve))?;
+
+    let file = OpenOptions::new().read(true).open(&opts.transcript)?;
+    let transcript = unsafe { MmapOptions::new().map(file.file())? };
+    let public_keys = match curve {
+        CurveKind::Bls12_377 => serialized_contributions::<Bls12_377>(&transcript)?,
+        CurveKind::BW6 => serialized_contributions::<BW6_761>(&transcript)?,
+        CurveKind::Bls12_381 => serialized_contributions::<Bls12_381>(&transcript)?,
+    };
+
+    let index = public_keys
+        .iter()
+        .position(|public_key| receipt.public_key.eq_ignore_ascii_case(&hex::encode(public_key)))
+        .ok_or_else(|| {
+            Error::InvalidReceipt(format!(
+                "the public key is not one of the {} contributions of {}",
+                public_keys.len(),
+                opts.transcript
+            ))
+        })?;
+    println!("The receipt is for contribution {} of {}", index, opts.transcript);
+
+    let response_hash = calculate_hash(&transcript);
+    if receipt.response_hash.eq_ignore_ascii_case(&hex::encode(response_hash)) {
+        if index + 1 != public_keys.len() {
+            return Err(Error::InvalidReceipt(
+                "the response does not end with the public key of the receipt".to_string(),
+            ));
+        }
+        println!("The transcript is the response of the receipt");
+    }
+
+    println!("The receipt matches the transcript");
+    Ok(())
+}
+
+pub(crate) fn receipt_parameters(is_inner: bool, curve: CurveKind) -> BTreeMap<String, String> {
+    let circuit = match is_inner {
+        true => "inner",
+        false => "outer",
+    };
+    let curve = match curve {
+        CurveKind::Bls12_377 => "Bls12_377",
+        CurveKind::BW6 => "BW6_761",
+        CurveKind::Bls12_381 => "Bls12_381",
+    };
+    let mut parameters = BTreeMap::new();
+    parameters.insert("circuit".to_string(), circuit.to_string());
+    parameters.insert("curve".to_string(), curve.to_string());
+    parameters
+}
+
+/// Returns the public keys of the contributions, as they are serialized.
+fn serialized_contributions<E: PairingEngine>(transcript: &[u8]) -> Result<Vec<Vec<u8>>> {
+    read_contributions::<E>(transcript)?
+        .iter()
+        .map(|public_key| -> Result<Vec<u8>> {
+            let mut bytes = vec![];
+            public_key.write(&mut bytes)?;
+            Ok(bytes)
+        })
+        .collect()
+}
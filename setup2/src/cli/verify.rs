@@ -1,5 +1,6 @@
+use crate::cli::{curve_from_str, curve_kind, CurveKind};
 use phase2::chunked_groth16::verify as chunked_verify;
-use setup_utils::Result;
+use setup_utils::{curves::Bls12_381, Result};
 
 use snarkvm_curves::{bls12_377::Bls12_377, bw6_761::BW6_761};
 
@@ -19,6 +20,11 @@ pub struct VerifyOpts {
     pub batch: usize,
     #[options(help = "setup the inner or the outer circuit?")]
     pub is_inner: bool,
+    #[options(
+        help = "the curve of a constraint system file: bls12_377, bw6 or bls12_381 (default: the curve of the circuit)",
+        parse(try_from_str = "curve_from_str")
+    )]
+    pub curve_type: Option<CurveKind>,
 }
 
 pub fn verify(opts: &VerifyOpts) -> Result<()> {
@@ -42,10 +48,10 @@ pub fn verify(opts: &VerifyOpts) -> Result<()> {
             .map_mut(after.file())
             .expect("unable to create a memory map for input")
     };
-    if opts.is_inner {
-        chunked_verify::<Bls12_377>(&mut before, &mut after, opts.batch)?;
-    } else {
-        chunked_verify::<BW6_761>(&mut before, &mut after, opts.batch)?;
-    }
+    match curve_kind(opts.curve_type, opts.is_inner) {
+        CurveKind::Bls12_377 => chunked_verify::<Bls12_377>(&mut before, &mut after, opts.batch)?,
+        CurveKind::BW6 => chunked_verify::<BW6_761>(&mut before, &mut after, opts.batch)?,
+        CurveKind::Bls12_381 => chunked_verify::<Bls12_381>(&mut before, &mut after, opts.batch)?,
+    };
     Ok(())
 }
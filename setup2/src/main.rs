@@ -5,10 +5,13 @@ cfg_if! {
         mod cli;
         use cli::*;
 
-        use setup_utils::{beacon_randomness, from_slice, get_rng, user_system_randomness};
+        use setup_utils::{compute_beacon, gather_entropy, get_rng, verify_beacon, ReceiptRandomness};
 
         use gumdrop::Options;
-        use std::{process, time::Instant};
+        use std::{
+            process,
+            time::{Instant, SystemTime},
+        };
         use tracing_subscriber::{
             filter::EnvFilter,
             fmt::{time, Subscriber},
@@ -32,16 +35,38 @@ cfg_if! {
                 Command::New(ref opt) => new(&opt).unwrap(),
                 Command::Contribute(ref opt) => {
                     // contribute to the randomness
-                    let mut rng = get_rng(&user_system_randomness());
-                    contribute(&opt, &mut rng).unwrap()
+                    let started_at = SystemTime::now();
+                    let (seed, entropy) = gather_entropy(&opt.entropy_sources()).unwrap();
+                    println!("Gathered entropy from {:?}", entropy);
+                    let mut rng = get_rng(&seed);
+                    let challenge_hash = hash_parameters(&opt.data).unwrap();
+                    contribute(&opt, &mut rng).unwrap();
+                    let randomness = ReceiptRandomness::Entropy { sources: entropy };
+                    write_receipt(&opt, &challenge_hash, started_at, randomness).unwrap()
                 }
                 Command::Beacon(ref opt) => {
                     // use the beacon's randomness
-                    let beacon_hash = hex::decode(&opt.beacon_hash).expect("could not hex decode beacon hash");
-                    let mut rng = get_rng(&beacon_randomness(from_slice(&beacon_hash)));
-                    contribute(&opt, &mut rng).unwrap()
+                    let started_at = SystemTime::now();
+                    let beacon = compute_beacon(
+                        &opt.beacon_hash,
+                        opt.beacon_iterations_log2,
+                        opt.insecure_beacon,
+                        &opt.beacon_transcript,
+                    )
+                    .unwrap();
+                    println!("Final result of beacon: {}", hex::encode(beacon.result));
+                    let mut rng = get_rng(&beacon.result);
+                    let challenge_hash = hash_parameters(&opt.data).unwrap();
+                    contribute(&opt, &mut rng).unwrap();
+                    write_receipt(&opt, &challenge_hash, started_at, ReceiptRandomness::beacon(&beacon)).unwrap()
                 }
                 Command::Verify(ref opt) => verify(&opt).unwrap(),
+                Command::VerifyBeacon(ref opt) => {
+                    let beacon = verify_beacon(&opt.beacon_hash, &opt.beacon_transcript).unwrap();
+                    println!("Final result of beacon: {}", hex::encode(beacon.result));
+                }
+                Command::VerifyReceipt(ref opt) => verify_receipt(&opt).unwrap(),
+                Command::Export(ref opt) => export(&opt).unwrap(),
             };
 
             let new_now = Instant::now();